@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+// retry delay stops growing after this number of doublings
+const MAX_BACKOFF_DOUBLINGS: u32 = 10;
+
+// Delay before retrying what failed on the given attempt, doubled per attempt from the base delay
+pub fn backoff_delay(base: Duration, attempts: u32) -> Duration {
+    let doublings = attempts.saturating_sub(1).min(MAX_BACKOFF_DOUBLINGS);
+    base * 2u32.pow(doublings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_per_attempt_until_max_doublings() {
+        // given
+        let base = Duration::from_secs(1);
+
+        // when
+        let delays = [1, 2, 3, 11, 12, u32::MAX].map(|attempts| backoff_delay(base, attempts));
+
+        // then
+        assert_eq!(delays, [1, 2, 4, 1024, 1024, 1024].map(Duration::from_secs));
+    }
+}
@@ -0,0 +1,70 @@
+use uuid::Uuid;
+
+// An issue sent to a subscriber, kept to settle every subscriber once
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delivery {
+    pub id: Uuid,
+    pub issue_id: Uuid,
+    pub subscriber_id: Uuid,
+    // address of the subscriber when the issue was published
+    pub email_address: String,
+    pub status: DeliveryStatus,
+    // sending attempts including the one in progress
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+impl Delivery {
+    pub fn new(issue_id: Uuid, subscriber_id: Uuid, email_address: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            issue_id,
+            subscriber_id,
+            email_address,
+            status: DeliveryStatus::Queued,
+            attempts: 0,
+            last_error: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DeliveryStatus {
+    // waiting to be sent or retried
+    #[default]
+    Queued,
+    Sent,
+    // gave up after the maximum attempts
+    Failed,
+    // rejected by the mail transport for the recipient
+    Bounced,
+}
+
+// Number of deliveries of an issue by status
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct DeliverySummary {
+    pub queued: u64,
+    pub sent: u64,
+    pub failed: u64,
+    pub bounced: u64,
+}
+
+impl DeliverySummary {
+    pub fn total(&self) -> u64 {
+        self.queued + self.sent + self.failed + self.bounced
+    }
+
+    // every delivery is sent or given up
+    pub fn is_settled(&self) -> bool {
+        self.queued == 0
+    }
+
+    pub fn count(&mut self, status: DeliveryStatus, count: u64) {
+        match status {
+            DeliveryStatus::Queued => self.queued += count,
+            DeliveryStatus::Sent => self.sent += count,
+            DeliveryStatus::Failed => self.failed += count,
+            DeliveryStatus::Bounced => self.bounced += count,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::issue::prelude::{
+    IssueError,
+    IssueStatus,
+};
+use crate::rendering::prelude::RenderingError;
+use crate::subscriber::prelude::SubscriberError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DeliveryError {
+    #[error("Issue (ID: {0}) doesn't exist")]
+    IssueNotFound(Uuid),
+
+    #[error("Issue (ID: {0}) can't be delivered in status {1}")]
+    IssueNotDeliverable(Uuid, IssueStatus),
+
+    #[error("Failed to operate on issue")]
+    IssueOperationFailed(#[source] IssueError),
+
+    #[error("Failed to render issue")]
+    RenderingFailed(#[source] RenderingError),
+
+    #[error("Failed to find subscribers")]
+    SubscriberOperationFailed(#[source] SubscriberError),
+
+    #[error("Failed to operator on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MailTransportError {
+    // worth retrying
+    #[error("Mail transport is unavailable")]
+    Unavailable(#[source] anyhow::Error),
+
+    // the recipient can't receive mails, so retrying doesn't help
+    #[error("Mail transport rejected the recipient: {0}")]
+    Rejected(String),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MailFeedbackError {
+    #[error("Failed to find deliveries")]
+    DeliveryOperationFailed(#[source] DeliveryError),
+
+    #[error("Failed to operate on subscriber")]
+    SubscriberOperationFailed(#[source] SubscriberError),
+
+    #[error("Failed to operator on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+}
@@ -0,0 +1,142 @@
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::delivery::model::error::MailFeedbackError;
+
+// Bounce or complaint reported by a mail provider, normalized from the payloads of providers
+#[derive(Clone, Debug, PartialEq)]
+pub struct MailFeedback {
+    // given by the provider, so that a notification sent again is recorded once
+    pub id: String,
+    pub kind: MailFeedbackKind,
+    pub email_address: String,
+    pub description: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl MailFeedback {
+    pub fn new(
+        id: String,
+        kind: MailFeedbackKind,
+        email_address: String,
+        description: Option<String>,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            kind,
+            email_address,
+            description,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MailFeedbackKind {
+    // the address doesn't exist or never accepts mails
+    HardBounce,
+    // the address can't accept mails for now, e.g. the mailbox is full
+    SoftBounce,
+    // the recipient marked the mail as spam
+    Complaint,
+}
+
+#[async_trait::async_trait]
+pub trait MailFeedbackRepository: Send + Sync + Clone {
+    // Record feedback about a subscriber, and return false if the feedback is recorded already
+    async fn record(
+        &self,
+        subscriber_id: Uuid,
+        feedback: &MailFeedback,
+    ) -> Result<bool, MailFeedbackError>;
+
+    // Count soft bounces of a subscriber which occurred at or after the time
+    async fn count_soft_bounces(
+        &self,
+        subscriber_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<u64, MailFeedbackError>;
+
+    // Delete feedback about a subscriber, which keeps its email address
+    // Returns the number of feedback deleted
+    async fn remove_by_subscriber_id(&self, subscriber_id: Uuid) -> Result<u64, MailFeedbackError>;
+}
+
+#[derive(Clone)]
+pub struct FakeMailFeedbackRepository {
+    items: Arc<RwLock<Vec<(Uuid, MailFeedback)>>>,
+}
+
+impl FakeMailFeedbackRepository {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            items: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MailFeedbackRepository for FakeMailFeedbackRepository {
+    async fn record(
+        &self,
+        subscriber_id: Uuid,
+        feedback: &MailFeedback,
+    ) -> Result<bool, MailFeedbackError> {
+        let mut store = self.items.write().map_err(|_| {
+            MailFeedbackError::RepositoryOperationFailed(anyhow::anyhow!(
+                "Failed to get fake store"
+            ))
+        })?;
+
+        if store.iter().any(|(_, existing)| existing.id == feedback.id) {
+            return Ok(false);
+        }
+        store.push((subscriber_id, feedback.clone()));
+
+        Ok(true)
+    }
+
+    async fn count_soft_bounces(
+        &self,
+        subscriber_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<u64, MailFeedbackError> {
+        let store = self.items.read().map_err(|_| {
+            MailFeedbackError::RepositoryOperationFailed(anyhow::anyhow!(
+                "Failed to get fake store"
+            ))
+        })?;
+
+        Ok(store
+            .iter()
+            .filter(|(id, feedback)| {
+                *id == subscriber_id
+                    && feedback.kind == MailFeedbackKind::SoftBounce
+                    && feedback.occurred_at >= since
+            })
+            .count() as u64)
+    }
+
+    async fn remove_by_subscriber_id(&self, subscriber_id: Uuid) -> Result<u64, MailFeedbackError> {
+        let mut store = self.items.write().map_err(|_| {
+            MailFeedbackError::RepositoryOperationFailed(anyhow::anyhow!(
+                "Failed to get fake store"
+            ))
+        })?;
+
+        let count = store.len();
+        store.retain(|(id, _)| *id != subscriber_id);
+
+        Ok((count - store.len()) as u64)
+    }
+}
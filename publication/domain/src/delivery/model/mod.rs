@@ -0,0 +1,8 @@
+mod backoff;
+mod entities;
+mod error;
+mod feedback;
+pub mod prelude;
+mod repository;
+mod transport;
+mod unsubscribe;
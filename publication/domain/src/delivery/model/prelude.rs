@@ -0,0 +1,27 @@
+pub use crate::delivery::model::backoff::backoff_delay;
+pub use crate::delivery::model::entities::{
+    Delivery,
+    DeliveryStatus,
+    DeliverySummary,
+};
+pub use crate::delivery::model::error::{
+    DeliveryError,
+    MailFeedbackError,
+    MailTransportError,
+};
+pub use crate::delivery::model::feedback::{
+    FakeMailFeedbackRepository,
+    MailFeedback,
+    MailFeedbackKind,
+    MailFeedbackRepository,
+};
+pub use crate::delivery::model::repository::{
+    DeliveryRepository,
+    FakeDeliveryRepository,
+};
+pub use crate::delivery::model::transport::{
+    FakeMailTransport,
+    MailMessage,
+    MailTransport,
+};
+pub use crate::delivery::model::unsubscribe::unsubscribe_token;
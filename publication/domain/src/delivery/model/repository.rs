@@ -0,0 +1,224 @@
+use std::sync::{
+    Arc,
+    RwLock,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use uuid::Uuid;
+
+use crate::delivery::model::entities::{
+    Delivery,
+    DeliveryStatus,
+    DeliverySummary,
+};
+use crate::delivery::model::error::DeliveryError;
+
+#[async_trait::async_trait]
+pub trait DeliveryRepository: Send + Sync + Clone {
+    // Queue deliveries all together or none of them, skipping subscribers who already have a
+    // delivery of the issue
+    // Returns the number of deliveries queued
+    async fn enqueue(&self, deliveries: &[Delivery]) -> Result<u64, DeliveryError>;
+
+    // Lease queued deliveries of an issue which are due, counting an attempt for each
+    // Leased deliveries aren't claimed again until the lease expires, so a crashed sender's
+    // deliveries are taken over by the next claim
+    async fn claim(
+        &self,
+        issue_id: Uuid,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<Delivery>, DeliveryError>;
+
+    async fn mark_as_sent(&self, id: Uuid) -> Result<(), DeliveryError>;
+
+    async fn mark_as_bounced(&self, id: Uuid, reason: &str) -> Result<(), DeliveryError>;
+
+    async fn mark_as_failed(&self, id: Uuid, reason: &str) -> Result<(), DeliveryError>;
+
+    // Keep the delivery queued, but not due until the delay passes
+    async fn retry(&self, id: Uuid, delay: Duration, reason: &str) -> Result<(), DeliveryError>;
+
+    async fn find_by_issue_id(&self, issue_id: Uuid) -> Result<Vec<Delivery>, DeliveryError>;
+
+    // Find the latest delivery to an address regardless of its case, which tells the subscriber
+    // mail feedback of the address is about
+    async fn find_latest_by_email_address(
+        &self,
+        email_address: &str,
+    ) -> Result<Option<Delivery>, DeliveryError>;
+
+    async fn summarize(&self, issue_id: Uuid) -> Result<DeliverySummary, DeliveryError>;
+
+    // Delete deliveries to a subscriber, which keep its email address
+    // Returns the number of deliveries deleted
+    async fn remove_by_subscriber_id(&self, subscriber_id: Uuid) -> Result<u64, DeliveryError>;
+}
+
+#[derive(Clone)]
+pub struct FakeDeliveryRepository {
+    items: Arc<RwLock<Vec<(Delivery, Instant)>>>,
+}
+
+impl FakeDeliveryRepository {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            items: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    // Make every queued delivery due, as if leases and retry delays have passed
+    pub fn expire_leases(&self) {
+        for (_, available_at) in self.items.write().unwrap().iter_mut() {
+            *available_at = Instant::now();
+        }
+    }
+
+    fn update<F>(&self, id: Uuid, mut updater: F) -> Result<(), DeliveryError>
+    where
+        F: FnMut(&mut Delivery, &mut Instant),
+    {
+        let mut store = self.items.write().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let (delivery, available_at) = store
+            .iter_mut()
+            .find(|(delivery, _)| delivery.id == id)
+            .ok_or_else(|| {
+                DeliveryError::RepositoryOperationFailed(anyhow::anyhow!(
+                    "Delivery (ID: {}) doesn't exist",
+                    id
+                ))
+            })?;
+        updater(delivery, available_at);
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl DeliveryRepository for FakeDeliveryRepository {
+    async fn enqueue(&self, deliveries: &[Delivery]) -> Result<u64, DeliveryError> {
+        let mut store = self.items.write().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let mut queued = 0;
+        for delivery in deliveries {
+            if store.iter().any(|(existing, _)| {
+                existing.issue_id == delivery.issue_id
+                    && existing.subscriber_id == delivery.subscriber_id
+            }) {
+                continue;
+            }
+            store.push((delivery.clone(), Instant::now()));
+            queued += 1;
+        }
+
+        Ok(queued)
+    }
+
+    async fn claim(
+        &self,
+        issue_id: Uuid,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<Delivery>, DeliveryError> {
+        let mut store = self.items.write().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let now = Instant::now();
+        Ok(store
+            .iter_mut()
+            .filter(|(delivery, available_at)| {
+                delivery.issue_id == issue_id
+                    && delivery.status == DeliveryStatus::Queued
+                    && *available_at <= now
+            })
+            .take(limit as usize)
+            .map(|(delivery, available_at)| {
+                delivery.attempts += 1;
+                *available_at = now + lease;
+                delivery.clone()
+            })
+            .collect())
+    }
+
+    async fn mark_as_sent(&self, id: Uuid) -> Result<(), DeliveryError> {
+        self.update(id, |delivery, _| delivery.status = DeliveryStatus::Sent)
+    }
+
+    async fn mark_as_bounced(&self, id: Uuid, reason: &str) -> Result<(), DeliveryError> {
+        self.update(id, |delivery, _| {
+            delivery.status = DeliveryStatus::Bounced;
+            delivery.last_error = Some(reason.to_string());
+        })
+    }
+
+    async fn mark_as_failed(&self, id: Uuid, reason: &str) -> Result<(), DeliveryError> {
+        self.update(id, |delivery, _| {
+            delivery.status = DeliveryStatus::Failed;
+            delivery.last_error = Some(reason.to_string());
+        })
+    }
+
+    async fn retry(&self, id: Uuid, delay: Duration, reason: &str) -> Result<(), DeliveryError> {
+        self.update(id, |delivery, available_at| {
+            delivery.last_error = Some(reason.to_string());
+            *available_at = Instant::now() + delay;
+        })
+    }
+
+    async fn find_by_issue_id(&self, issue_id: Uuid) -> Result<Vec<Delivery>, DeliveryError> {
+        let store = self.items.read().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store
+            .iter()
+            .filter(|(delivery, _)| delivery.issue_id == issue_id)
+            .map(|(delivery, _)| delivery.clone())
+            .collect())
+    }
+
+    async fn find_latest_by_email_address(
+        &self,
+        email_address: &str,
+    ) -> Result<Option<Delivery>, DeliveryError> {
+        let store = self.items.read().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store
+            .iter()
+            .rev()
+            .find(|(delivery, _)| delivery.email_address.eq_ignore_ascii_case(email_address))
+            .map(|(delivery, _)| delivery.clone()))
+    }
+
+    async fn summarize(&self, issue_id: Uuid) -> Result<DeliverySummary, DeliveryError> {
+        let mut summary = DeliverySummary::default();
+        for delivery in self.find_by_issue_id(issue_id).await? {
+            summary.count(delivery.status, 1);
+        }
+
+        Ok(summary)
+    }
+
+    async fn remove_by_subscriber_id(&self, subscriber_id: Uuid) -> Result<u64, DeliveryError> {
+        let mut store = self.items.write().map_err(|_| {
+            DeliveryError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let count = store.len();
+        store.retain(|(delivery, _)| delivery.subscriber_id != subscriber_id);
+
+        Ok((count - store.len()) as u64)
+    }
+}
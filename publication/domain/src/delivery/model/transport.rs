@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use uuid::Uuid;
+
+use crate::delivery::model::error::MailTransportError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MailMessage {
+    // same for every attempt of a delivery, so a transport supporting it drops duplicates
+    // of a delivery sent again after a crash
+    pub idempotency_key: Uuid,
+    pub to: String,
+    pub subject: String,
+    pub html_body: String,
+    // alternative for clients not showing HTML
+    pub text_body: String,
+}
+
+impl MailMessage {
+    pub fn new(
+        idempotency_key: Uuid,
+        to: String,
+        subject: String,
+        html_body: String,
+        text_body: String,
+    ) -> Self {
+        Self {
+            idempotency_key,
+            to,
+            subject,
+            html_body,
+            text_body,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait MailTransport: Send + Sync + Clone {
+    async fn send(&self, message: &MailMessage) -> Result<(), MailTransportError>;
+}
+
+#[derive(Clone)]
+pub struct FakeMailTransport {
+    sent: Arc<RwLock<Vec<MailMessage>>>,
+    rejected: Arc<RwLock<HashSet<String>>>,
+    unavailable: Arc<RwLock<bool>>,
+}
+
+impl FakeMailTransport {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            sent: Arc::new(RwLock::new(Vec::new())),
+            rejected: Arc::new(RwLock::new(HashSet::new())),
+            unavailable: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    pub fn sent(&self) -> Vec<MailMessage> {
+        self.sent.read().unwrap().clone()
+    }
+
+    pub fn reject(&self, to: &str) {
+        self.rejected.write().unwrap().insert(to.to_string());
+    }
+
+    pub fn set_unavailable(&self, unavailable: bool) {
+        *self.unavailable.write().unwrap() = unavailable;
+    }
+}
+
+#[async_trait::async_trait]
+impl MailTransport for FakeMailTransport {
+    async fn send(&self, message: &MailMessage) -> Result<(), MailTransportError> {
+        if self.rejected.read().unwrap().contains(&message.to) {
+            return Err(MailTransportError::Rejected(format!(
+                "{} doesn't exist",
+                message.to
+            )));
+        }
+        if *self.unavailable.read().unwrap() {
+            return Err(MailTransportError::Unavailable(anyhow::anyhow!(
+                "Fake mail transport is unavailable"
+            )));
+        }
+
+        self.sent.write().unwrap().push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use hmac::{
+    Hmac,
+    Mac,
+};
+use sha2::Sha256;
+use uuid::Uuid;
+
+// Token of the link a subscriber leaves at, the ID of the subscriber and its HMAC-SHA256 in hex
+// by the secret shared with the subscription service, so that links can't be made for others
+pub fn unsubscribe_token(secret: &str, subscriber_id: Uuid) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC takes a key of any size");
+    mac.update(subscriber_id.to_string().as_bytes());
+
+    format!(
+        "{}.{}",
+        subscriber_id,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsubscribe_token_is_signed_for_the_subscriber_by_the_secret() {
+        // given
+        let subscriber_id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        // when
+        let token = unsubscribe_token("welcome", subscriber_id);
+
+        // then
+        assert_eq!(
+            token,
+            "67e55044-10b1-426f-9247-bb680e5fe0c8.\
+            19d3b3506fe2ffa99601dbf4feaa8169f657906b275c9ae1ab44d43823465e01"
+        );
+        assert_ne!(token, unsubscribe_token("guess", subscriber_id));
+        assert_ne!(token, unsubscribe_token("welcome", Uuid::new_v4()));
+    }
+}
@@ -0,0 +1,2 @@
+pub use crate::delivery::model::prelude::*;
+pub use crate::delivery::service::prelude::*;
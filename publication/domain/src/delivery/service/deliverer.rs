@@ -0,0 +1,512 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::delivery::model::prelude::{
+    backoff_delay,
+    unsubscribe_token,
+    Delivery,
+    DeliveryError,
+    DeliveryRepository,
+    DeliverySummary,
+    MailMessage,
+    MailTransport,
+    MailTransportError,
+};
+use crate::issue::prelude::{
+    Issue,
+    IssueError,
+    IssueRepository,
+    IssueStatus,
+};
+use crate::rendering::prelude::{
+    IssueRenderer,
+    Personalization,
+    RenderedIssue,
+    RenderedIssueCache,
+};
+use crate::subscriber::prelude::{
+    SubscriberEmailVerifiationStatus,
+    SubscriberRepository,
+};
+
+#[derive(Clone, Debug)]
+pub struct DeliveryOptions {
+    // maximum number of deliveries sent at the same time
+    pub concurrency: usize,
+    // attempts of a delivery failed by an unavailable transport before it is given up
+    pub max_attempts: u32,
+    // delay before the first retry, doubled on every later retry
+    pub retry_backoff: Duration,
+    // time for a claimed delivery to be settled before another sender takes it over
+    pub lease: Duration,
+    // page subscribers leave at, which gets a token signed for the subscriber as a query parameter
+    pub unsubscribe_url: String,
+    // secret shared with the subscription service, which verifies the token before unsubscribing
+    pub unsubscribe_secret: String,
+}
+
+// Sends issues started by the scheduler to subscribers with valid emails
+#[derive(Clone)]
+pub struct IssueDeliverer<I, S, D, C, T>
+where
+    I: IssueRepository,
+    S: SubscriberRepository,
+    D: DeliveryRepository,
+    C: RenderedIssueCache,
+    T: MailTransport,
+{
+    issue_repository: I,
+    subscriber_repository: S,
+    delivery_repository: D,
+    renderer: IssueRenderer<I, C>,
+    mail_transport: T,
+    options: DeliveryOptions,
+}
+
+impl<I, S, D, C, T> IssueDeliverer<I, S, D, C, T>
+where
+    I: IssueRepository,
+    S: SubscriberRepository,
+    D: DeliveryRepository,
+    C: RenderedIssueCache,
+    T: MailTransport,
+{
+    pub fn new(
+        issue_repository: I,
+        subscriber_repository: S,
+        delivery_repository: D,
+        rendered_issue_cache: C,
+        mail_transport: T,
+        options: DeliveryOptions,
+    ) -> Self {
+        Self {
+            renderer: IssueRenderer::new(issue_repository.clone(), rendered_issue_cache),
+            issue_repository,
+            subscriber_repository,
+            delivery_repository,
+            mail_transport,
+            options,
+        }
+    }
+
+    // Queue the issue started sending for every valid subscriber unless queued already, which is
+    // done at once so that a failed fan-out leaves nothing queued and is done again next time, then
+    // send the deliveries due until none is left, and mark the issue as sent once all are settled
+    // Deliveries waiting for a retry are left for the next call
+    pub async fn deliver(&self, issue_id: Uuid) -> Result<DeliverySummary, DeliveryError> {
+        let issue = self.find_issue(issue_id).await?;
+        if issue.status == IssueStatus::Sent {
+            return self.delivery_repository.summarize(issue_id).await;
+        }
+        if issue.status != IssueStatus::Sending {
+            return Err(DeliveryError::IssueNotDeliverable(issue_id, issue.status));
+        }
+
+        if self.delivery_repository.summarize(issue_id).await?.total() == 0 {
+            self.fan_out(&issue).await?;
+        }
+
+        // every subscriber receives the issue as rendered for the preview
+        let rendered_issue = self
+            .renderer
+            .render_issue(&issue)
+            .await
+            .map_err(DeliveryError::RenderingFailed)?;
+        while self.deliver_next_batch(&rendered_issue).await? > 0 {}
+
+        let summary = self.delivery_repository.summarize(issue_id).await?;
+        if summary.is_settled() {
+            self.issue_repository
+                .modify(issue_id, |issue| issue.mark_as_sent())
+                .await
+                .or_else(|error| match error {
+                    // sent by another sender in the meantime
+                    IssueError::InvalidIssueStatusTransition {
+                        from: IssueStatus::Sent,
+                        ..
+                    } => Ok(()),
+                    error => Err(DeliveryError::IssueOperationFailed(error)),
+                })?;
+        }
+
+        Ok(summary)
+    }
+
+    async fn find_issue(&self, issue_id: Uuid) -> Result<Issue, DeliveryError> {
+        self.issue_repository
+            .find_by_id(issue_id)
+            .await
+            .map_err(DeliveryError::IssueOperationFailed)?
+            .ok_or(DeliveryError::IssueNotFound(issue_id))
+    }
+
+    async fn fan_out(&self, issue: &Issue) -> Result<u64, DeliveryError> {
+        let deliveries: Vec<Delivery> = self
+            .subscriber_repository
+            .find_all()
+            .await
+            .map_err(DeliveryError::SubscriberOperationFailed)?
+            .into_iter()
+            .filter(|subscriber| {
+                subscriber.email.verification_status == SubscriberEmailVerifiationStatus::Valid
+            })
+            .map(|subscriber| Delivery::new(issue.id, subscriber.id, subscriber.email.address))
+            .collect();
+
+        self.delivery_repository.enqueue(&deliveries).await
+    }
+
+    // Send a batch of claimed deliveries concurrently, returns the number of claimed deliveries
+    async fn deliver_next_batch(
+        &self,
+        rendered_issue: &RenderedIssue,
+    ) -> Result<usize, DeliveryError> {
+        let deliveries = self
+            .delivery_repository
+            .claim(
+                rendered_issue.issue_id,
+                self.options.concurrency as u64,
+                self.options.lease,
+            )
+            .await?;
+
+        let results = futures::future::join_all(
+            deliveries
+                .iter()
+                .map(|delivery| self.send(rendered_issue, delivery)),
+        )
+        .await;
+        for result in results {
+            result?;
+        }
+
+        Ok(deliveries.len())
+    }
+
+    async fn send(
+        &self,
+        rendered_issue: &RenderedIssue,
+        delivery: &Delivery,
+    ) -> Result<(), DeliveryError> {
+        let subscriber = match self
+            .subscriber_repository
+            .find_by_id(delivery.subscriber_id)
+            .await
+            .map_err(DeliveryError::SubscriberOperationFailed)?
+        {
+            Some(subscriber) => subscriber,
+            // removed after the issue was queued for the subscriber
+            None => {
+                return self
+                    .delivery_repository
+                    .mark_as_failed(delivery.id, "Subscriber doesn't exist any more")
+                    .await
+            }
+        };
+        let personalized_issue = rendered_issue
+            .personalize(&Personalization::new(
+                subscriber.name,
+                delivery.email_address.clone(),
+                format!(
+                    "{}?token={}",
+                    self.options.unsubscribe_url,
+                    unsubscribe_token(&self.options.unsubscribe_secret, subscriber.id)
+                ),
+            ))
+            .map_err(DeliveryError::RenderingFailed)?;
+        let message = MailMessage::new(
+            delivery.id,
+            delivery.email_address.clone(),
+            personalized_issue.subject,
+            personalized_issue.html,
+            personalized_issue.text,
+        );
+
+        match self.mail_transport.send(&message).await {
+            Ok(()) => self.delivery_repository.mark_as_sent(delivery.id).await,
+            Err(MailTransportError::Rejected(reason)) => {
+                self.delivery_repository
+                    .mark_as_bounced(delivery.id, &reason)
+                    .await
+            }
+            // the reason is kept on the delivery, so that it's inquired with the delivery
+            Err(error) if delivery.attempts >= self.options.max_attempts => {
+                self.delivery_repository
+                    .mark_as_failed(delivery.id, &format!("{:?}", error))
+                    .await
+            }
+            Err(error) => {
+                let delay = backoff_delay(self.options.retry_backoff, delivery.attempts);
+                self.delivery_repository
+                    .retry(delivery.id, delay, &format!("{:?}", error))
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use claims::assert_matches;
+
+    use crate::delivery::prelude::{
+        FakeDeliveryRepository,
+        FakeMailTransport,
+    };
+    use crate::issue::prelude::FakeIssueRepository;
+    use crate::rendering::prelude::FakeRenderedIssueCache;
+    use crate::subscriber::prelude::{
+        FakeSubscriberRepository,
+        Subscriber,
+    };
+
+    use super::*;
+
+    type Deliverer = IssueDeliverer<
+        FakeIssueRepository,
+        FakeSubscriberRepository,
+        FakeDeliveryRepository,
+        FakeRenderedIssueCache,
+        FakeMailTransport,
+    >;
+
+    struct Context {
+        issue_repository: FakeIssueRepository,
+        delivery_repository: FakeDeliveryRepository,
+        mail_transport: FakeMailTransport,
+        deliverer: Deliverer,
+    }
+
+    async fn prepare(statuses: &[SubscriberEmailVerifiationStatus]) -> Context {
+        let issue_repository = FakeIssueRepository::new();
+        let subscriber_repository = FakeSubscriberRepository::new();
+        let delivery_repository = FakeDeliveryRepository::new();
+        let mail_transport = FakeMailTransport::new();
+
+        for (index, status) in statuses.iter().enumerate() {
+            let mut subscriber = Subscriber::default();
+            subscriber.create(
+                Uuid::new_v4(),
+                format!("subscriber{}@example.com", index),
+                format!("Subscriber {}", index),
+            );
+            if *status != SubscriberEmailVerifiationStatus::Unverified {
+                subscriber.verified_email_as(status).unwrap();
+            }
+            subscriber_repository.save(&mut subscriber).await.unwrap();
+        }
+
+        let deliverer = IssueDeliverer::new(
+            issue_repository.clone(),
+            subscriber_repository,
+            delivery_repository.clone(),
+            FakeRenderedIssueCache::new(),
+            mail_transport.clone(),
+            DeliveryOptions {
+                concurrency: 2,
+                max_attempts: 2,
+                retry_backoff: Duration::from_secs(60),
+                lease: Duration::from_secs(60),
+                unsubscribe_url: "https://example.com/unsubscribe".to_string(),
+                unsubscribe_secret: "welcome".to_string(),
+            },
+        );
+
+        Context {
+            issue_repository,
+            delivery_repository,
+            mail_transport,
+            deliverer,
+        }
+    }
+
+    async fn start_sending_issue(issue_repository: &FakeIssueRepository) -> Uuid {
+        start_sending_issue_with_body(issue_repository, "Hello, subscribers").await
+    }
+
+    async fn start_sending_issue_with_body(
+        issue_repository: &FakeIssueRepository,
+        body: &str,
+    ) -> Uuid {
+        let id = Uuid::new_v4();
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                body.to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        issue.submit_for_review().unwrap();
+        issue.publish().unwrap();
+        issue.start_sending(Utc::now()).unwrap();
+        issue_repository.save(&mut issue).await.unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn delivering_issue_sends_it_to_valid_subscribers_only_and_marks_it_sent() {
+        // given
+        let context = prepare(&[
+            SubscriberEmailVerifiationStatus::Valid,
+            SubscriberEmailVerifiationStatus::Valid,
+            SubscriberEmailVerifiationStatus::Valid,
+            SubscriberEmailVerifiationStatus::Invalid,
+            SubscriberEmailVerifiationStatus::Unverified,
+        ])
+        .await;
+        let id = start_sending_issue(&context.issue_repository).await;
+
+        // when
+        let summary = context.deliverer.deliver(id).await.unwrap();
+
+        // then
+        assert_eq!(summary.sent, 3);
+        assert_eq!(summary.total(), 3);
+        let sent = context.mail_transport.sent();
+        assert_eq!(sent.len(), 3);
+        assert!(sent[0].html_body.contains("<p"));
+        assert_eq!(sent[0].text_body, "Hello, subscribers\n");
+        let issue = context
+            .issue_repository
+            .find_by_id(id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(issue.status, IssueStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn delivering_issue_personalizes_it_for_each_subscriber() {
+        // given
+        let context = prepare(&[SubscriberEmailVerifiationStatus::Valid]).await;
+        let id = start_sending_issue_with_body(
+            &context.issue_repository,
+            "Hello, {{ subscriber.name }}\n\n[Unsubscribe]({{ unsubscribe_url }})",
+        )
+        .await;
+
+        // when
+        context.deliverer.deliver(id).await.unwrap();
+
+        // then
+        let sent = context.mail_transport.sent();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].text_body.starts_with("Hello, Subscriber 0\n"));
+        let subscriber_id = context
+            .delivery_repository
+            .find_by_issue_id(id)
+            .await
+            .unwrap()[0]
+            .subscriber_id;
+        assert!(sent[0].html_body.contains(&format!(
+            "href=\"https://example.com/unsubscribe?token={}\"",
+            unsubscribe_token("welcome", subscriber_id)
+        )));
+    }
+
+    #[tokio::test]
+    async fn delivering_again_after_crash_doesnt_resend_to_subscribers_who_got_the_issue() {
+        // given
+        let context = prepare(&[
+            SubscriberEmailVerifiationStatus::Valid,
+            SubscriberEmailVerifiationStatus::Valid,
+        ])
+        .await;
+        let id = start_sending_issue(&context.issue_repository).await;
+        context.mail_transport.set_unavailable(true);
+        context.deliverer.deliver(id).await.unwrap();
+        context.mail_transport.set_unavailable(false);
+        let deliveries = context
+            .delivery_repository
+            .find_by_issue_id(id)
+            .await
+            .unwrap();
+        context
+            .delivery_repository
+            .mark_as_sent(deliveries[0].id)
+            .await
+            .unwrap();
+        context.delivery_repository.expire_leases();
+
+        // when
+        let summary = context.deliverer.deliver(id).await.unwrap();
+
+        // then
+        assert_eq!(summary.sent, 2);
+        let sent = context.mail_transport.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].idempotency_key, deliveries[1].id);
+    }
+
+    #[tokio::test]
+    async fn rejected_deliveries_bounce_and_unavailable_ones_fail_after_max_attempts() {
+        // given
+        let context = prepare(&[
+            SubscriberEmailVerifiationStatus::Valid,
+            SubscriberEmailVerifiationStatus::Valid,
+        ])
+        .await;
+        let id = start_sending_issue(&context.issue_repository).await;
+        context.mail_transport.reject("subscriber0@example.com");
+        context.mail_transport.set_unavailable(true);
+
+        // when
+        let first = context.deliverer.deliver(id).await.unwrap();
+        context.delivery_repository.expire_leases();
+        let second = context.deliverer.deliver(id).await.unwrap();
+
+        // then
+        assert_eq!(first.bounced, 1);
+        assert_eq!(first.queued, 1);
+        assert_eq!(second.bounced, 1);
+        assert_eq!(second.failed, 1);
+        let deliveries = context
+            .delivery_repository
+            .find_by_issue_id(id)
+            .await
+            .unwrap();
+        assert!(deliveries
+            .iter()
+            .all(|delivery| delivery.last_error.is_some()));
+        let issue = context
+            .issue_repository
+            .find_by_id(id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(issue.status, IssueStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn delivering_draft_issue_fails() {
+        // given
+        let context = prepare(&[SubscriberEmailVerifiationStatus::Valid]).await;
+        let id = Uuid::new_v4();
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        context.issue_repository.save(&mut issue).await.unwrap();
+
+        // when
+        let response = context.deliverer.deliver(id).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(DeliveryError::IssueNotDeliverable(_, IssueStatus::Draft))
+        );
+        assert!(context.mail_transport.sent().is_empty());
+    }
+}
@@ -0,0 +1,311 @@
+use uuid::Uuid;
+
+use crate::delivery::model::prelude::{
+    DeliveryRepository,
+    MailFeedback,
+    MailFeedbackError,
+    MailFeedbackKind,
+    MailFeedbackRepository,
+};
+use crate::subscriber::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberEmailVerifiationStatus,
+    SubscriberRepository,
+    VerifySubscriberEmailAs,
+};
+
+#[derive(Clone, Debug)]
+pub struct MailFeedbackOptions {
+    // soft bounces within the window making the email of a subscriber invalid
+    pub soft_bounce_threshold: u64,
+    pub soft_bounce_window: chrono::Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MailFeedbackOutcome {
+    // nothing was delivered to the address, or the subscriber doesn't exist any more
+    UnknownRecipient,
+    // soft bounces of the subscriber within the window, which is under the threshold
+    SoftBounceCounted(Uuid, u64),
+    SubscriberInvalidated(Uuid),
+    // e.g. by an earlier bounce, or by the same notification sent again
+    SubscriberAlreadyInvalid(Uuid),
+}
+
+// Verifies emails of subscribers as invalid by bounces and complaints reported by the mail provider
+#[derive(Clone)]
+pub struct MailFeedbackHandler<S, D, F>
+where
+    S: SubscriberRepository,
+    D: DeliveryRepository,
+    F: MailFeedbackRepository,
+{
+    subscriber_repository: S,
+    subscriber_command_executor: SubscriberCommandExecutor<S>,
+    delivery_repository: D,
+    feedback_repository: F,
+    options: MailFeedbackOptions,
+}
+
+impl<S, D, F> MailFeedbackHandler<S, D, F>
+where
+    S: SubscriberRepository,
+    D: DeliveryRepository,
+    F: MailFeedbackRepository,
+{
+    pub fn new(
+        subscriber_repository: S,
+        delivery_repository: D,
+        feedback_repository: F,
+        options: MailFeedbackOptions,
+    ) -> Self {
+        Self {
+            subscriber_command_executor: SubscriberCommandExecutor::new(
+                subscriber_repository.clone(),
+            ),
+            subscriber_repository,
+            delivery_repository,
+            feedback_repository,
+            options,
+        }
+    }
+
+    // Hard bounces and complaints make the email invalid at once, soft bounces only once they reach
+    // the threshold within the window
+    pub async fn handle(
+        &self,
+        feedback: &MailFeedback,
+    ) -> Result<MailFeedbackOutcome, MailFeedbackError> {
+        let subscriber_id = match self
+            .delivery_repository
+            .find_latest_by_email_address(&feedback.email_address)
+            .await
+            .map_err(MailFeedbackError::DeliveryOperationFailed)?
+        {
+            Some(delivery) => delivery.subscriber_id,
+            None => return Ok(MailFeedbackOutcome::UnknownRecipient),
+        };
+
+        // a notification sent again isn't counted twice, but still handled in case the email
+        // failed to be verified the last time
+        self.feedback_repository
+            .record(subscriber_id, feedback)
+            .await?;
+
+        if feedback.kind == MailFeedbackKind::SoftBounce {
+            let soft_bounces = self
+                .feedback_repository
+                .count_soft_bounces(
+                    subscriber_id,
+                    feedback.occurred_at - self.options.soft_bounce_window,
+                )
+                .await?;
+            if soft_bounces < self.options.soft_bounce_threshold {
+                return Ok(MailFeedbackOutcome::SoftBounceCounted(
+                    subscriber_id,
+                    soft_bounces,
+                ));
+            }
+        }
+
+        self.invalidate(subscriber_id).await
+    }
+
+    async fn invalidate(
+        &self,
+        subscriber_id: Uuid,
+    ) -> Result<MailFeedbackOutcome, MailFeedbackError> {
+        let subscriber = match self
+            .subscriber_repository
+            .find_by_id(subscriber_id)
+            .await
+            .map_err(MailFeedbackError::SubscriberOperationFailed)?
+        {
+            Some(subscriber) => subscriber,
+            None => return Ok(MailFeedbackOutcome::UnknownRecipient),
+        };
+        if subscriber.email.verification_status == SubscriberEmailVerifiationStatus::Invalid {
+            return Ok(MailFeedbackOutcome::SubscriberAlreadyInvalid(subscriber_id));
+        }
+
+        self.subscriber_command_executor
+            .execute(SubscriberCommand::VerifySubscriberEmailAs(
+                VerifySubscriberEmailAs::new(
+                    subscriber_id,
+                    SubscriberEmailVerifiationStatus::Invalid,
+                ),
+            ))
+            .await
+            .map_err(MailFeedbackError::SubscriberOperationFailed)?;
+
+        Ok(MailFeedbackOutcome::SubscriberInvalidated(subscriber_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{
+        Duration,
+        Utc,
+    };
+
+    use crate::delivery::model::prelude::{
+        Delivery,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+    };
+    use crate::subscriber::prelude::{
+        FakeSubscriberRepository,
+        Subscriber,
+    };
+
+    use super::*;
+
+    type Handler = MailFeedbackHandler<
+        FakeSubscriberRepository,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+    >;
+
+    async fn prepare() -> (Handler, FakeSubscriberRepository, Uuid) {
+        let subscriber_repository = FakeSubscriberRepository::new();
+        let delivery_repository = FakeDeliveryRepository::new();
+
+        let mut subscriber = Subscriber::default();
+        subscriber.create(
+            Uuid::new_v4(),
+            "arine@example.com".to_string(),
+            "Arine".to_string(),
+        );
+        subscriber
+            .verified_email_as(&SubscriberEmailVerifiationStatus::Valid)
+            .unwrap();
+        subscriber_repository.save(&mut subscriber).await.unwrap();
+        delivery_repository
+            .enqueue(&[Delivery::new(
+                Uuid::new_v4(),
+                subscriber.id,
+                "arine@example.com".to_string(),
+            )])
+            .await
+            .unwrap();
+
+        let handler = MailFeedbackHandler::new(
+            subscriber_repository.clone(),
+            delivery_repository,
+            FakeMailFeedbackRepository::new(),
+            MailFeedbackOptions {
+                soft_bounce_threshold: 2,
+                soft_bounce_window: Duration::days(7),
+            },
+        );
+        (handler, subscriber_repository, subscriber.id)
+    }
+
+    fn feedback(id: &str, kind: MailFeedbackKind) -> MailFeedback {
+        MailFeedback::new(
+            id.to_string(),
+            kind,
+            "Arine@Example.com".to_string(),
+            None,
+            Utc::now(),
+        )
+    }
+
+    async fn verification_status(
+        subscriber_repository: &FakeSubscriberRepository,
+        id: Uuid,
+    ) -> SubscriberEmailVerifiationStatus {
+        subscriber_repository
+            .find_by_id(id)
+            .await
+            .unwrap()
+            .unwrap()
+            .email
+            .verification_status
+    }
+
+    #[tokio::test]
+    async fn hard_bounce_and_complaint_invalidate_subscriber_at_once() {
+        for kind in [MailFeedbackKind::HardBounce, MailFeedbackKind::Complaint] {
+            // given
+            let (handler, subscriber_repository, id) = prepare().await;
+
+            // when
+            let outcome = handler.handle(&feedback("1", kind)).await.unwrap();
+
+            // then
+            assert_eq!(outcome, MailFeedbackOutcome::SubscriberInvalidated(id));
+            assert_eq!(
+                verification_status(&subscriber_repository, id).await,
+                SubscriberEmailVerifiationStatus::Invalid
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn soft_bounces_invalidate_subscriber_once_they_reach_threshold() {
+        // given
+        let (handler, subscriber_repository, id) = prepare().await;
+
+        // when
+        let first = handler
+            .handle(&feedback("1", MailFeedbackKind::SoftBounce))
+            .await
+            .unwrap();
+        let first_again = handler
+            .handle(&feedback("1", MailFeedbackKind::SoftBounce))
+            .await
+            .unwrap();
+        let second = handler
+            .handle(&feedback("2", MailFeedbackKind::SoftBounce))
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(first, MailFeedbackOutcome::SoftBounceCounted(id, 1));
+        assert_eq!(first_again, MailFeedbackOutcome::SoftBounceCounted(id, 1));
+        assert_eq!(second, MailFeedbackOutcome::SubscriberInvalidated(id));
+        assert_eq!(
+            verification_status(&subscriber_repository, id).await,
+            SubscriberEmailVerifiationStatus::Invalid
+        );
+    }
+
+    #[tokio::test]
+    async fn soft_bounces_outside_window_are_not_counted() {
+        // given
+        let (handler, subscriber_repository, id) = prepare().await;
+        let mut old = feedback("1", MailFeedbackKind::SoftBounce);
+        old.occurred_at = Utc::now() - Duration::days(30);
+        handler.handle(&old).await.unwrap();
+
+        // when
+        let outcome = handler
+            .handle(&feedback("2", MailFeedbackKind::SoftBounce))
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(outcome, MailFeedbackOutcome::SoftBounceCounted(id, 1));
+        assert_eq!(
+            verification_status(&subscriber_repository, id).await,
+            SubscriberEmailVerifiationStatus::Valid
+        );
+    }
+
+    #[tokio::test]
+    async fn feedback_for_address_never_delivered_to_is_ignored() {
+        // given
+        let (handler, _, _) = prepare().await;
+        let mut unknown = feedback("1", MailFeedbackKind::HardBounce);
+        unknown.email_address = "unknown@example.com".to_string();
+
+        // when
+        let outcome = handler.handle(&unknown).await.unwrap();
+
+        // then
+        assert_eq!(outcome, MailFeedbackOutcome::UnknownRecipient);
+    }
+}
@@ -0,0 +1,3 @@
+mod deliverer;
+mod feedback;
+pub mod prelude;
@@ -0,0 +1,2 @@
+pub use crate::delivery::service::deliverer::*;
+pub use crate::delivery::service::feedback::*;
@@ -0,0 +1,3 @@
+mod model;
+pub mod prelude;
+mod service;
@@ -0,0 +1,450 @@
+use std::fmt::{
+    Display,
+    Formatter,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::error::IssueError;
+use crate::issue::model::events::{
+    IssueCancelled,
+    IssueDrafted,
+    IssueEvent,
+    IssuePublished,
+    IssueReturnedToDraft,
+    IssueRevised,
+    IssueScheduled,
+    IssueSendingStarted,
+    IssueSent,
+    IssueSubmittedForReview,
+};
+use crate::rendering::prelude::{
+    RenderedIssue,
+    Template,
+};
+
+#[derive(Default, Clone, Debug)]
+pub struct Issue {
+    pub id: Uuid,
+    pub title: String,
+    pub subject: String,
+    pub body: String,
+    pub author: String,
+    pub status: IssueStatus,
+    // time to start sending the issue at, or as soon as possible if none
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub pending_events: Vec<IssueEvent>,
+    // version of the persisted state this issue was loaded from, for optimistic locking
+    pub version: i64,
+}
+
+impl Issue {
+    pub fn draft(
+        &mut self,
+        id: Uuid,
+        title: String,
+        subject: String,
+        body: String,
+        author: String,
+    ) -> Result<(), IssueError> {
+        Self::validate(&title, &subject, &body)?;
+
+        let event = IssueEvent::IssueDrafted(IssueDrafted::new(id, title, subject, body, author));
+        self.apply(event);
+        Ok(())
+    }
+
+    // Only drafts are editable, so that reviewed content is what gets sent
+    pub fn revise(
+        &mut self,
+        title: String,
+        subject: String,
+        body: String,
+    ) -> Result<(), IssueError> {
+        if self.status != IssueStatus::Draft {
+            return Err(IssueError::IssueNotEditable(self.status));
+        }
+        Self::validate(&title, &subject, &body)?;
+
+        let event = IssueEvent::IssueRevised(IssueRevised::new(title, subject, body));
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn submit_for_review(&mut self) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::InReview)?;
+        if self.body.trim().is_empty() {
+            return Err(IssueError::InvalidIssueBody);
+        }
+
+        let event = IssueEvent::IssueSubmittedForReview(IssueSubmittedForReview::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn return_to_draft(&mut self) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Draft)?;
+
+        let event = IssueEvent::IssueReturnedToDraft(IssueReturnedToDraft::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn publish(&mut self) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Scheduled)?;
+
+        let event = IssueEvent::IssuePublished(IssuePublished::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    // Scheduling an issue scheduled already reschedules it, until sending starts
+    pub fn schedule(&mut self, at: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Scheduled)?;
+        if at <= now {
+            return Err(IssueError::InvalidIssueSchedule(at));
+        }
+
+        let event = IssueEvent::IssueScheduled(IssueScheduled::new(at));
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn start_sending(&mut self, now: DateTime<Utc>) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Sending)?;
+        if !self.is_due(now) {
+            return Err(IssueError::IssueNotDue(self.id));
+        }
+
+        let event = IssueEvent::IssueSendingStarted(IssueSendingStarted::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.status == IssueStatus::Scheduled
+            && self
+                .scheduled_at
+                .is_none_or(|scheduled_at| scheduled_at <= now)
+    }
+
+    pub fn mark_as_sent(&mut self) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Sent)?;
+
+        let event = IssueEvent::IssueSent(IssueSent::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    pub fn cancel(&mut self) -> Result<(), IssueError> {
+        self.ensure_transition_to(IssueStatus::Cancelled)?;
+
+        let event = IssueEvent::IssueCancelled(IssueCancelled::new());
+        self.apply(event);
+        Ok(())
+    }
+
+    // Personalized content is validated on saving, so that sending doesn't fail on it later
+    fn validate(title: &str, subject: &str, body: &str) -> Result<(), IssueError> {
+        if title.trim().is_empty() {
+            return Err(IssueError::InvalidIssueTitle);
+        }
+        if subject.trim().is_empty() {
+            return Err(IssueError::InvalidIssueSubject);
+        }
+        Template::parse(subject).map_err(IssueError::InvalidIssueTemplate)?;
+        Template::parse(body).map_err(IssueError::InvalidIssueTemplate)?;
+        RenderedIssue::check_body(body).map_err(IssueError::InvalidIssueTemplate)?;
+        Ok(())
+    }
+
+    fn ensure_transition_to(&self, to: IssueStatus) -> Result<(), IssueError> {
+        if !self.status.can_move_to(to) {
+            return Err(IssueError::InvalidIssueStatusTransition {
+                from: self.status,
+                to,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IssueStatus {
+    #[default]
+    Draft,
+    InReview,
+    Scheduled,
+    Sending,
+    Sent,
+    Cancelled,
+}
+
+impl IssueStatus {
+    // Draft → InReview → Scheduled → Sending → Sent, where a review can send the issue back to
+    // draft, a scheduled issue can be rescheduled and an issue can be cancelled any time before
+    // sending starts
+    pub fn can_move_to(&self, to: IssueStatus) -> bool {
+        matches!(
+            (self, to),
+            (IssueStatus::Draft, IssueStatus::InReview)
+                | (IssueStatus::InReview, IssueStatus::Draft)
+                | (
+                    IssueStatus::InReview | IssueStatus::Scheduled,
+                    IssueStatus::Scheduled
+                )
+                | (IssueStatus::Scheduled, IssueStatus::Sending)
+                | (IssueStatus::Sending, IssueStatus::Sent)
+                | (
+                    IssueStatus::Draft | IssueStatus::InReview | IssueStatus::Scheduled,
+                    IssueStatus::Cancelled
+                )
+        )
+    }
+}
+
+impl Display for IssueStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            IssueStatus::Draft => "Draft",
+            IssueStatus::InReview => "InReview",
+            IssueStatus::Scheduled => "Scheduled",
+            IssueStatus::Sending => "Sending",
+            IssueStatus::Sent => "Sent",
+            IssueStatus::Cancelled => "Cancelled",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+impl Issue {
+    pub fn apply(&mut self, event: IssueEvent) {
+        self.pending_events.push(event.clone());
+        self.mutate(event);
+    }
+
+    fn mutate(&mut self, event: IssueEvent) {
+        match event {
+            IssueEvent::IssueDrafted(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueRevised(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueSubmittedForReview(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueReturnedToDraft(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssuePublished(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueScheduled(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueSendingStarted(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueSent(event) => {
+                event.apply(self);
+            }
+            IssueEvent::IssueCancelled(event) => {
+                event.apply(self);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use claims::{
+        assert_err,
+        assert_matches,
+        assert_ok,
+    };
+
+    use crate::rendering::prelude::TemplateError;
+
+    use super::*;
+
+    fn get_draft() -> Issue {
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                Uuid::new_v4(),
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, subscribers".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        issue
+    }
+
+    #[test]
+    fn drafting_issue_with_empty_title_is_rejected() {
+        // given
+        let mut issue = Issue::default();
+
+        // when
+        let response = issue.draft(
+            Uuid::new_v4(),
+            " ".to_string(),
+            "What's new this week".to_string(),
+            "".to_string(),
+            "arine@example.com".to_string(),
+        );
+
+        // then
+        assert_matches!(response, Err(IssueError::InvalidIssueTitle));
+        assert!(issue.pending_events.is_empty());
+    }
+
+    #[test]
+    fn issue_goes_through_review_to_sent() {
+        // given
+        let mut issue = get_draft();
+
+        // when
+        issue.submit_for_review().unwrap();
+        issue.publish().unwrap();
+        issue.start_sending(Utc::now()).unwrap();
+        issue.mark_as_sent().unwrap();
+
+        // then
+        assert_eq!(issue.status, IssueStatus::Sent);
+        assert_eq!(issue.pending_events.len(), 5);
+        assert_err!(issue.cancel());
+    }
+
+    #[test]
+    fn reviewed_issue_is_not_editable_until_returned_to_draft() {
+        // given
+        let mut issue = get_draft();
+        issue.submit_for_review().unwrap();
+
+        // when
+        let response = issue.revise(
+            "Weekly #1".to_string(),
+            "What's new".to_string(),
+            "Hello".to_string(),
+        );
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::IssueNotEditable(IssueStatus::InReview))
+        );
+        issue.return_to_draft().unwrap();
+        assert_ok!(issue.revise(
+            "Weekly #1".to_string(),
+            "What's new".to_string(),
+            "Hello".to_string(),
+        ));
+        assert_eq!(issue.subject, "What's new");
+    }
+
+    #[test]
+    fn publishing_issue_without_review_is_rejected() {
+        // given
+        let mut issue = get_draft();
+
+        // when
+        let response = issue.publish();
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::InvalidIssueStatusTransition {
+                from: IssueStatus::Draft,
+                to: IssueStatus::Scheduled,
+            })
+        );
+    }
+
+    #[test]
+    fn scheduled_issue_is_due_at_its_time_and_can_be_rescheduled_until_sending_starts() {
+        // given
+        let mut issue = get_draft();
+        issue.submit_for_review().unwrap();
+        let now = Utc::now();
+        issue.schedule(now + Duration::hours(1), now).unwrap();
+
+        // when
+        let response = issue.start_sending(now);
+
+        // then
+        assert_matches!(response, Err(IssueError::IssueNotDue(_)));
+        issue
+            .schedule(now + Duration::hours(2), now + Duration::minutes(30))
+            .unwrap();
+        assert_eq!(issue.scheduled_at, Some(now + Duration::hours(2)));
+        assert!(issue.is_due(now + Duration::hours(2)));
+        issue.start_sending(now + Duration::hours(2)).unwrap();
+        assert_err!(issue.schedule(now + Duration::hours(3), now + Duration::hours(2)));
+        assert_err!(issue.cancel());
+    }
+
+    #[test]
+    fn scheduling_issue_in_past_is_rejected() {
+        // given
+        let mut issue = get_draft();
+        issue.submit_for_review().unwrap();
+        let now = Utc::now();
+
+        // when
+        let response = issue.schedule(now - Duration::minutes(1), now);
+
+        // then
+        assert_matches!(response, Err(IssueError::InvalidIssueSchedule(_)));
+        assert_eq!(issue.status, IssueStatus::InReview);
+    }
+
+    #[test]
+    fn revising_issue_with_unknown_template_variable_is_rejected() {
+        // given
+        let mut issue = get_draft();
+
+        // when
+        let response = issue.revise(
+            "Weekly #1".to_string(),
+            "What's new this week".to_string(),
+            "Hello, {{ subscriber.nickname }}".to_string(),
+        );
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::InvalidIssueTemplate(
+                TemplateError::UnknownVariable(_)
+            ))
+        );
+        assert_eq!(issue.body, "Hello, subscribers");
+    }
+
+    #[test]
+    fn revising_issue_with_subscriber_variable_in_link_is_rejected() {
+        // given
+        let mut issue = get_draft();
+
+        // when
+        let response = issue.revise(
+            "Weekly #1".to_string(),
+            "What's new this week".to_string(),
+            "[Hello]({{ subscriber.name }}), [leave]({{ unsubscribe_url }})".to_string(),
+        );
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::InvalidIssueTemplate(TemplateError::TagInUrl(_)))
+        );
+        assert_eq!(issue.body, "Hello, subscribers");
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::entities::IssueStatus;
+use crate::rendering::prelude::TemplateError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum IssueError {
+    #[error("Issue's title is empty")]
+    InvalidIssueTitle,
+
+    #[error("Issue's subject is empty")]
+    InvalidIssueSubject,
+
+    #[error("Issue's body is empty")]
+    InvalidIssueBody,
+
+    #[error("Issue's subject or body has an invalid template")]
+    InvalidIssueTemplate(#[source] TemplateError),
+
+    #[error("Issue can't be scheduled at {0}, which isn't in the future")]
+    InvalidIssueSchedule(DateTime<Utc>),
+
+    #[error("Issue can't be revised in status {0}")]
+    IssueNotEditable(IssueStatus),
+
+    #[error("Issue can't move from status {from} to {to}")]
+    InvalidIssueStatusTransition { from: IssueStatus, to: IssueStatus },
+
+    #[error("Issue (ID: {0}) isn't due to be sent yet")]
+    IssueNotDue(Uuid),
+
+    #[error("Issue (ID: {0}) doesn't exist")]
+    IssueNotFound(Uuid),
+
+    #[error("Issue (ID: {0}) already exists")]
+    IssueAlreadyExists(Uuid),
+
+    #[error("Single issue is expected, but found multiple issues")]
+    MultipleIssuesFound,
+
+    #[error("Issue (ID: {0}) was modified concurrently")]
+    ConcurrentModification(Uuid),
+
+    #[error("Failed to operator on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+
+    #[error("Failed unexpectedly")]
+    Unexpected(#[source] anyhow::Error),
+}
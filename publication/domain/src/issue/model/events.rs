@@ -0,0 +1,178 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::entities::{
+    Issue,
+    IssueStatus,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IssueEvent {
+    IssueDrafted(IssueDrafted),
+    IssueRevised(IssueRevised),
+    IssueSubmittedForReview(IssueSubmittedForReview),
+    IssueReturnedToDraft(IssueReturnedToDraft),
+    IssuePublished(IssuePublished),
+    IssueScheduled(IssueScheduled),
+    IssueSendingStarted(IssueSendingStarted),
+    IssueSent(IssueSent),
+    IssueCancelled(IssueCancelled),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueDrafted {
+    pub id: Uuid,
+    pub title: String,
+    pub subject: String,
+    pub body: String,
+    pub author: String,
+}
+
+impl IssueDrafted {
+    pub fn new(id: Uuid, title: String, subject: String, body: String, author: String) -> Self {
+        Self {
+            id,
+            title,
+            subject,
+            body,
+            author,
+        }
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.id = self.id;
+        issue.title = self.title;
+        issue.subject = self.subject;
+        issue.body = self.body;
+        issue.author = self.author;
+        issue.status = IssueStatus::Draft;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueRevised {
+    pub title: String,
+    pub subject: String,
+    pub body: String,
+}
+
+impl IssueRevised {
+    pub fn new(title: String, subject: String, body: String) -> Self {
+        Self {
+            title,
+            subject,
+            body,
+        }
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.title = self.title;
+        issue.subject = self.subject;
+        issue.body = self.body;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueSubmittedForReview {}
+
+impl IssueSubmittedForReview {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::InReview;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueReturnedToDraft {}
+
+impl IssueReturnedToDraft {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Draft;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssuePublished {}
+
+impl IssuePublished {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // published issues are sent as soon as possible
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Scheduled;
+        issue.scheduled_at = None;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueScheduled {
+    pub scheduled_at: DateTime<Utc>,
+}
+
+impl IssueScheduled {
+    pub fn new(scheduled_at: DateTime<Utc>) -> Self {
+        Self { scheduled_at }
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Scheduled;
+        issue.scheduled_at = Some(self.scheduled_at);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueSendingStarted {}
+
+impl IssueSendingStarted {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Sending;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueSent {}
+
+impl IssueSent {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Sent;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IssueCancelled {}
+
+impl IssueCancelled {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn apply(self, issue: &mut Issue) {
+        issue.status = IssueStatus::Cancelled;
+    }
+}
@@ -0,0 +1,5 @@
+mod entities;
+mod error;
+mod events;
+pub mod prelude;
+mod repository;
@@ -0,0 +1,10 @@
+pub use crate::issue::model::entities::{
+    Issue,
+    IssueStatus,
+};
+pub use crate::issue::model::error::IssueError;
+pub use crate::issue::model::events::*;
+pub use crate::issue::model::repository::{
+    FakeIssueRepository,
+    IssueRepository,
+};
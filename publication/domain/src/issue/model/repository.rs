@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::entities::{
+    Issue,
+    IssueStatus,
+};
+use crate::issue::model::error::IssueError;
+use crate::issue::model::events::IssueEvent;
+
+#[async_trait::async_trait]
+pub trait IssueRepository: Send + Sync + Clone {
+    // Persist a new issue, and must fail with already exists if the issue ID exists
+    async fn save(&self, issue: &mut Issue) -> Result<(), IssueError>;
+
+    // Find an issue by ID and fail if it doesn't exist
+    // After that modify by the modifier function and persist the changes
+    // Fail with concurrent modification if the issue has been changed in the meantime
+    async fn modify<F>(&self, id: Uuid, mut modifier: F) -> Result<(), IssueError>
+    where
+        F: FnMut(&mut Issue) -> Result<(), IssueError> + Send;
+
+    // Find an issue by ID
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Issue>, IssueError>;
+
+    // Find all issues
+    async fn find_all(&self) -> Result<Vec<Issue>, IssueError>;
+
+    // Find issues in the status
+    async fn find_by_status(&self, status: IssueStatus) -> Result<Vec<Issue>, IssueError>;
+
+    // Find scheduled issues due to be sent at the time
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<Issue>, IssueError>;
+}
+
+#[derive(Clone)]
+pub struct FakeIssueRepository {
+    items: Arc<RwLock<HashMap<Uuid, Issue>>>,
+    events: Arc<RwLock<HashMap<Uuid, Vec<IssueEvent>>>>,
+}
+
+impl FakeIssueRepository {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            items: Arc::new(RwLock::new(HashMap::new())),
+            events: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub async fn find_events_by_id(&self, id: Uuid) -> Result<Vec<IssueEvent>, IssueError> {
+        let store = self.events.read().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        store.get(&id).cloned().ok_or(IssueError::IssueNotFound(id))
+    }
+}
+
+#[async_trait::async_trait]
+impl IssueRepository for FakeIssueRepository {
+    async fn save(&self, issue: &mut Issue) -> Result<(), IssueError> {
+        let mut item_store = self.items.write().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+        let mut event_store = self.events.write().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        if item_store.contains_key(&issue.id) {
+            return Err(IssueError::IssueAlreadyExists(issue.id));
+        }
+
+        let mut events: Vec<IssueEvent> = issue.pending_events.drain(..).collect();
+        item_store.insert(issue.id, issue.clone());
+        event_store
+            .entry(issue.id)
+            .or_insert_with(Vec::new)
+            .append(&mut events);
+
+        Ok(())
+    }
+
+    async fn modify<F>(&self, id: Uuid, mut modifier: F) -> Result<(), IssueError>
+    where
+        F: FnMut(&mut Issue) -> Result<(), IssueError> + Send,
+    {
+        let mut item_store = self.items.write().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+        let mut event_store = self.events.write().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let mut issue = item_store
+            .get(&id)
+            .cloned()
+            .ok_or(IssueError::IssueNotFound(id))?;
+
+        modifier(&mut issue)?;
+        let mut events: Vec<IssueEvent> = issue.pending_events.drain(..).collect();
+        issue.version += 1;
+
+        item_store.insert(id, issue.clone());
+        event_store
+            .entry(id)
+            .or_insert_with(Vec::new)
+            .append(&mut events);
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Issue>, IssueError> {
+        let store = self.items.read().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store.get(&id).cloned())
+    }
+
+    async fn find_all(&self) -> Result<Vec<Issue>, IssueError> {
+        let store = self.items.read().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store.values().cloned().collect::<Vec<Issue>>())
+    }
+
+    async fn find_by_status(&self, status: IssueStatus) -> Result<Vec<Issue>, IssueError> {
+        let store = self.items.read().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store
+            .values()
+            .filter(|issue| issue.status == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<Issue>, IssueError> {
+        let store = self.items.read().map_err(|_| {
+            IssueError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store
+            .values()
+            .filter(|issue| issue.is_due(now))
+            .cloned()
+            .collect())
+    }
+}
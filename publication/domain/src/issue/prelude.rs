@@ -0,0 +1,2 @@
+pub use crate::issue::model::prelude::*;
+pub use crate::issue::service::prelude::*;
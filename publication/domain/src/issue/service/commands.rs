@@ -0,0 +1,339 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::prelude::{
+    Issue,
+    IssueError,
+    IssueRepository,
+};
+
+pub enum IssueCommand {
+    DraftIssue(DraftIssue),
+    ReviseIssue(ReviseIssue),
+    SubmitIssueForReview(SubmitIssueForReview),
+    ReturnIssueToDraft(ReturnIssueToDraft),
+    PublishIssue(PublishIssue),
+    ScheduleIssue(ScheduleIssue),
+    CancelIssue(CancelIssue),
+}
+
+#[derive(Clone)]
+pub struct IssueCommandExecutor<R>
+where
+    R: IssueRepository,
+{
+    repository: R,
+}
+
+impl<R> IssueCommandExecutor<R>
+where
+    R: IssueRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, command: IssueCommand) -> Result<(), IssueError> {
+        match command {
+            IssueCommand::DraftIssue(command) => command.execute(self.repository.clone()).await,
+            IssueCommand::ReviseIssue(command) => command.execute(self.repository.clone()).await,
+            IssueCommand::SubmitIssueForReview(command) => {
+                command.execute(self.repository.clone()).await
+            }
+            IssueCommand::ReturnIssueToDraft(command) => {
+                command.execute(self.repository.clone()).await
+            }
+            IssueCommand::PublishIssue(command) => command.execute(self.repository.clone()).await,
+            IssueCommand::ScheduleIssue(command) => command.execute(self.repository.clone()).await,
+            IssueCommand::CancelIssue(command) => command.execute(self.repository.clone()).await,
+        }
+    }
+}
+
+pub struct DraftIssue {
+    id: Uuid,
+    title: String,
+    subject: String,
+    body: String,
+    author: String,
+}
+
+impl DraftIssue {
+    pub fn new(id: Uuid, title: String, subject: String, body: String, author: String) -> Self {
+        Self {
+            id,
+            title,
+            subject,
+            body,
+            author,
+        }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        let mut issue = Issue::default();
+        issue.draft(
+            self.id,
+            self.title.clone(),
+            self.subject.clone(),
+            self.body.clone(),
+            self.author.clone(),
+        )?;
+
+        repository.save(&mut issue).await
+    }
+}
+
+pub struct ReviseIssue {
+    id: Uuid,
+    title: String,
+    subject: String,
+    body: String,
+}
+
+impl ReviseIssue {
+    pub fn new(id: Uuid, title: String, subject: String, body: String) -> Self {
+        Self {
+            id,
+            title,
+            subject,
+            body,
+        }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository
+            .modify(self.id, |issue| {
+                issue.revise(self.title.clone(), self.subject.clone(), self.body.clone())
+            })
+            .await
+    }
+}
+
+pub struct SubmitIssueForReview {
+    id: Uuid,
+}
+
+impl SubmitIssueForReview {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository
+            .modify(self.id, |issue| issue.submit_for_review())
+            .await
+    }
+}
+
+pub struct ReturnIssueToDraft {
+    id: Uuid,
+}
+
+impl ReturnIssueToDraft {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository
+            .modify(self.id, |issue| issue.return_to_draft())
+            .await
+    }
+}
+
+pub struct PublishIssue {
+    id: Uuid,
+}
+
+impl PublishIssue {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository.modify(self.id, |issue| issue.publish()).await
+    }
+}
+
+pub struct ScheduleIssue {
+    id: Uuid,
+    scheduled_at: DateTime<Utc>,
+}
+
+impl ScheduleIssue {
+    pub fn new(id: Uuid, scheduled_at: DateTime<Utc>) -> Self {
+        Self { id, scheduled_at }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository
+            .modify(self.id, |issue| {
+                issue.schedule(self.scheduled_at, Utc::now())
+            })
+            .await
+    }
+}
+
+pub struct CancelIssue {
+    id: Uuid,
+}
+
+impl CancelIssue {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn execute(&self, repository: impl IssueRepository) -> Result<(), IssueError> {
+        repository.modify(self.id, |issue| issue.cancel()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::*;
+
+    use crate::issue::prelude::{
+        FakeIssueRepository,
+        IssueEvent,
+        IssueStatus,
+    };
+
+    use super::*;
+
+    async fn draft_issue(repository: impl IssueRepository) -> Uuid {
+        let id = Uuid::new_v4();
+        let command = DraftIssue::new(
+            id,
+            "Weekly #1".to_string(),
+            "What's new this week".to_string(),
+            "Hello, subscribers".to_string(),
+            "arine@example.com".to_string(),
+        );
+        command.execute(repository).await.unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn draft_issue_twice_with_same_issue_id_fails() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let id = draft_issue(repository.clone()).await;
+
+        // when
+        let command = DraftIssue::new(
+            id,
+            "Weekly #2".to_string(),
+            "What's new".to_string(),
+            "".to_string(),
+            "arine@example.com".to_string(),
+        );
+        let response = command.execute(repository).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::IssueAlreadyExists(existing_id)) if existing_id == id
+        );
+    }
+
+    #[tokio::test]
+    async fn publishing_reviewed_issue_schedules_it() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let id = draft_issue(repository.clone()).await;
+        let executor = IssueCommandExecutor::new(repository.clone());
+        executor
+            .execute(IssueCommand::SubmitIssueForReview(
+                SubmitIssueForReview::new(id),
+            ))
+            .await
+            .unwrap();
+
+        // when
+        executor
+            .execute(IssueCommand::PublishIssue(PublishIssue::new(id)))
+            .await
+            .unwrap();
+
+        // then
+        let issue = repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(issue.status, IssueStatus::Scheduled);
+        let events = repository.find_events_by_id(id).await.unwrap();
+        assert_eq!(events.len(), 3);
+        assert_matches!(events.last().unwrap(), IssueEvent::IssuePublished(_));
+    }
+
+    #[tokio::test]
+    async fn rescheduling_scheduled_issue_moves_its_time() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let id = draft_issue(repository.clone()).await;
+        SubmitIssueForReview::new(id)
+            .execute(repository.clone())
+            .await
+            .unwrap();
+        let scheduled_at = Utc::now() + chrono::Duration::days(1);
+        ScheduleIssue::new(id, scheduled_at)
+            .execute(repository.clone())
+            .await
+            .unwrap();
+
+        // when
+        let rescheduled_at = scheduled_at + chrono::Duration::days(1);
+        ScheduleIssue::new(id, rescheduled_at)
+            .execute(repository.clone())
+            .await
+            .unwrap();
+
+        // then
+        let issue = repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(issue.status, IssueStatus::Scheduled);
+        assert_eq!(issue.scheduled_at, Some(rescheduled_at));
+        let events = repository.find_events_by_id(id).await.unwrap();
+        assert_matches!(events.last().unwrap(), IssueEvent::IssueScheduled(_));
+    }
+
+    #[tokio::test]
+    async fn revising_issue_in_review_fails_without_changing_it() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let id = draft_issue(repository.clone()).await;
+        SubmitIssueForReview::new(id)
+            .execute(repository.clone())
+            .await
+            .unwrap();
+
+        // when
+        let command = ReviseIssue::new(
+            id,
+            "Weekly #1".to_string(),
+            "Changed".to_string(),
+            "Changed".to_string(),
+        );
+        let response = command.execute(repository.clone()).await;
+
+        // then
+        assert_matches!(response, Err(IssueError::IssueNotEditable(_)));
+        let issue = repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(issue.subject, "What's new this week");
+    }
+
+    #[tokio::test]
+    async fn cancelling_not_existing_issue_fails() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let id = Uuid::new_v4();
+
+        // when
+        let response = CancelIssue::new(id).execute(repository).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::IssueNotFound(not_found_id)) if not_found_id == id
+        );
+    }
+}
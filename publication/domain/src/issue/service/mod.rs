@@ -0,0 +1,4 @@
+mod commands;
+pub mod prelude;
+mod queries;
+mod scheduler;
@@ -0,0 +1,3 @@
+pub use crate::issue::service::commands::*;
+pub use crate::issue::service::queries::*;
+pub use crate::issue::service::scheduler::*;
@@ -0,0 +1,126 @@
+use uuid::Uuid;
+
+use crate::issue::prelude::{
+    Issue,
+    IssueError,
+    IssueRepository,
+};
+
+pub enum IssueQuery {
+    InquiryIssue(InquiryIssue),
+    InquiryAllIssues(InquiryAllIssues),
+}
+
+#[derive(Debug)]
+pub enum IssueQueryResult {
+    Single(Issue),
+    Multiple(Vec<Issue>),
+}
+
+impl TryFrom<IssueQueryResult> for Issue {
+    type Error = IssueError;
+
+    fn try_from(result: IssueQueryResult) -> Result<Self, Self::Error> {
+        match result {
+            IssueQueryResult::Single(issue) => Ok(issue),
+            IssueQueryResult::Multiple(_) => Err(IssueError::MultipleIssuesFound),
+        }
+    }
+}
+
+impl TryFrom<IssueQueryResult> for Vec<Issue> {
+    type Error = IssueError;
+
+    fn try_from(result: IssueQueryResult) -> Result<Self, Self::Error> {
+        match result {
+            IssueQueryResult::Single(issue) => Ok(vec![issue]),
+            IssueQueryResult::Multiple(issues) => Ok(issues),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IssueQueryReader<R>
+where
+    R: IssueRepository,
+{
+    repository: R,
+}
+
+impl<R> IssueQueryReader<R>
+where
+    R: IssueRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn read(&self, query: IssueQuery) -> Result<IssueQueryResult, IssueError> {
+        match query {
+            IssueQuery::InquiryIssue(query) => query
+                .read(self.repository.clone())
+                .await
+                .map(IssueQueryResult::Single),
+            IssueQuery::InquiryAllIssues(query) => query
+                .read(self.repository.clone())
+                .await
+                .map(IssueQueryResult::Multiple),
+        }
+    }
+}
+
+pub struct InquiryIssue {
+    id: Uuid,
+}
+
+impl InquiryIssue {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn read(&self, repository: impl IssueRepository) -> Result<Issue, IssueError> {
+        repository
+            .find_by_id(self.id)
+            .await?
+            .ok_or(IssueError::IssueNotFound(self.id))
+    }
+}
+
+pub struct InquiryAllIssues {}
+
+impl InquiryAllIssues {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    async fn read(&self, repository: impl IssueRepository) -> Result<Vec<Issue>, IssueError> {
+        repository.find_all().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_matches;
+
+    use crate::issue::prelude::FakeIssueRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inquiry_issue_fails_if_id_doesnt_exist() {
+        // given
+        let query_reader = IssueQueryReader::new(FakeIssueRepository::new());
+        let id = Uuid::new_v4();
+
+        // when
+        let query = IssueQuery::InquiryIssue(InquiryIssue::new(id));
+        let response = query_reader.read(query).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(IssueError::IssueNotFound(not_found_id)) if not_found_id == id
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::issue::model::prelude::{
+    IssueError,
+    IssueRepository,
+    IssueStatus,
+};
+
+// Issues found due by a scheduling
+#[derive(Debug, Default, PartialEq)]
+pub struct IssueSchedulingReport {
+    // moved to sending by this scheduling
+    pub started: Vec<Uuid>,
+    // started, rescheduled or cancelled by someone else in the meantime
+    pub skipped: Vec<Uuid>,
+    // failed to start, they are found again on the next scheduling
+    pub failed: Vec<Uuid>,
+}
+
+// Starts sending scheduled issues once they are due
+// Starting is a modification of the issue checked against its version, so an issue is started
+// exactly once even if schedulers of several instances find it at the same time
+#[derive(Clone)]
+pub struct IssueScheduler<R>
+where
+    R: IssueRepository,
+{
+    repository: R,
+}
+
+impl<R> IssueScheduler<R>
+where
+    R: IssueRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn start_due_issues(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<IssueSchedulingReport, IssueError> {
+        let mut report = IssueSchedulingReport::default();
+
+        for issue in self.repository.find_due(now).await? {
+            match self
+                .repository
+                .modify(issue.id, |issue| issue.start_sending(now))
+                .await
+            {
+                Ok(()) => report.started.push(issue.id),
+                // the issue isn't scheduled or due any more when read again
+                Err(IssueError::IssueNotDue(_))
+                | Err(IssueError::IssueNotFound(_))
+                | Err(IssueError::InvalidIssueStatusTransition {
+                    to: IssueStatus::Sending,
+                    ..
+                }) => report.skipped.push(issue.id),
+                Err(_) => report.failed.push(issue.id),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::issue::prelude::{
+        FakeIssueRepository,
+        Issue,
+    };
+
+    use super::*;
+
+    async fn schedule_issue(
+        repository: &FakeIssueRepository,
+        scheduled_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Uuid {
+        let id = Uuid::new_v4();
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, subscribers".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        issue.submit_for_review().unwrap();
+        match scheduled_at {
+            Some(scheduled_at) => issue.schedule(scheduled_at, now).unwrap(),
+            None => issue.publish().unwrap(),
+        }
+        repository.save(&mut issue).await.unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn scheduling_starts_due_issues_only() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let now = Utc::now();
+        let published = schedule_issue(&repository, None, now).await;
+        let due = schedule_issue(&repository, Some(now + Duration::minutes(1)), now).await;
+        let not_due = schedule_issue(&repository, Some(now + Duration::hours(1)), now).await;
+        let scheduler = IssueScheduler::new(repository.clone());
+
+        // when
+        let mut report = scheduler
+            .start_due_issues(now + Duration::minutes(1))
+            .await
+            .unwrap();
+
+        // then
+        report.started.sort();
+        let mut expected = vec![published, due];
+        expected.sort();
+        assert_eq!(report.started, expected);
+        let issue = repository.find_by_id(not_due).await.unwrap().unwrap();
+        assert_eq!(issue.status, IssueStatus::Scheduled);
+    }
+
+    #[tokio::test]
+    async fn scheduling_again_doesnt_start_issue_twice() {
+        // given
+        let repository = FakeIssueRepository::new();
+        let now = Utc::now();
+        let id = schedule_issue(&repository, None, now).await;
+        let scheduler = IssueScheduler::new(repository.clone());
+        scheduler.start_due_issues(now).await.unwrap();
+
+        // when
+        let report = scheduler.start_due_issues(now).await.unwrap();
+
+        // then
+        assert_eq!(report, IssueSchedulingReport::default());
+        let events = repository.find_events_by_id(id).await.unwrap();
+        assert_eq!(events.len(), 4);
+    }
+}
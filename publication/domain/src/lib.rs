@@ -1,2 +1,5 @@
+mod delivery;
+mod issue;
 pub mod prelude;
+mod rendering;
 mod subscriber;
@@ -1 +1,4 @@
+pub use crate::delivery::prelude::*;
+pub use crate::issue::prelude::*;
+pub use crate::rendering::prelude::*;
 pub use crate::subscriber::prelude::*;
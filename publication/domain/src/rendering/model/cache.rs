@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use uuid::Uuid;
+
+use crate::rendering::model::entities::RenderedIssue;
+use crate::rendering::model::error::RenderingError;
+
+#[async_trait::async_trait]
+pub trait RenderedIssueCache: Send + Sync + Clone {
+    // Find the issue rendered from the version of the issue
+    async fn find(
+        &self,
+        issue_id: Uuid,
+        version: i64,
+    ) -> Result<Option<RenderedIssue>, RenderingError>;
+
+    // Keep the rendered issue, and may drop the ones rendered from older versions
+    async fn save(&self, rendered_issue: &RenderedIssue) -> Result<(), RenderingError>;
+}
+
+#[derive(Clone)]
+pub struct FakeRenderedIssueCache {
+    items: Arc<RwLock<HashMap<(Uuid, i64), RenderedIssue>>>,
+}
+
+impl FakeRenderedIssueCache {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            items: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.items.read().unwrap().len()
+    }
+}
+
+#[async_trait::async_trait]
+impl RenderedIssueCache for FakeRenderedIssueCache {
+    async fn find(
+        &self,
+        issue_id: Uuid,
+        version: i64,
+    ) -> Result<Option<RenderedIssue>, RenderingError> {
+        let store = self.items.read().map_err(|_| {
+            RenderingError::CacheOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        Ok(store.get(&(issue_id, version)).cloned())
+    }
+
+    async fn save(&self, rendered_issue: &RenderedIssue) -> Result<(), RenderingError> {
+        let mut store = self.items.write().map_err(|_| {
+            RenderingError::CacheOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        store.retain(|(issue_id, version), _| {
+            *issue_id != rendered_issue.issue_id || *version > rendered_issue.version
+        });
+        store.insert(
+            (rendered_issue.issue_id, rendered_issue.version),
+            rendered_issue.clone(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use uuid::Uuid;
+
+use crate::issue::prelude::Issue;
+use crate::rendering::model::error::{
+    RenderingError,
+    TemplateError,
+};
+use crate::rendering::model::markdown;
+use crate::rendering::model::template::{
+    Escaping,
+    MaskedTemplate,
+    Personalization,
+    Template,
+};
+
+// Issue as subscribers receive it, rendered from a revision of the issue
+// Its subject, HTML and text are still templates, personalized per subscriber
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderedIssue {
+    pub issue_id: Uuid,
+    // version of the issue rendered, so that a revised issue is rendered again
+    pub version: i64,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+impl RenderedIssue {
+    // Markdown → HTML → sanitized by an allow-list → styles inlined for email clients,
+    // and a plain text alternative for clients not showing HTML
+    pub fn render(issue: &Issue) -> Result<Self, RenderingError> {
+        let masked = MaskedTemplate::mask(&issue.body).map_err(RenderingError::InvalidTemplate)?;
+
+        let html = markdown::render_html(&masked.source);
+        let html = markdown::sanitize(&html);
+        // also checked on saving, but issues saved before the check are rendered too
+        masked
+            .check_urls(&html)
+            .map_err(RenderingError::InvalidTemplate)?;
+        let html = markdown::inline_styles(&html)?;
+        let rendered_issue = Self {
+            issue_id: issue.id,
+            version: issue.version,
+            subject: issue.subject.clone(),
+            html: masked.unmask(&html),
+            text: masked.unmask(&markdown::render_text(&masked.source)),
+        };
+
+        // fail on rendering rather than on sending, e.g. for braces escaped in Markdown
+        rendered_issue.templates()?;
+
+        Ok(rendered_issue)
+    }
+
+    // Body is checked as it's rendered, since only the rendered links tell where tags end up
+    pub fn check_body(body: &str) -> Result<(), TemplateError> {
+        let masked = MaskedTemplate::mask(body)?;
+        let html = markdown::sanitize(&markdown::render_html(&masked.source));
+        masked.check_urls(&html)
+    }
+
+    pub fn personalize(
+        &self,
+        personalization: &Personalization,
+    ) -> Result<PersonalizedIssue, RenderingError> {
+        let (subject, html, text) = self.templates()?;
+
+        Ok(PersonalizedIssue {
+            // a value can't break the subject into several header lines
+            subject: subject
+                .render(personalization, Escaping::Text)
+                .replace(['\r', '\n'], " "),
+            html: html.render(personalization, Escaping::Html),
+            text: text.render(personalization, Escaping::Text),
+        })
+    }
+
+    fn templates(&self) -> Result<(Template, Template, Template), RenderingError> {
+        Ok((
+            Template::parse(&self.subject).map_err(RenderingError::InvalidTemplate)?,
+            Template::parse(&self.html).map_err(RenderingError::InvalidTemplate)?,
+            Template::parse(&self.text).map_err(RenderingError::InvalidTemplate)?,
+        ))
+    }
+}
+
+// Issue as a subscriber receives it
+#[derive(Clone, Debug, PartialEq)]
+pub struct PersonalizedIssue {
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn personalizing_rendered_issue_fills_link_and_escapes_name() {
+        // given
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                Uuid::new_v4(),
+                "Weekly #1".to_string(),
+                "News for {{ subscriber.name }}".to_string(),
+                "Hi **{{ subscriber.name }}**\n\n[Unsubscribe]({{unsubscribe_url}})".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        let rendered_issue = RenderedIssue::render(&issue).unwrap();
+
+        // when
+        let personalized_issue = rendered_issue
+            .personalize(&Personalization::new(
+                "<b>Arine</b>\n".to_string(),
+                "arine@example.com".to_string(),
+                "https://example.com/unsubscribe?SubscriberId=1&Source=mail".to_string(),
+            ))
+            .unwrap();
+
+        // then
+        assert_eq!(personalized_issue.subject, "News for <b>Arine</b> ");
+        assert!(personalized_issue.html.contains("&lt;b&gt;Arine&lt;/b&gt;"));
+        assert!(personalized_issue
+            .html
+            .contains("href=\"https://example.com/unsubscribe?SubscriberId=1&amp;Source=mail\""));
+        assert_eq!(
+            personalized_issue.text,
+            "Hi <b>Arine</b>\n\n\n\
+            Unsubscribe (https://example.com/unsubscribe?SubscriberId=1&Source=mail)\n"
+        );
+    }
+
+    #[test]
+    fn rendering_issue_linking_to_subscriber_name_is_rejected() {
+        // given
+        let issue = Issue {
+            body: "[Read more]({{ subscriber.name }})".to_string(),
+            ..Default::default()
+        };
+
+        // when
+        let response = RenderedIssue::render(&issue);
+
+        // then
+        assert!(matches!(
+            response,
+            Err(RenderingError::InvalidTemplate(TemplateError::TagInUrl(_)))
+        ));
+    }
+}
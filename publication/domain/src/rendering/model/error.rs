@@ -0,0 +1,39 @@
+use uuid::Uuid;
+
+use crate::issue::prelude::IssueError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RenderingError {
+    #[error("Issue (ID: {0}) doesn't exist")]
+    IssueNotFound(Uuid),
+
+    #[error("Failed to operate on issue")]
+    IssueOperationFailed(#[source] IssueError),
+
+    #[error("Issue's content has an invalid template")]
+    InvalidTemplate(#[source] TemplateError),
+
+    #[error("Failed to inline styles of rendered issue")]
+    StyleInliningFailed(#[source] anyhow::Error),
+
+    #[error("Failed to operator on cache")]
+    CacheOperationFailed(#[source] anyhow::Error),
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    #[error("Template refers to unknown variable {0}")]
+    UnknownVariable(String),
+
+    #[error("Template tag is malformed: {0}")]
+    MalformedTag(String),
+
+    #[error("Template tag {0} isn't expected here")]
+    UnexpectedTag(String),
+
+    #[error("Template block on {0} isn't closed by {{% endif %}}")]
+    UnclosedBlock(&'static str),
+
+    #[error("Template tag {0} can't be used in a URL, only {{{{ unsubscribe_url }}}} can")]
+    TagInUrl(String),
+}
@@ -0,0 +1,191 @@
+use pulldown_cmark::{
+    Event,
+    Options,
+    Parser,
+    Tag,
+    TagEnd,
+};
+
+use crate::rendering::model::error::RenderingError;
+
+// Styles inlined into every element, as many email clients drop <style> elements
+const STYLESHEET: &str = r#"
+body { margin: 0; padding: 0; background-color: #f6f6f6; }
+.issue { max-width: 640px; margin: 0 auto; padding: 24px; background-color: #ffffff; color: #222222; font-family: Helvetica, Arial, sans-serif; font-size: 16px; line-height: 1.5; }
+h1, h2, h3, h4, h5, h6 { margin: 24px 0 12px; line-height: 1.25; }
+p, ul, ol, blockquote, pre, table { margin: 0 0 16px; }
+a { color: #1a73e8; }
+blockquote { padding: 0 16px; border-left: 4px solid #dddddd; color: #555555; }
+code { padding: 2px 4px; background-color: #f0f0f0; font-family: Menlo, Consolas, monospace; font-size: 14px; }
+pre { padding: 12px; overflow: auto; background-color: #f0f0f0; }
+pre code { padding: 0; }
+table { border-collapse: collapse; }
+th, td { padding: 6px 12px; border: 1px solid #dddddd; }
+img { max-width: 100%; height: auto; }
+"#;
+
+// CommonMark with the GitHub flavored extensions editors are used to
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES
+}
+
+pub(crate) fn render_html(markdown: &str) -> String {
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, Parser::new_ext(markdown, options()));
+    html
+}
+
+// Raw HTML is allowed in CommonMark, so anything outside the allow-list is removed,
+// e.g. scripts, event handlers, styles and javascript: links
+pub(crate) fn sanitize(html: &str) -> String {
+    ammonia::Builder::default()
+        .url_schemes(["http", "https", "mailto"].into())
+        .clean(html)
+        .to_string()
+}
+
+pub(crate) fn inline_styles(html: &str) -> Result<String, RenderingError> {
+    let document = format!(
+        "<html><head><meta charset=\"utf-8\"><style>{}</style></head>\
+        <body><div class=\"issue\">{}</div></body></html>",
+        STYLESHEET, html
+    );
+
+    css_inline::inline(&document).map_err(|error| RenderingError::StyleInliningFailed(error.into()))
+}
+
+// Text is taken from the markdown rather than the HTML, so raw HTML never reaches the text
+pub(crate) fn render_text(markdown: &str) -> String {
+    let mut text = String::new();
+    // numbers of the open lists, none for bullet lists
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // destinations of the open links, written after their text
+    let mut links: Vec<String> = Vec::new();
+
+    for event in Parser::new_ext(markdown, options()) {
+        match event {
+            Event::Start(Tag::List(start)) => {
+                end_line(&mut text);
+                lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+                if lists.is_empty() {
+                    end_block(&mut text);
+                }
+            }
+            Event::Start(Tag::Item) => {
+                end_line(&mut text);
+                text.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        text.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => text.push_str("- "),
+                }
+            }
+            Event::End(TagEnd::Item) => end_line(&mut text),
+            Event::Start(Tag::Link { dest_url, .. }) => links.push(dest_url.to_string()),
+            Event::End(TagEnd::Link) => {
+                if let Some(dest_url) = links.pop() {
+                    text.push_str(&format!(" ({})", dest_url));
+                }
+            }
+            Event::End(TagEnd::Paragraph) if !lists.is_empty() => end_line(&mut text),
+            Event::End(
+                TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::CodeBlock | TagEnd::Table,
+            ) => end_block(&mut text),
+            Event::End(TagEnd::TableHead | TagEnd::TableRow) => end_line(&mut text),
+            Event::Start(Tag::TableCell) if !text.is_empty() && !text.ends_with('\n') => {
+                text.push_str(" | ")
+            }
+            Event::Text(content) | Event::Code(content) => text.push_str(&content),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            Event::Rule => {
+                end_line(&mut text);
+                text.push_str("----------\n\n");
+            }
+            _ => {}
+        }
+    }
+
+    let mut text = text
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    text.push('\n');
+    text
+}
+
+fn end_line(text: &mut String) {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+fn end_block(text: &mut String) {
+    end_line(text);
+    if !text.is_empty() && !text.ends_with("\n\n") {
+        text.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitizing_rendered_markdown_removes_scripts_and_unsafe_links() {
+        // given
+        let markdown = "Hello <script>alert(1)</script><b onclick=\"steal()\">there</b>\n\n\
+            [click](javascript:alert(1)) and [read](https://example.com)";
+
+        // when
+        let html = sanitize(&render_html(markdown));
+
+        // then
+        assert!(!html.contains("script"));
+        assert!(!html.contains("onclick"));
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("<b>there</b>"));
+        assert!(html.contains("href=\"https://example.com\""));
+    }
+
+    #[test]
+    fn inlining_styles_moves_stylesheet_onto_elements() {
+        // given
+        let html = sanitize(&render_html("# Weekly\n\n[read](https://example.com)"));
+
+        // when
+        let html = inline_styles(&html).unwrap();
+
+        // then
+        assert!(!html.contains("<style>"));
+        assert!(html.contains("href=\"https://example.com\""));
+        assert!(html.contains("color: #1a73e8"));
+    }
+
+    #[test]
+    fn rendering_text_keeps_structure_and_link_destinations() {
+        // given
+        let markdown = "# Weekly #1\n\nHello, *subscribers*. Read [more](https://example.com).\n\n\
+            - first\n- second\n  1. nested\n\n<div>raw</div>\n\n```\nlet x = 1;\n```\n";
+
+        // when
+        let text = render_text(markdown);
+
+        // then
+        assert_eq!(
+            text,
+            "Weekly #1\n\n\
+            Hello, subscribers. Read more (https://example.com).\n\n\
+            - first\n- second\n  1. nested\n\n\
+            let x = 1;\n"
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod cache;
+mod entities;
+mod error;
+mod markdown;
+pub mod prelude;
+mod template;
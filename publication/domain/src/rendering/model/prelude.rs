@@ -0,0 +1,18 @@
+pub use crate::rendering::model::cache::{
+    FakeRenderedIssueCache,
+    RenderedIssueCache,
+};
+pub use crate::rendering::model::entities::{
+    PersonalizedIssue,
+    RenderedIssue,
+};
+pub use crate::rendering::model::error::{
+    RenderingError,
+    TemplateError,
+};
+pub use crate::rendering::model::template::{
+    Escaping,
+    Personalization,
+    Template,
+    TemplateVariable,
+};
@@ -0,0 +1,449 @@
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::rendering::model::error::TemplateError;
+
+// Variables an issue can refer to, filled per subscriber at send time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemplateVariable {
+    SubscriberName,
+    SubscriberEmail,
+    UnsubscribeUrl,
+}
+
+impl TemplateVariable {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TemplateVariable::SubscriberName => "subscriber.name",
+            TemplateVariable::SubscriberEmail => "subscriber.email",
+            TemplateVariable::UnsubscribeUrl => "unsubscribe_url",
+        }
+    }
+}
+
+impl FromStr for TemplateVariable {
+    type Err = TemplateError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "subscriber.name" => Ok(TemplateVariable::SubscriberName),
+            "subscriber.email" => Ok(TemplateVariable::SubscriberEmail),
+            "unsubscribe_url" => Ok(TemplateVariable::UnsubscribeUrl),
+            _ => Err(TemplateError::UnknownVariable(name.to_string())),
+        }
+    }
+}
+
+// Values of the variables for a subscriber
+#[derive(Clone, Debug, PartialEq)]
+pub struct Personalization {
+    pub subscriber_name: String,
+    pub subscriber_email: String,
+    pub unsubscribe_url: String,
+}
+
+impl Personalization {
+    pub fn new(subscriber_name: String, subscriber_email: String, unsubscribe_url: String) -> Self {
+        Self {
+            subscriber_name,
+            subscriber_email,
+            unsubscribe_url,
+        }
+    }
+
+    fn value(&self, variable: TemplateVariable) -> &str {
+        match variable {
+            TemplateVariable::SubscriberName => &self.subscriber_name,
+            TemplateVariable::SubscriberEmail => &self.subscriber_email,
+            TemplateVariable::UnsubscribeUrl => &self.unsubscribe_url,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Escaping {
+    Html,
+    Text,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Text(String),
+    Variable(TemplateVariable),
+    If(TemplateVariable),
+    Else,
+    EndIf,
+}
+
+impl Token {
+    // tag as written in canonical form
+    fn tag(&self) -> String {
+        match self {
+            Token::Text(text) => text.clone(),
+            Token::Variable(variable) => format!("{{{{ {} }}}}", variable.name()),
+            Token::If(variable) => format!("{{% if {} %}}", variable.name()),
+            Token::Else => "{% else %}".to_string(),
+            Token::EndIf => "{% endif %}".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Text(String),
+    Variable(TemplateVariable),
+    // the branch taken depends on whether the variable is empty
+    If {
+        variable: TemplateVariable,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+// Content with `{{ variable }}` placeholders and `{% if variable %} … {% else %} … {% endif %}`
+// blocks, where only the variables above are known
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        let mut tokens = tokenize(source)?.into_iter();
+
+        match parse_nodes(&mut tokens)? {
+            (nodes, None) => Ok(Self { nodes }),
+            (_, Some(token)) => Err(TemplateError::UnexpectedTag(token.tag())),
+        }
+    }
+
+    // Values are inserted as they are after the template is parsed, so a value looking like
+    // a template is never evaluated, and escaped for HTML so it can't add markup
+    pub fn render(&self, personalization: &Personalization, escaping: Escaping) -> String {
+        let mut output = String::new();
+        render_nodes(&self.nodes, personalization, escaping, &mut output);
+        output
+    }
+}
+
+// Source with its template tags replaced by masks, which Markdown and sanitizing leave as they
+// are, e.g. `[Unsubscribe]({{ unsubscribe_url }})` is still a link
+pub(crate) struct MaskedTemplate {
+    pub source: String,
+    // masks and the tags they replace
+    masks: Vec<(String, String)>,
+}
+
+impl MaskedTemplate {
+    pub fn mask(source: &str) -> Result<Self, TemplateError> {
+        // unique per masking, so that masks never collide with the content
+        let nonce = Uuid::new_v4().simple().to_string();
+        let mut masked = Self {
+            source: String::new(),
+            masks: Vec::new(),
+        };
+
+        for token in tokenize(source)? {
+            match token {
+                Token::Text(text) => masked.source.push_str(&text),
+                token => {
+                    let mask = format!("T{}N{}E", nonce, masked.masks.len());
+                    masked.source.push_str(&mask);
+                    masked.masks.push((mask, token.tag()));
+                }
+            }
+        }
+
+        Ok(masked)
+    }
+
+    // Values are escaped for HTML but not checked as URLs, so e.g. a subscriber's name used as
+    // a link could be a javascript: URL, while the unsubscribe URL is configured by operators
+    pub fn check_urls(&self, html: &str) -> Result<(), TemplateError> {
+        let unsubscribe_url = Token::Variable(TemplateVariable::UnsubscribeUrl).tag();
+
+        for value in url_attribute_values(html) {
+            if let Some((_, tag)) = self
+                .masks
+                .iter()
+                .find(|(mask, tag)| value.contains(mask.as_str()) && *tag != unsubscribe_url)
+            {
+                return Err(TemplateError::TagInUrl(tag.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn unmask(&self, output: &str) -> String {
+        self.masks
+            .iter()
+            .fold(output.to_string(), |output, (mask, tag)| {
+                output.replace(mask, tag)
+            })
+    }
+}
+
+// Values of the attributes taking URLs, which sanitized HTML always quotes by "
+fn url_attribute_values(html: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+
+    for attribute in [" href=\"", " src=\"", " cite=\""] {
+        for (start, _) in html.match_indices(attribute) {
+            let rest = &html[start + attribute.len()..];
+            values.push(rest.split('"').next().unwrap_or(rest));
+        }
+    }
+
+    values
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, TemplateError> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+
+    while let Some(start) = find_tag_start(rest) {
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+
+        let is_variable = rest[start..].starts_with("{{");
+        let closing = if is_variable { "}}" } else { "%}" };
+        let end = rest[start + 2..]
+            .find(closing)
+            .map(|end| start + 2 + end)
+            .ok_or_else(|| TemplateError::MalformedTag(excerpt(&rest[start..])))?;
+        let content = rest[start + 2..end].trim();
+
+        tokens.push(if is_variable {
+            Token::Variable(content.parse()?)
+        } else {
+            match content.split_whitespace().collect::<Vec<_>>()[..] {
+                ["if", variable] => Token::If(variable.parse()?),
+                ["else"] => Token::Else,
+                ["endif"] => Token::EndIf,
+                _ => return Err(TemplateError::MalformedTag(excerpt(&rest[start..]))),
+            }
+        });
+        rest = &rest[end + 2..];
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+
+    Ok(tokens)
+}
+
+fn find_tag_start(source: &str) -> Option<usize> {
+    source
+        .match_indices('{')
+        .map(|(index, _)| index)
+        .find(|index| source[index + 1..].starts_with(['{', '%']))
+}
+
+fn excerpt(source: &str) -> String {
+    source.chars().take(40).collect()
+}
+
+// Parse nodes until the tokens end or a tag closing the enclosing block, which is returned
+fn parse_nodes(
+    tokens: &mut impl Iterator<Item = Token>,
+) -> Result<(Vec<Node>, Option<Token>), TemplateError> {
+    let mut nodes = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Variable(variable) => nodes.push(Node::Variable(variable)),
+            Token::If(variable) => {
+                let (then, closing) = parse_nodes(tokens)?;
+                let otherwise = match closing {
+                    Some(Token::EndIf) => Vec::new(),
+                    Some(Token::Else) => match parse_nodes(tokens)? {
+                        (otherwise, Some(Token::EndIf)) => otherwise,
+                        (_, Some(token)) => return Err(TemplateError::UnexpectedTag(token.tag())),
+                        (_, None) => return Err(TemplateError::UnclosedBlock(variable.name())),
+                    },
+                    _ => return Err(TemplateError::UnclosedBlock(variable.name())),
+                };
+                nodes.push(Node::If {
+                    variable,
+                    then,
+                    otherwise,
+                });
+            }
+            Token::Else | Token::EndIf => return Ok((nodes, Some(token))),
+        }
+    }
+
+    Ok((nodes, None))
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    personalization: &Personalization,
+    escaping: Escaping,
+    output: &mut String,
+) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Variable(variable) => {
+                let value = personalization.value(*variable);
+                match escaping {
+                    Escaping::Html => escape_html(value, output),
+                    Escaping::Text => output.push_str(value),
+                }
+            }
+            Node::If {
+                variable,
+                then,
+                otherwise,
+            } => {
+                let branch = if personalization.value(*variable).trim().is_empty() {
+                    otherwise
+                } else {
+                    then
+                };
+                render_nodes(branch, personalization, escaping, output);
+            }
+        }
+    }
+}
+
+fn escape_html(value: &str, output: &mut String) {
+    for character in value.chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            character => output.push(character),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_matches;
+
+    use super::*;
+    use crate::rendering::model::markdown;
+
+    fn get_personalization(subscriber_name: &str) -> Personalization {
+        Personalization::new(
+            subscriber_name.to_string(),
+            "arine@example.com".to_string(),
+            "https://example.com/unsubscribe?SubscriberId=1".to_string(),
+        )
+    }
+
+    #[test]
+    fn rendering_template_fills_variables_and_takes_branches() {
+        // given
+        let template = Template::parse(
+            "{% if subscriber.name %}Hi {{subscriber.name}}{% else %}Hi there{% endif %}, \
+            leave at {{ unsubscribe_url }}",
+        )
+        .unwrap();
+
+        // when
+        let named = template.render(&get_personalization("Arine"), Escaping::Text);
+        let unnamed = template.render(&get_personalization(" "), Escaping::Text);
+
+        // then
+        assert_eq!(
+            named,
+            "Hi Arine, leave at https://example.com/unsubscribe?SubscriberId=1"
+        );
+        assert_eq!(
+            unnamed,
+            "Hi there, leave at https://example.com/unsubscribe?SubscriberId=1"
+        );
+    }
+
+    #[test]
+    fn rendering_template_doesnt_evaluate_or_inject_values() {
+        // given
+        let template = Template::parse("<p>Hi {{ subscriber.name }}</p>").unwrap();
+        let personalization =
+            get_personalization("{{ unsubscribe_url }}<script>alert('x')</script>");
+
+        // when
+        let html = template.render(&personalization, Escaping::Html);
+
+        // then
+        assert_eq!(
+            html,
+            "<p>Hi {{ unsubscribe_url }}&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;</p>"
+        );
+    }
+
+    #[test]
+    fn parsing_template_rejects_unknown_variables_and_malformed_blocks() {
+        assert_matches!(
+            Template::parse("Hi {{ subscriber.password }}"),
+            Err(TemplateError::UnknownVariable(name)) if name == "subscriber.password"
+        );
+        assert_matches!(
+            Template::parse("{% if subscriber.name %}Hi"),
+            Err(TemplateError::UnclosedBlock(_))
+        );
+        assert_matches!(
+            Template::parse("Hi{% endif %}"),
+            Err(TemplateError::UnexpectedTag(_))
+        );
+        assert_matches!(
+            Template::parse("Hi {{ subscriber.name"),
+            Err(TemplateError::MalformedTag(_))
+        );
+        assert_matches!(
+            Template::parse("{% for subscriber in subscribers %}"),
+            Err(TemplateError::MalformedTag(_))
+        );
+    }
+
+    #[test]
+    fn masking_template_keeps_tags_through_unmasking() {
+        // given
+        let source = "[Unsubscribe]({{unsubscribe_url}}) {% if subscriber.name %}!{% endif %}";
+
+        // when
+        let masked = MaskedTemplate::mask(source).unwrap();
+
+        // then
+        assert!(!masked.source.contains('{'));
+        assert_eq!(
+            masked.unmask(&masked.source),
+            "[Unsubscribe]({{ unsubscribe_url }}) {% if subscriber.name %}!{% endif %}"
+        );
+    }
+
+    #[test]
+    fn checking_urls_rejects_tags_other_than_unsubscribe_url() {
+        // given
+        let html = |source: &str| {
+            let masked = MaskedTemplate::mask(source).unwrap();
+            let html = markdown::sanitize(&markdown::render_html(&masked.source));
+            masked.check_urls(&html)
+        };
+
+        // when
+        let unsubscribe_link = html("[Unsubscribe]({{ unsubscribe_url }}) {{ subscriber.name }}");
+        let name_link = html("[Profile]({{ subscriber.name }})");
+        let email_image = html("![Avatar](https://example.com/{{ subscriber.email }})");
+        let block_link = html("<a href=\"{% if subscriber.name %}x{% endif %}\">Hi</a>");
+
+        // then
+        assert!(unsubscribe_link.is_ok());
+        assert_matches!(
+            name_link,
+            Err(TemplateError::TagInUrl(tag)) if tag == "{{ subscriber.name }}"
+        );
+        assert_matches!(email_image, Err(TemplateError::TagInUrl(_)));
+        assert_matches!(block_link, Err(TemplateError::TagInUrl(_)));
+    }
+}
@@ -0,0 +1,2 @@
+pub use crate::rendering::model::prelude::*;
+pub use crate::rendering::service::prelude::*;
@@ -0,0 +1,2 @@
+pub mod prelude;
+mod renderer;
@@ -0,0 +1 @@
+pub use crate::rendering::service::renderer::*;
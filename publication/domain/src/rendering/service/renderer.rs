@@ -0,0 +1,132 @@
+use uuid::Uuid;
+
+use crate::issue::prelude::{
+    Issue,
+    IssueRepository,
+};
+use crate::rendering::model::prelude::{
+    RenderedIssue,
+    RenderedIssueCache,
+    RenderingError,
+};
+
+// Renders issues for subscribers, once per revision of an issue
+#[derive(Clone)]
+pub struct IssueRenderer<I, C>
+where
+    I: IssueRepository,
+    C: RenderedIssueCache,
+{
+    issue_repository: I,
+    cache: C,
+}
+
+impl<I, C> IssueRenderer<I, C>
+where
+    I: IssueRepository,
+    C: RenderedIssueCache,
+{
+    pub fn new(issue_repository: I, cache: C) -> Self {
+        Self {
+            issue_repository,
+            cache,
+        }
+    }
+
+    pub async fn render(&self, issue_id: Uuid) -> Result<RenderedIssue, RenderingError> {
+        let issue = self
+            .issue_repository
+            .find_by_id(issue_id)
+            .await
+            .map_err(RenderingError::IssueOperationFailed)?
+            .ok_or(RenderingError::IssueNotFound(issue_id))?;
+
+        self.render_issue(&issue).await
+    }
+
+    pub async fn render_issue(&self, issue: &Issue) -> Result<RenderedIssue, RenderingError> {
+        if let Some(rendered_issue) = self.cache.find(issue.id, issue.version).await? {
+            return Ok(rendered_issue);
+        }
+
+        let rendered_issue = RenderedIssue::render(issue)?;
+        self.cache.save(&rendered_issue).await?;
+
+        Ok(rendered_issue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_matches;
+
+    use crate::issue::prelude::FakeIssueRepository;
+    use crate::rendering::model::prelude::FakeRenderedIssueCache;
+
+    use super::*;
+
+    async fn draft_issue(issue_repository: &FakeIssueRepository) -> Uuid {
+        let id = Uuid::new_v4();
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, **subscribers**".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        issue_repository.save(&mut issue).await.unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn rendering_issue_again_uses_cache_until_revised() {
+        // given
+        let issue_repository = FakeIssueRepository::new();
+        let cache = FakeRenderedIssueCache::new();
+        let renderer = IssueRenderer::new(issue_repository.clone(), cache.clone());
+        let id = draft_issue(&issue_repository).await;
+        let rendered_issue = renderer.render(id).await.unwrap();
+
+        // when
+        let cached_issue = renderer.render(id).await.unwrap();
+        issue_repository
+            .modify(id, |issue| {
+                issue.revise(
+                    "Weekly #1".to_string(),
+                    "What's new this week".to_string(),
+                    "Hello, *subscribers*".to_string(),
+                )
+            })
+            .await
+            .unwrap();
+        let revised_issue = renderer.render(id).await.unwrap();
+
+        // then
+        assert_eq!(cached_issue, rendered_issue);
+        assert!(rendered_issue.html.contains("<strong>subscribers</strong>"));
+        assert_eq!(rendered_issue.text, "Hello, subscribers\n");
+        assert!(revised_issue.html.contains("<em>subscribers</em>"));
+        assert_eq!(revised_issue.version, rendered_issue.version + 1);
+        assert_eq!(cache.size(), 1);
+    }
+
+    #[tokio::test]
+    async fn rendering_not_existing_issue_fails() {
+        // given
+        let renderer =
+            IssueRenderer::new(FakeIssueRepository::new(), FakeRenderedIssueCache::new());
+        let id = Uuid::new_v4();
+
+        // when
+        let response = renderer.render(id).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(RenderingError::IssueNotFound(not_found_id)) if not_found_id == id
+        );
+    }
+}
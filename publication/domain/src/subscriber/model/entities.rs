@@ -15,6 +15,8 @@ pub struct Subscriber {
     pub email: SubscriberEmail,
     pub name: String,
     pub pending_events: Vec<SubscriberEvent>,
+    // version of the persisted state this subscriber was loaded from, for optimistic locking
+    pub version: i64,
 }
 
 impl Subscriber {
@@ -71,7 +73,17 @@ pub enum SubscriberEmailVerifiationStatus {
 impl Subscriber {
     pub fn apply(&mut self, event: SubscriberEvent) {
         self.pending_events.push(event.clone());
+        self.mutate(event);
+    }
+
+    // Rebuild the state from events already persisted, so they aren't pending
+    pub fn replay(&mut self, events: impl IntoIterator<Item = SubscriberEvent>) {
+        for event in events {
+            self.mutate(event);
+        }
+    }
 
+    fn mutate(&mut self, event: SubscriberEvent) {
         match event {
             SubscriberEvent::SubscriberCreated(event) => {
                 event.apply(self);
@@ -107,9 +119,32 @@ mod tests {
             },
             name: LastName().fake(),
             pending_events: vec![],
+            version: 0,
         }
     }
 
+    #[test]
+    fn replaying_events_rebuilds_the_state_without_pending_events() {
+        // given
+        let mut subscriber = get_subscriber();
+        subscriber.update("Arine".to_string());
+        subscriber
+            .verified_email_as(&SubscriberEmailVerifiationStatus::Valid)
+            .unwrap();
+
+        // when
+        let mut replayed_subscriber = Subscriber::default();
+        replayed_subscriber.replay(subscriber.pending_events.clone());
+
+        // then
+        assert_eq!(replayed_subscriber.name, "Arine");
+        assert_eq!(
+            replayed_subscriber.email.verification_status,
+            SubscriberEmailVerifiationStatus::Valid
+        );
+        assert!(replayed_subscriber.pending_events.is_empty());
+    }
+
     #[test]
     fn verified_as_unverified_status_is_rejected() {
         // given
@@ -11,12 +11,51 @@ pub enum SubscriberError {
     #[error("Subscriber (ID: {0}) doesn't exist")]
     SubscriberNotFound(Uuid),
 
+    #[error("Subscriber (ID: {0}) already exists")]
+    SubscriberAlreadyExists(Uuid),
+
     #[error("Single subscriber is expected, but found multiple subscribers")]
     MultipleSubscribersFound,
 
+    #[error("Subscriber (ID: {0}) was modified concurrently")]
+    ConcurrentModification(Uuid),
+
+    #[error("Subscriber event can't be serialized or deserialized")]
+    InvalidSubscriberEvent(#[source] anyhow::Error),
+
     #[error("Failed to operator on repository")]
     RepositoryOperationFailed(#[source] anyhow::Error),
 
     #[error("Failed unexpectedly")]
     Unexpected(#[source] anyhow::Error),
 }
+
+#[derive(thiserror::Error, Debug)]
+pub enum SubscriptionGatewayError {
+    #[error("Subscription service is unavailable")]
+    Unavailable(#[source] anyhow::Error),
+
+    #[error("Subscription service rejected the request with status {0}")]
+    Rejected(u16),
+
+    #[error("Subscription service responded with an invalid response")]
+    InvalidResponse(#[source] anyhow::Error),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SubscriberReconciliationError {
+    #[error("Failed to find confirmed subscribers from subscription")]
+    SubscriptionGatewayFailed(#[source] SubscriptionGatewayError),
+
+    #[error("Failed to find subscribers from repository")]
+    RepositoryOperationFailed(#[source] SubscriberError),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SubscriberProjectionError {
+    #[error("Projection {0} doesn't exist")]
+    UnknownProjection(String),
+
+    #[error("Failed to rebuild projection")]
+    RebuildFailed(#[source] anyhow::Error),
+}
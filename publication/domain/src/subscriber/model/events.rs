@@ -5,7 +5,10 @@ use crate::subscriber::model::entities::{
     SubscriberEmailVerifiationStatus,
 };
 
-#[derive(Clone, Debug)]
+// Serialized as {"Type": <event type>, "Data": <payload>}, see SerializedSubscriberEvent
+// for the stored and sent form, which carries the schema version of the payload as well
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "Type", content = "Data")]
 pub enum SubscriberEvent {
     SubscriberCreated(SubscriberCreated),
     SubscriberUpdated(SubscriberUpdated),
@@ -13,7 +16,49 @@ pub enum SubscriberEvent {
     SubscriberEmailVerifiedAsInvalid(SubscriberEmailVerifiedAsInvalid),
 }
 
-#[derive(Clone, Debug)]
+impl SubscriberEvent {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            SubscriberEvent::SubscriberCreated(_) => "SubscriberCreated",
+            SubscriberEvent::SubscriberUpdated(_) => "SubscriberUpdated",
+            SubscriberEvent::SubscriberEmailVerifiedAsValid(_) => "SubscriberEmailVerifiedAsValid",
+            SubscriberEvent::SubscriberEmailVerifiedAsInvalid(_) => {
+                "SubscriberEmailVerifiedAsInvalid"
+            }
+        }
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        match self {
+            SubscriberEvent::SubscriberCreated(_) => SubscriberCreated::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberUpdated(_) => SubscriberUpdated::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberEmailVerifiedAsValid(_) => {
+                SubscriberEmailVerifiedAsValid::SCHEMA_VERSION
+            }
+            SubscriberEvent::SubscriberEmailVerifiedAsInvalid(_) => {
+                SubscriberEmailVerifiedAsInvalid::SCHEMA_VERSION
+            }
+        }
+    }
+
+    // Schema version of the payload this application writes for the event type
+    pub fn schema_version_of(event_type: &str) -> Option<u32> {
+        match event_type {
+            "SubscriberCreated" => Some(SubscriberCreated::SCHEMA_VERSION),
+            "SubscriberUpdated" => Some(SubscriberUpdated::SCHEMA_VERSION),
+            "SubscriberEmailVerifiedAsValid" => {
+                Some(SubscriberEmailVerifiedAsValid::SCHEMA_VERSION)
+            }
+            "SubscriberEmailVerifiedAsInvalid" => {
+                Some(SubscriberEmailVerifiedAsInvalid::SCHEMA_VERSION)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
 pub struct SubscriberCreated {
     pub id: Uuid,
     pub email: String,
@@ -21,6 +66,8 @@ pub struct SubscriberCreated {
 }
 
 impl SubscriberCreated {
+    pub const SCHEMA_VERSION: u32 = 1;
+
     pub fn new(id: Uuid, email: String, name: String) -> Self {
         Self { id, email, name }
     }
@@ -32,12 +79,15 @@ impl SubscriberCreated {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
 pub struct SubscriberUpdated {
     pub name: String,
 }
 
 impl SubscriberUpdated {
+    pub const SCHEMA_VERSION: u32 = 1;
+
     pub fn new(name: String) -> Self {
         Self { name }
     }
@@ -47,10 +97,13 @@ impl SubscriberUpdated {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
 pub struct SubscriberEmailVerifiedAsValid {}
 
 impl SubscriberEmailVerifiedAsValid {
+    pub const SCHEMA_VERSION: u32 = 1;
+
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {}
@@ -61,10 +114,13 @@ impl SubscriberEmailVerifiedAsValid {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
 pub struct SubscriberEmailVerifiedAsInvalid {}
 
 impl SubscriberEmailVerifiedAsInvalid {
+    pub const SCHEMA_VERSION: u32 = 1;
+
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {}
@@ -0,0 +1,75 @@
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use uuid::Uuid;
+
+use crate::subscriber::model::error::SubscriptionGatewayError;
+
+// Subscriber who confirmed subscription in subscription service
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmedSubscriber {
+    pub id: Uuid,
+    pub email: String,
+    pub name: String,
+}
+
+impl ConfirmedSubscriber {
+    pub fn new(id: Uuid, email: String, name: String) -> Self {
+        Self { id, email, name }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait SubscriptionGateway: Send + Sync + Clone {
+    // Find all confirmed subscribers, ordered by ID
+    async fn find_confirmed_subscribers(
+        &self,
+    ) -> Result<Vec<ConfirmedSubscriber>, SubscriptionGatewayError>;
+}
+
+#[derive(Clone)]
+pub struct FakeSubscriptionGateway {
+    subscribers: Arc<RwLock<Vec<ConfirmedSubscriber>>>,
+    unavailable: Arc<RwLock<bool>>,
+}
+
+impl FakeSubscriptionGateway {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            subscribers: Arc::new(RwLock::new(Vec::new())),
+            unavailable: Arc::new(RwLock::new(false)),
+        }
+    }
+
+    pub fn set_confirmed_subscribers(&self, subscribers: Vec<ConfirmedSubscriber>) {
+        *self.subscribers.write().unwrap() = subscribers;
+    }
+
+    pub fn set_unavailable(&self, unavailable: bool) {
+        *self.unavailable.write().unwrap() = unavailable;
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionGateway for FakeSubscriptionGateway {
+    async fn find_confirmed_subscribers(
+        &self,
+    ) -> Result<Vec<ConfirmedSubscriber>, SubscriptionGatewayError> {
+        if *self.unavailable.read().unwrap() {
+            return Err(SubscriptionGatewayError::Unavailable(anyhow::anyhow!(
+                "Fake subscription is unavailable"
+            )));
+        }
+
+        let subscribers = self.subscribers.read().map_err(|_| {
+            SubscriptionGatewayError::Unavailable(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        let mut subscribers = subscribers.clone();
+        subscribers.sort_by_key(|subscriber| subscriber.id);
+        Ok(subscribers)
+    }
+}
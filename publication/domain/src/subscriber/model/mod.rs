@@ -1,5 +1,8 @@
 mod entities;
 mod error;
 mod events;
+mod gateway;
 pub mod prelude;
+mod projection;
 mod repository;
+mod serialization;
@@ -3,9 +3,30 @@ pub use crate::subscriber::model::entities::{
     SubscriberEmail,
     SubscriberEmailVerifiationStatus,
 };
-pub use crate::subscriber::model::error::SubscriberError;
+pub use crate::subscriber::model::error::{
+    SubscriberError,
+    SubscriberProjectionError,
+    SubscriberReconciliationError,
+    SubscriptionGatewayError,
+};
 pub use crate::subscriber::model::events::*;
+pub use crate::subscriber::model::gateway::{
+    ConfirmedSubscriber,
+    FakeSubscriptionGateway,
+    SubscriptionGateway,
+};
+pub use crate::subscriber::model::projection::{
+    FakeSubscriberProjectionRebuilder,
+    SubscriberProjection,
+    SubscriberProjectionRebuildProgress,
+    SubscriberProjectionRebuilder,
+};
 pub use crate::subscriber::model::repository::{
     FakeSubscriberRepository,
     SubscriberRepository,
 };
+pub use crate::subscriber::model::serialization::{
+    SerializedSubscriberEvent,
+    SubscriberEventUpcaster,
+    SubscriberEventUpcasters,
+};
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use crate::subscriber::model::error::SubscriberProjectionError;
+
+// Read models built from subscriber events, which can be rebuilt from the event log
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriberProjection {
+    Subscribers,
+}
+
+impl SubscriberProjection {
+    // events replayed in a transaction unless given otherwise
+    pub const DEFAULT_REBUILD_BATCH_SIZE: u64 = 500;
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubscriberProjection::Subscribers => "subscribers",
+        }
+    }
+}
+
+impl FromStr for SubscriberProjection {
+    type Err = SubscriberProjectionError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "subscribers" => Ok(SubscriberProjection::Subscribers),
+            _ => Err(SubscriberProjectionError::UnknownProjection(
+                name.to_string(),
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriberProjectionRebuildProgress {
+    pub projection: SubscriberProjection,
+    // position in the event log the rebuild has replayed up to
+    pub processed_position: i64,
+    // position of the last event in the event log when the progress was reported
+    pub last_position: i64,
+    pub processed_events: i64,
+    // the rebuilt projection has been swapped in
+    pub completed: bool,
+}
+
+#[async_trait::async_trait]
+pub trait SubscriberProjectionRebuilder: Send + Sync + Clone {
+    // Replay the next batch of events into the shadow of the projection, starting a new rebuild
+    // unless one is in progress, and swap the shadow in once it has caught up with the event log
+    // Progress is kept with every batch, so that a rebuild interrupted by a crash is resumed
+    async fn rebuild_next_batch(
+        &self,
+        projection: SubscriberProjection,
+        batch_size: u64,
+    ) -> Result<SubscriberProjectionRebuildProgress, SubscriberProjectionError>;
+
+    // Progress of the rebuild in progress or completed last
+    async fn find_progress(
+        &self,
+        projection: SubscriberProjection,
+    ) -> Result<Option<SubscriberProjectionRebuildProgress>, SubscriberProjectionError>;
+}
+
+#[derive(Clone)]
+pub struct FakeSubscriberProjectionRebuilder {
+    last_position: Arc<RwLock<i64>>,
+    progress: Arc<RwLock<Option<SubscriberProjectionRebuildProgress>>>,
+    failing_position: Arc<RwLock<Option<i64>>>,
+}
+
+impl FakeSubscriberProjectionRebuilder {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            last_position: Arc::new(RwLock::new(0)),
+            progress: Arc::new(RwLock::new(None)),
+            failing_position: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub fn set_last_position(&self, last_position: i64) {
+        *self.last_position.write().unwrap() = last_position;
+    }
+
+    // Fail the batch containing the position, as if crashed there
+    pub fn set_failing_position(&self, failing_position: Option<i64>) {
+        *self.failing_position.write().unwrap() = failing_position;
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriberProjectionRebuilder for FakeSubscriberProjectionRebuilder {
+    async fn rebuild_next_batch(
+        &self,
+        projection: SubscriberProjection,
+        batch_size: u64,
+    ) -> Result<SubscriberProjectionRebuildProgress, SubscriberProjectionError> {
+        let last_position = *self.last_position.read().unwrap();
+        let mut progress = self.progress.write().unwrap();
+
+        let mut next_progress = match progress.as_ref() {
+            Some(progress) if !progress.completed => progress.clone(),
+            _ => SubscriberProjectionRebuildProgress {
+                projection,
+                processed_position: 0,
+                last_position,
+                processed_events: 0,
+                completed: false,
+            },
+        };
+        let position = last_position.min(next_progress.processed_position + batch_size as i64);
+
+        if matches!(
+            *self.failing_position.read().unwrap(),
+            Some(failing_position) if next_progress.processed_position < failing_position
+                && failing_position <= position
+        ) {
+            return Err(SubscriberProjectionError::RebuildFailed(anyhow::anyhow!(
+                "Fake rebuild crashed"
+            )));
+        }
+
+        next_progress.processed_events += position - next_progress.processed_position;
+        next_progress.completed = position == next_progress.processed_position;
+        next_progress.processed_position = position;
+        next_progress.last_position = last_position;
+        *progress = Some(next_progress.clone());
+
+        Ok(next_progress)
+    }
+
+    async fn find_progress(
+        &self,
+        _projection: SubscriberProjection,
+    ) -> Result<Option<SubscriberProjectionRebuildProgress>, SubscriberProjectionError> {
+        Ok(self.progress.read().unwrap().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_err;
+
+    use super::*;
+
+    #[test]
+    fn parsing_unknown_projection_name_fails() {
+        assert_eq!(
+            "subscribers".parse::<SubscriberProjection>().unwrap(),
+            SubscriberProjection::Subscribers
+        );
+        assert_err!("issues".parse::<SubscriberProjection>());
+    }
+}
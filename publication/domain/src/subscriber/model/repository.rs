@@ -12,11 +12,12 @@ use crate::subscriber::model::events::SubscriberEvent;
 
 #[async_trait::async_trait]
 pub trait SubscriberRepository: Send + Sync + Clone {
-    // Persist a new subscriber, and must fail if the subscriber ID already exists
+    // Persist a new subscriber, and must fail with already exists if the subscriber ID exists
     async fn save(&self, subscriber: &mut Subscriber) -> Result<(), SubscriberError>;
 
     // Find a subscriber by ID and fail if it doesn't exist
     // After that modify by the modifier function and persist the changes
+    // Fail with concurrent modification if the subscriber has been changed in the meantime
     // If async is needed, use the following signature
     // modifier: F
     // F: FnMut(&mut Subscriber) -> Fut + Send
@@ -30,6 +31,9 @@ pub trait SubscriberRepository: Send + Sync + Clone {
 
     // Find all subscribers
     async fn find_all(&self) -> Result<Vec<Subscriber>, SubscriberError>;
+
+    // Remove a subscriber by ID and fail if it doesn't exist
+    async fn remove(&self, id: Uuid) -> Result<(), SubscriberError>;
 }
 
 #[derive(Clone)]
@@ -77,10 +81,7 @@ impl SubscriberRepository for FakeSubscriberRepository {
         let mut events: Vec<SubscriberEvent> = subscriber.pending_events.drain(..).collect();
 
         if item_store.get(&subscriber.id).is_some() {
-            return Err(SubscriberError::RepositoryOperationFailed(anyhow::anyhow!(
-                "Subscriber ID {} already exist",
-                subscriber.id
-            )));
+            return Err(SubscriberError::SubscriberAlreadyExists(subscriber.id));
         }
         item_store.insert(subscriber.id, subscriber.clone());
         event_store
@@ -110,6 +111,7 @@ impl SubscriberRepository for FakeSubscriberRepository {
 
         modifier(&mut subscriber)?;
         let mut events: Vec<SubscriberEvent> = subscriber.pending_events.drain(..).collect();
+        subscriber.version += 1;
 
         item_store.insert(id, subscriber.clone());
         event_store
@@ -134,4 +136,15 @@ impl SubscriberRepository for FakeSubscriberRepository {
         })?;
         Ok(store.values().cloned().collect::<Vec<Subscriber>>())
     }
+
+    async fn remove(&self, id: Uuid) -> Result<(), SubscriberError> {
+        let mut store = self.items.write().map_err(|_| {
+            SubscriberError::RepositoryOperationFailed(anyhow::anyhow!("Failed to get fake store"))
+        })?;
+
+        store
+            .remove(&id)
+            .map(|_| ())
+            .ok_or(SubscriberError::SubscriberNotFound(id))
+    }
 }
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use crate::subscriber::model::error::SubscriberError;
+use crate::subscriber::model::events::SubscriberEvent;
+
+// Form of a subscriber event to be stored or sent
+// Payload is kept as JSON, so that it can be upcasted before being deserialized
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SerializedSubscriberEvent {
+    pub event_type: String,
+    pub schema_version: u32,
+    pub payload: serde_json::Value,
+}
+
+impl SerializedSubscriberEvent {
+    pub fn new(event_type: String, schema_version: u32, payload: serde_json::Value) -> Self {
+        Self {
+            event_type,
+            schema_version,
+            payload,
+        }
+    }
+
+    pub fn serialize(event: &SubscriberEvent) -> Result<Self, SubscriberError> {
+        let mut serialized = serde_json::to_value(event)
+            .map_err(|error| SubscriberError::InvalidSubscriberEvent(error.into()))?;
+        let payload = serialized
+            .get_mut("Data")
+            .map(serde_json::Value::take)
+            .unwrap_or_default();
+
+        Ok(Self::new(
+            event.event_type().to_string(),
+            event.schema_version(),
+            payload,
+        ))
+    }
+
+    // Payloads of old schema versions are upcasted to the current ones before deserialized
+    pub fn deserialize(
+        self,
+        upcasters: &SubscriberEventUpcasters,
+    ) -> Result<SubscriberEvent, SubscriberError> {
+        let schema_version =
+            SubscriberEvent::schema_version_of(&self.event_type).ok_or_else(|| {
+                SubscriberError::InvalidSubscriberEvent(anyhow::anyhow!(
+                    "Subscriber event type {} is unknown",
+                    self.event_type
+                ))
+            })?;
+        let upcasted = upcasters.upcast(self, schema_version)?;
+
+        serde_json::from_value(serde_json::json!({
+            "Type": upcasted.event_type,
+            "Data": upcasted.payload,
+        }))
+        .map_err(|error| SubscriberError::InvalidSubscriberEvent(error.into()))
+    }
+}
+
+// Migrates a payload of a schema version to the next schema version
+pub type SubscriberEventUpcaster =
+    fn(serde_json::Value) -> Result<serde_json::Value, SubscriberError>;
+
+// Upcasters by event type and the schema version they migrate from
+#[derive(Clone, Default)]
+pub struct SubscriberEventUpcasters {
+    upcasters: HashMap<(String, u32), SubscriberEventUpcaster>,
+}
+
+impl SubscriberEventUpcasters {
+    // Upcasters of all old schema versions ever written, register one here whenever
+    // a payload schema changes and bump the schema version of the event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        mut self,
+        event_type: &str,
+        from_schema_version: u32,
+        upcaster: SubscriberEventUpcaster,
+    ) -> Self {
+        self.upcasters
+            .insert((event_type.to_string(), from_schema_version), upcaster);
+        self
+    }
+
+    // Migrates the payload one schema version at a time until the given schema version
+    fn upcast(
+        &self,
+        mut event: SerializedSubscriberEvent,
+        schema_version: u32,
+    ) -> Result<SerializedSubscriberEvent, SubscriberError> {
+        if event.schema_version > schema_version {
+            return Err(SubscriberError::InvalidSubscriberEvent(anyhow::anyhow!(
+                "Schema version {} of subscriber event type {} is newer than {}",
+                event.schema_version,
+                event.event_type,
+                schema_version
+            )));
+        }
+
+        while event.schema_version < schema_version {
+            let upcaster = self
+                .upcasters
+                .get(&(event.event_type.clone(), event.schema_version))
+                .ok_or_else(|| {
+                    SubscriberError::InvalidSubscriberEvent(anyhow::anyhow!(
+                        "No upcaster of subscriber event type {} from schema version {}",
+                        event.event_type,
+                        event.schema_version
+                    ))
+                })?;
+            event.payload = upcaster(event.payload)?;
+            event.schema_version += 1;
+        }
+
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::{
+        assert_err,
+        assert_matches,
+    };
+    use uuid::Uuid;
+
+    use crate::subscriber::model::events::{
+        SubscriberCreated,
+        SubscriberEmailVerifiedAsInvalid,
+        SubscriberEmailVerifiedAsValid,
+        SubscriberUpdated,
+    };
+
+    use super::*;
+
+    // Golden files pin the wire format, a failure here means stored or sent events
+    // can't be read any more, so bump the schema version and add an upcaster instead
+    fn golden_events() -> Vec<(SubscriberEvent, &'static str)> {
+        let id = Uuid::parse_str("5f0c4b5e-5a57-4a34-9b2f-0b7f3c7e3a4d").unwrap();
+        vec![
+            (
+                SubscriberEvent::SubscriberCreated(SubscriberCreated::new(
+                    id,
+                    "arine@example.com".to_string(),
+                    "Arine".to_string(),
+                )),
+                include_str!("golden/subscriber_created.v1.json"),
+            ),
+            (
+                SubscriberEvent::SubscriberUpdated(SubscriberUpdated::new("Arine You".to_string())),
+                include_str!("golden/subscriber_updated.v1.json"),
+            ),
+            (
+                SubscriberEvent::SubscriberEmailVerifiedAsValid(
+                    SubscriberEmailVerifiedAsValid::new(),
+                ),
+                include_str!("golden/subscriber_email_verified_as_valid.v1.json"),
+            ),
+            (
+                SubscriberEvent::SubscriberEmailVerifiedAsInvalid(
+                    SubscriberEmailVerifiedAsInvalid::new(),
+                ),
+                include_str!("golden/subscriber_email_verified_as_invalid.v1.json"),
+            ),
+        ]
+    }
+
+    #[test]
+    fn serializing_subscriber_events_matches_golden_files() {
+        for (event, golden) in golden_events() {
+            // given
+            let expected: serde_json::Value = serde_json::from_str(golden).unwrap();
+
+            // when
+            let serialized = SerializedSubscriberEvent::serialize(&event).unwrap();
+
+            // then
+            assert_eq!(serde_json::to_value(serialized).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn deserializing_golden_files_returns_subscriber_events() {
+        for (expected, golden) in golden_events() {
+            // given
+            let serialized: SerializedSubscriberEvent = serde_json::from_str(golden).unwrap();
+
+            // when
+            let event = serialized
+                .deserialize(&SubscriberEventUpcasters::new())
+                .unwrap();
+
+            // then
+            assert_eq!(event, expected);
+        }
+    }
+
+    #[test]
+    fn upcasting_migrates_payload_through_every_schema_version() {
+        // given
+        let upcasters = SubscriberEventUpcasters::new()
+            .register("SubscriberUpdated", 1, |mut payload| {
+                payload["FullName"] = payload["Name"].take();
+                Ok(payload)
+            })
+            .register("SubscriberUpdated", 2, |payload| {
+                Ok(serde_json::json!({ "Names": [payload["FullName"]] }))
+            });
+        let event = SerializedSubscriberEvent::new(
+            "SubscriberUpdated".to_string(),
+            1,
+            serde_json::json!({ "Name": "Arine" }),
+        );
+
+        // when
+        let upcasted = upcasters.upcast(event, 3).unwrap();
+
+        // then
+        assert_eq!(upcasted.schema_version, 3);
+        assert_eq!(upcasted.payload, serde_json::json!({ "Names": ["Arine"] }));
+    }
+
+    #[test]
+    fn upcasting_fails_without_upcaster_of_old_schema_version() {
+        // given
+        let event = SerializedSubscriberEvent::new(
+            "SubscriberUpdated".to_string(),
+            1,
+            serde_json::json!({ "Name": "Arine" }),
+        );
+
+        // when
+        let response = SubscriberEventUpcasters::new().upcast(event, 2);
+
+        // then
+        assert_matches!(response, Err(SubscriberError::InvalidSubscriberEvent(_)));
+    }
+
+    #[test]
+    fn deserializing_fails_with_newer_schema_version_or_unknown_event_type() {
+        // given
+        let newer = SerializedSubscriberEvent::new(
+            "SubscriberUpdated".to_string(),
+            SubscriberUpdated::SCHEMA_VERSION + 1,
+            serde_json::json!({ "Name": "Arine" }),
+        );
+        let unknown = SerializedSubscriberEvent::new(
+            "SubscriberRenamed".to_string(),
+            1,
+            serde_json::json!({ "Name": "Arine" }),
+        );
+
+        // when
+        let upcasters = SubscriberEventUpcasters::new();
+
+        // then
+        assert_err!(newer.deserialize(&upcasters));
+        assert_err!(unknown.deserialize(&upcasters));
+    }
+}
@@ -13,6 +13,7 @@ pub enum SubscriberCommand {
     CreateSubscriber(CreateSubscriber),
     UpdateSubscriber(UpdateSubscriber),
     VerifySubscriberEmailAs(VerifySubscriberEmailAs),
+    RemoveSubscriber(RemoveSubscriber),
 }
 
 #[derive(Clone)]
@@ -42,6 +43,9 @@ where
             SubscriberCommand::VerifySubscriberEmailAs(command) => {
                 command.execute(self.repository.clone()).await
             }
+            SubscriberCommand::RemoveSubscriber(command) => {
+                command.execute(self.repository.clone()).await
+            }
         }
     }
 }
@@ -104,6 +108,20 @@ impl VerifySubscriberEmailAs {
     }
 }
 
+pub struct RemoveSubscriber {
+    id: Uuid,
+}
+
+impl RemoveSubscriber {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    async fn execute(&self, repository: impl SubscriberRepository) -> Result<(), SubscriberError> {
+        repository.remove(self.id).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use claims::*;
@@ -151,7 +169,10 @@ mod tests {
         let response = command.execute(repository.clone()).await;
 
         // then
-        assert_err!(response);
+        assert_matches!(
+            response,
+            Err(SubscriberError::SubscriberAlreadyExists(existing_id)) if existing_id == id
+        );
     }
 
     #[tokio::test]
@@ -191,4 +212,37 @@ mod tests {
             SubscriberEvent::SubscriberEmailVerifiedAsValid(_)
         );
     }
+
+    #[tokio::test]
+    async fn remove_subscriber_makes_the_subscriber_not_found() {
+        // given
+        let repository = FakeSubscriberRepository::new();
+        let id = Uuid::new_v4();
+        let mut subscriber = create_subscriber(id).await;
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        let command = RemoveSubscriber::new(id);
+        command.execute(repository.clone()).await.unwrap();
+
+        // then
+        assert_none!(repository.find_by_id(id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn remove_not_existing_subscriber_fails() {
+        // given
+        let repository = FakeSubscriberRepository::new();
+        let id = Uuid::new_v4();
+
+        // when
+        let command = RemoveSubscriber::new(id);
+        let response = command.execute(repository).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(SubscriberError::SubscriberNotFound(not_found_id)) if not_found_id == id
+        );
+    }
 }
@@ -1,3 +1,5 @@
 mod commands;
 pub mod prelude;
+mod projections;
 mod queries;
+mod reconciler;
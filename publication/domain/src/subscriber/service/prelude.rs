@@ -1,2 +1,4 @@
 pub use crate::subscriber::service::commands::*;
+pub use crate::subscriber::service::projections::*;
 pub use crate::subscriber::service::queries::*;
+pub use crate::subscriber::service::reconciler::*;
@@ -0,0 +1,120 @@
+use crate::subscriber::model::prelude::{
+    SubscriberProjection,
+    SubscriberProjectionError,
+    SubscriberProjectionRebuildProgress,
+    SubscriberProjectionRebuilder,
+};
+
+// Rebuilds projections from the event log batch by batch, so that a rebuild can be resumed
+#[derive(Clone)]
+pub struct SubscriberProjectionMaintainer<P>
+where
+    P: SubscriberProjectionRebuilder,
+{
+    rebuilder: P,
+}
+
+impl<P> SubscriberProjectionMaintainer<P>
+where
+    P: SubscriberProjectionRebuilder,
+{
+    pub fn new(rebuilder: P) -> Self {
+        Self { rebuilder }
+    }
+
+    // Rebuild the projection up to the end, reporting the progress of every batch
+    pub async fn rebuild(
+        &self,
+        projection: SubscriberProjection,
+        batch_size: u64,
+        report: impl Fn(&SubscriberProjectionRebuildProgress),
+    ) -> Result<SubscriberProjectionRebuildProgress, SubscriberProjectionError> {
+        loop {
+            let progress = self
+                .rebuilder
+                .rebuild_next_batch(projection, batch_size)
+                .await?;
+            report(&progress);
+
+            if progress.completed {
+                return Ok(progress);
+            }
+        }
+    }
+
+    pub async fn inquire_progress(
+        &self,
+        projection: SubscriberProjection,
+    ) -> Result<Option<SubscriberProjectionRebuildProgress>, SubscriberProjectionError> {
+        self.rebuilder.find_progress(projection).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use claims::assert_err;
+
+    use crate::subscriber::model::prelude::FakeSubscriberProjectionRebuilder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn rebuilding_replays_batches_until_swapped_in() {
+        // given
+        let rebuilder = FakeSubscriberProjectionRebuilder::new();
+        rebuilder.set_last_position(5);
+        let maintainer = SubscriberProjectionMaintainer::new(rebuilder);
+        let reported = RefCell::new(Vec::new());
+
+        // when
+        let progress = maintainer
+            .rebuild(SubscriberProjection::Subscribers, 2, |progress| {
+                reported.borrow_mut().push(progress.processed_position)
+            })
+            .await
+            .unwrap();
+
+        // then
+        assert!(progress.completed);
+        assert_eq!(progress.processed_events, 5);
+        assert_eq!(reported.into_inner(), vec![2, 4, 5, 5]);
+    }
+
+    #[tokio::test]
+    async fn rebuilding_after_failure_resumes_from_the_last_processed_position() {
+        // given
+        let rebuilder = FakeSubscriberProjectionRebuilder::new();
+        rebuilder.set_last_position(5);
+        rebuilder.set_failing_position(Some(3));
+        let maintainer = SubscriberProjectionMaintainer::new(rebuilder.clone());
+        assert_err!(
+            maintainer
+                .rebuild(SubscriberProjection::Subscribers, 2, |_| {})
+                .await
+        );
+        rebuilder.set_failing_position(None);
+        let reported = RefCell::new(Vec::new());
+
+        // when
+        let progress = maintainer
+            .rebuild(SubscriberProjection::Subscribers, 2, |progress| {
+                reported.borrow_mut().push(progress.processed_position)
+            })
+            .await
+            .unwrap();
+
+        // then
+        assert!(progress.completed);
+        assert_eq!(progress.processed_events, 5);
+        assert_eq!(reported.into_inner(), vec![4, 5, 5]);
+        assert_eq!(
+            maintainer
+                .inquire_progress(SubscriberProjection::Subscribers)
+                .await
+                .unwrap(),
+            Some(progress)
+        );
+    }
+}
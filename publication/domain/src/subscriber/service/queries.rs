@@ -111,6 +111,7 @@ impl InquirySubscriber {
 pub struct InquiryAllSubscribers {}
 
 impl InquiryAllSubscribers {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {}
     }
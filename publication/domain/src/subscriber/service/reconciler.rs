@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::subscriber::model::prelude::{
+    Subscriber,
+    SubscriberReconciliationError,
+    SubscriberRepository,
+    SubscriptionGateway,
+};
+use crate::subscriber::service::commands::{
+    CreateSubscriber,
+    RemoveSubscriber,
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    UpdateSubscriber,
+};
+
+// Differences found between confirmed subscribers in subscription and subscribers in publication
+#[derive(Debug, Default, PartialEq)]
+pub struct SubscriberDriftReport {
+    pub dry_run: bool,
+    // confirmed in subscription, but missing in publication
+    pub missing: Vec<Uuid>,
+    // existing in both, but named differently in publication
+    pub outdated: Vec<Uuid>,
+    // existing in publication, but not confirmed in subscription any more
+    pub stale: Vec<Uuid>,
+    // subscribers failed to converge, they are found again on the next reconciliation
+    pub failed: Vec<Uuid>,
+}
+
+impl SubscriberDriftReport {
+    pub fn has_drifted(&self) -> bool {
+        !(self.missing.is_empty() && self.outdated.is_empty() && self.stale.is_empty())
+    }
+}
+
+// Converges subscribers in publication to confirmed subscribers in subscription,
+// which covers events lost or not handled on the way
+#[derive(Clone)]
+pub struct SubscriberReconciler<R, G>
+where
+    R: SubscriberRepository,
+    G: SubscriptionGateway,
+{
+    repository: R,
+    command_executor: SubscriberCommandExecutor<R>,
+    gateway: G,
+}
+
+impl<R, G> SubscriberReconciler<R, G>
+where
+    R: SubscriberRepository,
+    G: SubscriptionGateway,
+{
+    pub fn new(repository: R, gateway: G) -> Self {
+        Self {
+            command_executor: SubscriberCommandExecutor::new(repository.clone()),
+            repository,
+            gateway,
+        }
+    }
+
+    // Only report the drift without issuing any commands in dry run
+    pub async fn reconcile(
+        &self,
+        dry_run: bool,
+    ) -> Result<SubscriberDriftReport, SubscriberReconciliationError> {
+        let confirmed_subscribers = self
+            .gateway
+            .find_confirmed_subscribers()
+            .await
+            .map_err(SubscriberReconciliationError::SubscriptionGatewayFailed)?;
+        let mut subscribers: HashMap<Uuid, Subscriber> = self
+            .repository
+            .find_all()
+            .await
+            .map_err(SubscriberReconciliationError::RepositoryOperationFailed)?
+            .into_iter()
+            .map(|subscriber| (subscriber.id, subscriber))
+            .collect();
+
+        let mut report = SubscriberDriftReport {
+            dry_run,
+            ..Default::default()
+        };
+        let mut commands = Vec::new();
+
+        for confirmed_subscriber in confirmed_subscribers {
+            match subscribers.remove(&confirmed_subscriber.id) {
+                None => {
+                    report.missing.push(confirmed_subscriber.id);
+                    commands.push((
+                        confirmed_subscriber.id,
+                        SubscriberCommand::CreateSubscriber(CreateSubscriber::new(
+                            confirmed_subscriber.id,
+                            confirmed_subscriber.email,
+                            confirmed_subscriber.name,
+                        )),
+                    ));
+                }
+                Some(subscriber) if subscriber.name != confirmed_subscriber.name => {
+                    report.outdated.push(confirmed_subscriber.id);
+                    commands.push((
+                        confirmed_subscriber.id,
+                        SubscriberCommand::UpdateSubscriber(UpdateSubscriber::new(
+                            confirmed_subscriber.id,
+                            confirmed_subscriber.name,
+                        )),
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
+        // subscribers left are not confirmed in subscription
+        report.stale = subscribers.into_keys().collect();
+        report.stale.sort();
+
+        if dry_run {
+            return Ok(report);
+        }
+
+        // remove first, so that an email of a stale subscriber can be taken by a missing one
+        let removals: Vec<_> = report
+            .stale
+            .iter()
+            .map(|id| {
+                (
+                    *id,
+                    SubscriberCommand::RemoveSubscriber(RemoveSubscriber::new(*id)),
+                )
+            })
+            .collect();
+        for (id, command) in removals.into_iter().chain(commands) {
+            if self.command_executor.execute(command).await.is_err() {
+                report.failed.push(id);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_matches;
+
+    use crate::subscriber::model::prelude::{
+        ConfirmedSubscriber,
+        FakeSubscriberRepository,
+        FakeSubscriptionGateway,
+    };
+
+    use super::*;
+
+    fn get_reconciler() -> (
+        SubscriberReconciler<FakeSubscriberRepository, FakeSubscriptionGateway>,
+        FakeSubscriberRepository,
+        FakeSubscriptionGateway,
+    ) {
+        let repository = FakeSubscriberRepository::new();
+        let gateway = FakeSubscriptionGateway::new();
+        let reconciler = SubscriberReconciler::new(repository.clone(), gateway.clone());
+        (reconciler, repository, gateway)
+    }
+
+    async fn prepare_subscriber(repository: &FakeSubscriberRepository, id: Uuid, name: &str) {
+        let mut subscriber = Subscriber::default();
+        subscriber.create(id, format!("{}@example.com", id), name.to_string());
+        repository.save(&mut subscriber).await.unwrap();
+    }
+
+    fn confirmed_subscriber(id: Uuid, name: &str) -> ConfirmedSubscriber {
+        ConfirmedSubscriber::new(id, format!("{}@example.com", id), name.to_string())
+    }
+
+    #[tokio::test]
+    async fn reconciling_converges_publication_to_confirmed_subscribers() {
+        // given
+        let (reconciler, repository, gateway) = get_reconciler();
+        let (missing, outdated, stale, synced) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        prepare_subscriber(&repository, outdated, "Arine").await;
+        prepare_subscriber(&repository, stale, "Arine").await;
+        prepare_subscriber(&repository, synced, "Arine").await;
+        gateway.set_confirmed_subscribers(vec![
+            confirmed_subscriber(missing, "Arine"),
+            confirmed_subscriber(outdated, "Arine You"),
+            confirmed_subscriber(synced, "Arine"),
+        ]);
+
+        // when
+        let report = reconciler.reconcile(false).await.unwrap();
+
+        // then
+        assert_eq!(
+            report,
+            SubscriberDriftReport {
+                dry_run: false,
+                missing: vec![missing],
+                outdated: vec![outdated],
+                stale: vec![stale],
+                failed: vec![],
+            }
+        );
+        assert!(repository.find_by_id(missing).await.unwrap().is_some());
+        assert!(repository.find_by_id(stale).await.unwrap().is_none());
+        assert_eq!(
+            repository.find_by_id(outdated).await.unwrap().unwrap().name,
+            "Arine You"
+        );
+    }
+
+    #[tokio::test]
+    async fn reconciling_in_dry_run_reports_drift_without_changing_subscribers() {
+        // given
+        let (reconciler, repository, gateway) = get_reconciler();
+        let (missing, stale) = (Uuid::new_v4(), Uuid::new_v4());
+        prepare_subscriber(&repository, stale, "Arine").await;
+        gateway.set_confirmed_subscribers(vec![confirmed_subscriber(missing, "Arine")]);
+
+        // when
+        let report = reconciler.reconcile(true).await.unwrap();
+
+        // then
+        assert!(report.dry_run);
+        assert!(report.has_drifted());
+        assert_eq!(report.missing, vec![missing]);
+        assert_eq!(report.stale, vec![stale]);
+        assert!(repository.find_by_id(missing).await.unwrap().is_none());
+        assert!(repository.find_by_id(stale).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn reconciling_again_after_convergence_finds_no_drift() {
+        // given
+        let (reconciler, _, gateway) = get_reconciler();
+        gateway.set_confirmed_subscribers(vec![confirmed_subscriber(Uuid::new_v4(), "Arine")]);
+        reconciler.reconcile(false).await.unwrap();
+
+        // when
+        let report = reconciler.reconcile(false).await.unwrap();
+
+        // then
+        assert!(!report.has_drifted());
+    }
+
+    #[tokio::test]
+    async fn reconciling_reports_subscribers_failed_to_converge() {
+        // given
+        let (reconciler, _, gateway) = get_reconciler();
+        let duplicated = Uuid::new_v4();
+        // subscription never lists a subscriber twice, but this makes the second creation fail
+        gateway.set_confirmed_subscribers(vec![
+            confirmed_subscriber(duplicated, "Arine"),
+            confirmed_subscriber(duplicated, "Arine"),
+        ]);
+
+        // when
+        let report = reconciler.reconcile(false).await.unwrap();
+
+        // then
+        assert_eq!(report.failed, vec![duplicated]);
+    }
+
+    #[tokio::test]
+    async fn reconciling_fails_if_subscription_gateway_fails() {
+        // given
+        let (reconciler, _, gateway) = get_reconciler();
+        gateway.set_unavailable(true);
+
+        // when
+        let response = reconciler.reconcile(false).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(SubscriberReconciliationError::SubscriptionGatewayFailed(_))
+        );
+    }
+}
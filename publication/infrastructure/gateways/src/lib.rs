@@ -1 +1,3 @@
-
+mod mail_http_gateway;
+pub mod prelude;
+mod subscription_http_gateway;
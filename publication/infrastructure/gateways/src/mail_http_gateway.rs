@@ -0,0 +1,247 @@
+use anyhow::anyhow;
+use reqwest::StatusCode;
+
+use domain::prelude::{
+    MailMessage,
+    MailTransport,
+    MailTransportError,
+};
+
+const SEND_MESSAGE_PATH: &str = "/v1/messages";
+
+#[derive(Clone, Debug)]
+pub struct MailHttpGatewayOptions {
+    // address the issues are sent from
+    pub sender: String,
+    pub token: String,
+}
+
+// Sends a message through the HTTP API of the mail service, which drops a message repeating
+// the idempotency key of one sent already
+#[derive(Clone)]
+pub struct MailHttpGateway {
+    client: reqwest::Client,
+    origin: reqwest::Url,
+    options: MailHttpGatewayOptions,
+}
+
+impl MailHttpGateway {
+    pub fn new(
+        client: reqwest::Client,
+        origin: reqwest::Url,
+        options: MailHttpGatewayOptions,
+    ) -> Self {
+        Self {
+            client,
+            origin,
+            options,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MailTransport for MailHttpGateway {
+    // Overloaded or failing service is unavailable and worth retrying, a refused message isn't
+    #[tracing::instrument(
+        name = "Sending message through mail service",
+        skip(self, message),
+        fields(idempotency_key = %message.idempotency_key)
+    )]
+    async fn send(&self, message: &MailMessage) -> Result<(), MailTransportError> {
+        let url = self
+            .origin
+            .join(SEND_MESSAGE_PATH)
+            .map_err(|error| MailTransportError::Unavailable(error.into()))?;
+        let request = Request {
+            from: &self.options.sender,
+            to: &message.to,
+            subject: &message.subject,
+            html_body: &message.html_body,
+            text_body: &message.text_body,
+        };
+
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(&self.options.token)
+            .header("Idempotency-Key", message.idempotency_key.to_string())
+            .json(&request)
+            .send()
+            .await
+            .map_err(|error| MailTransportError::Unavailable(error.into()))?;
+
+        let status = response.status();
+        if status.is_server_error()
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::UNAUTHORIZED
+            || status == StatusCode::FORBIDDEN
+        {
+            return Err(MailTransportError::Unavailable(anyhow!(
+                "Mail service responded with status {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            let reason = response.text().await.unwrap_or_default();
+            return Err(MailTransportError::Rejected(format!(
+                "Mail service responded with status {}: {}",
+                status, reason
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Request<'a> {
+    from: &'a str,
+    to: &'a str,
+    subject: &'a str,
+    html_body: &'a str,
+    text_body: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use claims::{
+        assert_matches,
+        assert_ok,
+    };
+    use uuid::Uuid;
+    use wiremock::matchers::{
+        bearer_token,
+        body_json,
+        header,
+        method,
+        path,
+    };
+    use wiremock::{
+        Mock,
+        MockServer,
+        ResponseTemplate,
+    };
+
+    use super::*;
+
+    async fn run_mail_server() -> (MockServer, MailHttpGateway) {
+        let mail_server = MockServer::start().await;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .expect("Failed to create mail client pool");
+        let gateway = MailHttpGateway::new(
+            client,
+            reqwest::Url::parse(mail_server.uri().as_ref()).unwrap(),
+            MailHttpGatewayOptions {
+                sender: "newsletter@example.com".to_string(),
+                token: "token".to_string(),
+            },
+        );
+
+        (mail_server, gateway)
+    }
+
+    fn generate_message() -> MailMessage {
+        MailMessage::new(
+            Uuid::new_v4(),
+            "arine@example.com".to_string(),
+            "Weekly #1".to_string(),
+            "<p>Hello, subscribers</p>".to_string(),
+            "Hello, subscribers\n".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn sending_message_posts_it_with_idempotency_key() {
+        // given
+        let (mail_server, gateway) = run_mail_server().await;
+        let message = generate_message();
+
+        Mock::given(method("POST"))
+            .and(path(SEND_MESSAGE_PATH))
+            .and(bearer_token("token"))
+            .and(header(
+                "Idempotency-Key",
+                message.idempotency_key.to_string().as_str(),
+            ))
+            .and(body_json(serde_json::json!({
+                "From": "newsletter@example.com",
+                "To": "arine@example.com",
+                "Subject": "Weekly #1",
+                "HtmlBody": "<p>Hello, subscribers</p>",
+                "TextBody": "Hello, subscribers\n",
+            })))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&mail_server)
+            .await;
+
+        // when
+        let response = gateway.send(&message).await;
+
+        // then
+        assert_ok!(response);
+    }
+
+    #[tokio::test]
+    async fn sending_message_fails_as_unavailable_on_503() {
+        // given
+        let (mail_server, gateway) = run_mail_server().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mail_server)
+            .await;
+
+        // when
+        let response = gateway.send(&generate_message()).await;
+
+        // then
+        assert_matches!(response, Err(MailTransportError::Unavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn sending_message_fails_as_unavailable_if_mail_service_times_out() {
+        // given
+        let (mail_server, gateway) = run_mail_server().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_delay(Duration::from_secs(1)))
+            .expect(1)
+            .mount(&mail_server)
+            .await;
+
+        // when
+        let response = gateway.send(&generate_message()).await;
+
+        // then
+        assert_matches!(response, Err(MailTransportError::Unavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn sending_message_fails_as_rejected_on_422() {
+        // given
+        let (mail_server, gateway) = run_mail_server().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(422).set_body_string("Mailbox doesn't exist"))
+            .expect(1)
+            .mount(&mail_server)
+            .await;
+
+        // when
+        let response = gateway.send(&generate_message()).await;
+
+        // then
+        assert_matches!(
+            response,
+            Err(MailTransportError::Rejected(reason)) if reason.contains("Mailbox doesn't exist")
+        );
+    }
+}
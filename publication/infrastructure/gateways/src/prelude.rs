@@ -0,0 +1,13 @@
+pub use reqwest::{
+    Client as HttpClient,
+    Url as HttpUrl,
+};
+
+pub use crate::mail_http_gateway::{
+    MailHttpGateway,
+    MailHttpGatewayOptions,
+};
+pub use crate::subscription_http_gateway::{
+    SubscriptionHttpGateway,
+    SubscriptionHttpGatewayOptions,
+};
@@ -0,0 +1,365 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use domain::prelude::{
+    ConfirmedSubscriber,
+    SubscriptionGateway,
+    SubscriptionGatewayError,
+};
+
+const INQUIRE_CONFIRMED_SUBSCRIBERS_PATH: &str =
+    "/subscription/query/inquire-confirmed-subscribers/read";
+
+#[derive(Clone, Debug)]
+pub struct SubscriptionHttpGatewayOptions {
+    // number of subscribers requested at once
+    pub page_size: u64,
+    // retries of a page request failed by an unavailable subscription service
+    pub max_retries: u32,
+    // delay before the first retry, doubled on every later retry
+    pub retry_backoff: Duration,
+}
+
+// Timeouts are set on the client, so that one is shared by all requests to subscription
+#[derive(Clone)]
+pub struct SubscriptionHttpGateway {
+    client: reqwest::Client,
+    origin: reqwest::Url,
+    options: SubscriptionHttpGatewayOptions,
+}
+
+impl SubscriptionHttpGateway {
+    pub fn new(
+        client: reqwest::Client,
+        origin: reqwest::Url,
+        options: SubscriptionHttpGatewayOptions,
+    ) -> Self {
+        Self {
+            client,
+            origin,
+            options,
+        }
+    }
+
+    async fn find_confirmed_subscribers_page(
+        &self,
+        after: Option<Uuid>,
+    ) -> Result<Vec<ConfirmedSubscriber>, SubscriptionGatewayError> {
+        let mut retries = 0;
+        loop {
+            match self.request_confirmed_subscribers_page(after).await {
+                Err(SubscriptionGatewayError::Unavailable(error))
+                    if retries < self.options.max_retries =>
+                {
+                    let delay = self.options.retry_backoff * 2u32.pow(retries);
+                    tracing::warn!(
+                        "Retrying confirmed subscribers request in {:?}: {:?}",
+                        delay,
+                        error
+                    );
+                    tokio::time::sleep(delay).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Overloaded or failing service is unavailable and worth retrying, other failures aren't
+    async fn request_confirmed_subscribers_page(
+        &self,
+        after: Option<Uuid>,
+    ) -> Result<Vec<ConfirmedSubscriber>, SubscriptionGatewayError> {
+        let url = self
+            .origin
+            .join(INQUIRE_CONFIRMED_SUBSCRIBERS_PATH)
+            .map_err(|error| SubscriptionGatewayError::Unavailable(error.into()))?;
+        let query = Query {
+            after,
+            limit: self.options.page_size,
+        };
+
+        let response = self
+            .client
+            .get(url)
+            .query(&query)
+            .send()
+            .await
+            .map_err(|error| SubscriptionGatewayError::Unavailable(error.into()))?;
+
+        let status = response.status();
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(SubscriptionGatewayError::Unavailable(anyhow!(
+                "Subscription service responded with status {}",
+                status
+            )));
+        }
+        if !status.is_success() {
+            return Err(SubscriptionGatewayError::Rejected(status.as_u16()));
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|error| SubscriptionGatewayError::Unavailable(error.into()))?;
+        let subscribers: Vec<Response> = serde_json::from_slice(&body)
+            .map_err(|error| SubscriptionGatewayError::InvalidResponse(error.into()))?;
+
+        Ok(subscribers
+            .into_iter()
+            .map(|subscriber| {
+                ConfirmedSubscriber::new(subscriber.id, subscriber.email, subscriber.name)
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionGateway for SubscriptionHttpGateway {
+    #[tracing::instrument(name = "Finding confirmed subscribers from subscription", skip(self))]
+    async fn find_confirmed_subscribers(
+        &self,
+    ) -> Result<Vec<ConfirmedSubscriber>, SubscriptionGatewayError> {
+        let mut subscribers: Vec<ConfirmedSubscriber> = Vec::new();
+        let mut after = None;
+
+        loop {
+            let page = self.find_confirmed_subscribers_page(after).await?;
+            let page_size = page.len() as u64;
+
+            // pages must move forward, or paging would never end
+            if let Some(last) = page.last() {
+                if after.is_some_and(|after| last.id <= after) {
+                    return Err(SubscriptionGatewayError::InvalidResponse(anyhow!(
+                        "Page after subscriber (ID: {}) doesn't move forward",
+                        last.id
+                    )));
+                }
+                after = Some(last.id);
+            }
+            subscribers.extend(page);
+
+            if page_size < self.options.page_size {
+                return Ok(subscribers);
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Query {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Uuid>,
+    limit: u64,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Response {
+    id: Uuid,
+    email: String,
+    name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::assert_matches;
+    use wiremock::matchers::{
+        method,
+        path,
+        query_param,
+        query_param_is_missing,
+    };
+    use wiremock::{
+        Mock,
+        MockServer,
+        ResponseTemplate,
+    };
+
+    use super::*;
+
+    async fn run_subscription_server(
+        page_size: u64,
+        max_retries: u32,
+    ) -> (MockServer, SubscriptionHttpGateway) {
+        let subscription_server = MockServer::start().await;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .expect("Failed to create subscription client pool");
+        let gateway = SubscriptionHttpGateway::new(
+            client,
+            reqwest::Url::parse(subscription_server.uri().as_ref()).unwrap(),
+            SubscriptionHttpGatewayOptions {
+                page_size,
+                max_retries,
+                retry_backoff: Duration::from_millis(10),
+            },
+        );
+
+        (subscription_server, gateway)
+    }
+
+    // subscribers ordered by ID as subscription pages them
+    fn generate_subscribers(count: usize) -> Vec<serde_json::Value> {
+        let mut ids: Vec<Uuid> = (0..count).map(|_| Uuid::new_v4()).collect();
+        ids.sort();
+
+        ids.into_iter()
+            .map(|id| {
+                serde_json::json!({
+                    "Id": id,
+                    "Email": format!("{}@example.com", id),
+                    "Name": "Arine",
+                })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_pages_until_short_page() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 0).await;
+        let subscribers = generate_subscribers(3);
+
+        Mock::given(method("GET"))
+            .and(path(INQUIRE_CONFIRMED_SUBSCRIBERS_PATH))
+            .and(query_param("limit", "2"))
+            .and(query_param_is_missing("after"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&subscribers[..2]))
+            .expect(1)
+            .mount(&subscription_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(INQUIRE_CONFIRMED_SUBSCRIBERS_PATH))
+            .and(query_param("limit", "2"))
+            .and(query_param("after", subscribers[1]["Id"].as_str().unwrap()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&subscribers[2..]))
+            .expect(1)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let confirmed_subscribers = gateway.find_confirmed_subscribers().await.unwrap();
+
+        // then
+        assert_eq!(
+            confirmed_subscribers
+                .iter()
+                .map(|subscriber| subscriber.id.to_string())
+                .collect::<Vec<_>>(),
+            subscribers
+                .iter()
+                .map(|subscriber| subscriber["Id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(confirmed_subscribers[0].name, "Arine");
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_retries_while_subscription_is_unavailable() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 2).await;
+        let subscribers = generate_subscribers(1);
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&subscription_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&subscribers))
+            .expect(1)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let confirmed_subscribers = gateway.find_confirmed_subscribers().await.unwrap();
+
+        // then
+        assert_eq!(confirmed_subscribers.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_fails_as_unavailable_after_max_retries() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 1).await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(2)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let response = gateway.find_confirmed_subscribers().await;
+
+        // then
+        assert_matches!(response, Err(SubscriptionGatewayError::Unavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_fails_as_unavailable_if_subscription_times_out() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 1).await;
+
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(generate_subscribers(1))
+                    .set_delay(Duration::from_secs(1)),
+            )
+            .expect(2)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let response = gateway.find_confirmed_subscribers().await;
+
+        // then
+        assert_matches!(response, Err(SubscriptionGatewayError::Unavailable(_)));
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_fails_as_rejected_without_retrying_on_400() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 2).await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let response = gateway.find_confirmed_subscribers().await;
+
+        // then
+        assert_matches!(response, Err(SubscriptionGatewayError::Rejected(400)));
+    }
+
+    #[tokio::test]
+    async fn finding_confirmed_subscribers_fails_as_invalid_response_on_malformed_body() {
+        // given
+        let (subscription_server, gateway) = run_subscription_server(2, 2).await;
+
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "Unknown": 1 }])),
+            )
+            .expect(1)
+            .mount(&subscription_server)
+            .await;
+
+        // when
+        let response = gateway.find_confirmed_subscribers().await;
+
+        // then
+        assert_matches!(response, Err(SubscriptionGatewayError::InvalidResponse(_)));
+    }
+}
@@ -0,0 +1,12 @@
+-- Events of subscribers appended in the same transaction as the subscribers projection
+-- Not referencing subscribers, the events are deleted along with the subscriber when it is removed
+CREATE TABLE subscriber_events (
+    position BIGSERIAL PRIMARY KEY,
+    stream_id UUID NOT NULL,
+    sequence BIGINT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    metadata JSONB NOT NULL,
+    recorded_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT subscriber_events_stream_id_sequence_key UNIQUE (stream_id, sequence)
+);
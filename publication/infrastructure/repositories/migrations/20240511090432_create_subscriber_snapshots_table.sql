@@ -0,0 +1,7 @@
+-- Latest state of event sourced subscribers, so that loading replays only the events after it
+CREATE TABLE subscriber_snapshots (
+    stream_id UUID PRIMARY KEY,
+    sequence BIGINT NOT NULL,
+    state JSONB NOT NULL,
+    taken_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
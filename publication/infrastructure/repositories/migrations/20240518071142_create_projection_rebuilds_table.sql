@@ -0,0 +1,10 @@
+-- Progress of rebuilding projections from subscriber events into their shadow tables,
+-- committed along with every replayed batch, so that an interrupted rebuild is resumed
+CREATE TABLE projection_rebuilds (
+    projection TEXT PRIMARY KEY,
+    position BIGINT NOT NULL,
+    processed_events BIGINT NOT NULL,
+    started_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    completed_at TIMESTAMP WITH TIME ZONE
+);
@@ -0,0 +1,14 @@
+CREATE TYPE ISSUE_STATUS AS ENUM ('Draft', 'InReview', 'Scheduled', 'Sent', 'Cancelled');
+
+CREATE TABLE issues (
+    id UUID PRIMARY KEY,
+    title TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    author TEXT NOT NULL,
+    status ISSUE_STATUS NOT NULL,
+    -- Incremented on every update, so concurrent modifications can detect each other
+    version BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX issues_status_idx ON issues (status);
@@ -0,0 +1,19 @@
+CREATE TYPE DELIVERY_STATUS AS ENUM ('Queued', 'Sent', 'Failed', 'Bounced');
+
+-- One row per subscriber an issue is sent to, so that a resumed delivery skips who got it already
+CREATE TABLE deliveries (
+    id UUID PRIMARY KEY,
+    issue_id UUID NOT NULL REFERENCES issues (id),
+    subscriber_id UUID NOT NULL,
+    email_address TEXT NOT NULL,
+    status DELIVERY_STATUS NOT NULL DEFAULT 'Queued',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    -- queued deliveries are claimed once due, and leased by pushing this forward
+    available_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    UNIQUE (issue_id, subscriber_id)
+);
+
+CREATE INDEX deliveries_queued_idx ON deliveries (issue_id, available_at) WHERE status = 'Queued';
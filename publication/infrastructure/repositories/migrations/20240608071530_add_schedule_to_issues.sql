@@ -0,0 +1,6 @@
+ALTER TYPE ISSUE_STATUS ADD VALUE 'Sending' AFTER 'Scheduled';
+
+-- Scheduled issues without a time are sent as soon as possible
+ALTER TABLE issues ADD COLUMN scheduled_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX issues_scheduled_at_idx ON issues (scheduled_at) WHERE status = 'Scheduled';
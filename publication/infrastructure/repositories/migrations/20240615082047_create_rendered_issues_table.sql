@@ -0,0 +1,10 @@
+-- Issues rendered for subscribers, only the latest rendered version of an issue is kept
+CREATE TABLE rendered_issues (
+    issue_id UUID NOT NULL REFERENCES issues (id),
+    version BIGINT NOT NULL,
+    subject TEXT NOT NULL,
+    html TEXT NOT NULL,
+    text TEXT NOT NULL,
+    rendered_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    PRIMARY KEY (issue_id, version)
+);
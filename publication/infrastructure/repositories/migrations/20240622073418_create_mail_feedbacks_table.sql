@@ -0,0 +1,18 @@
+CREATE TYPE MAIL_FEEDBACK_KIND AS ENUM ('HardBounce', 'SoftBounce', 'Complaint');
+
+-- Bounces and complaints reported by mail providers, soft bounces are counted per subscriber
+CREATE TABLE mail_feedbacks (
+    -- given by the provider, so that a notification sent again is recorded once
+    id TEXT PRIMARY KEY,
+    subscriber_id UUID NOT NULL,
+    kind MAIL_FEEDBACK_KIND NOT NULL,
+    email_address TEXT NOT NULL,
+    description TEXT,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    received_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE INDEX mail_feedbacks_subscriber_idx ON mail_feedbacks (subscriber_id, kind, occurred_at);
+
+-- feedback only tells the address, whose subscriber is found by deliveries to it
+CREATE INDEX deliveries_email_address_idx ON deliveries (lower(email_address), created_at);
@@ -0,0 +1,24 @@
+-- Start streams of subscribers saved before their events were stored, so that event sourced
+-- repositories find them: a created event, followed by the verification of the email if any
+WITH streamless AS (
+    SELECT * FROM subscribers AS live
+    WHERE NOT EXISTS (SELECT 1 FROM subscriber_events AS events WHERE events.stream_id = live.id)
+)
+INSERT INTO subscriber_events (stream_id, sequence, event_type, payload, metadata)
+SELECT stream_id, sequence, event_type, payload, '{"SchemaVersion": 1}' FROM (
+    SELECT
+        id AS stream_id,
+        1 AS sequence,
+        'SubscriberCreated' AS event_type,
+        jsonb_build_object('Id', id, 'Email', email_address, 'Name', name) AS payload
+    FROM streamless
+    UNION ALL
+    SELECT
+        id,
+        2,
+        'SubscriberEmailVerifiedAs' || email_verification_status,
+        '{}'
+    FROM streamless
+    WHERE email_verification_status <> 'Unverified'
+) AS events
+ORDER BY stream_id, sequence;
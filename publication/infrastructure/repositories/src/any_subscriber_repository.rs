@@ -0,0 +1,58 @@
+use uuid::Uuid;
+
+use domain::prelude::{
+    Subscriber,
+    SubscriberError,
+    SubscriberRepository,
+};
+
+use crate::subscriber_event_sourced_sea_orm_repository::SubscriberEventSourcedSeaOrmRepository;
+use crate::subscriber_sea_orm_repository::SubscriberSeaOrmRepository;
+
+// Subscriber repository chosen by configuration at startup
+#[derive(Clone)]
+pub enum AnySubscriberRepository {
+    StateBased(SubscriberSeaOrmRepository),
+    EventSourced(SubscriberEventSourcedSeaOrmRepository),
+}
+
+#[async_trait::async_trait]
+impl SubscriberRepository for AnySubscriberRepository {
+    async fn save(&self, subscriber: &mut Subscriber) -> Result<(), SubscriberError> {
+        match self {
+            Self::StateBased(repository) => repository.save(subscriber).await,
+            Self::EventSourced(repository) => repository.save(subscriber).await,
+        }
+    }
+
+    async fn modify<F>(&self, id: Uuid, modifier: F) -> Result<(), SubscriberError>
+    where
+        F: FnMut(&mut Subscriber) -> Result<(), SubscriberError> + Send,
+    {
+        match self {
+            Self::StateBased(repository) => repository.modify(id, modifier).await,
+            Self::EventSourced(repository) => repository.modify(id, modifier).await,
+        }
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Subscriber>, SubscriberError> {
+        match self {
+            Self::StateBased(repository) => repository.find_by_id(id).await,
+            Self::EventSourced(repository) => repository.find_by_id(id).await,
+        }
+    }
+
+    async fn find_all(&self) -> Result<Vec<Subscriber>, SubscriberError> {
+        match self {
+            Self::StateBased(repository) => repository.find_all().await,
+            Self::EventSourced(repository) => repository.find_all().await,
+        }
+    }
+
+    async fn remove(&self, id: Uuid) -> Result<(), SubscriberError> {
+        match self {
+            Self::StateBased(repository) => repository.remove(id).await,
+            Self::EventSourced(repository) => repository.remove(id).await,
+        }
+    }
+}
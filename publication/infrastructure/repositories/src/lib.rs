@@ -1,2 +1,12 @@
+mod any_subscriber_repository;
+mod delivery_sea_orm_repository;
+mod issue_sea_orm_repository;
+mod mail_feedback_sea_orm_repository;
+mod modify_attempts;
 pub mod prelude;
+mod rendered_issue_sea_orm_cache;
+mod subscriber_event_sea_orm_repository;
+mod subscriber_event_sourced_sea_orm_repository;
+mod subscriber_projection_sea_orm_rebuilder;
 mod subscriber_sea_orm_repository;
+mod subscriber_snapshot_sea_orm_repository;
@@ -0,0 +1,42 @@
+// Modifications read, change and write back under optimistic locking, so a modification losing
+// the race to a concurrent one is retried on a fresh read up to a number of attempts
+pub(crate) struct ModifyAttempts {
+    attempts: usize,
+}
+
+impl ModifyAttempts {
+    const MAX_ATTEMPTS: usize = 3;
+
+    pub fn new() -> Self {
+        Self { attempts: 1 }
+    }
+
+    // Count another attempt, unless the attempts are used up
+    pub fn retry(&mut self) -> bool {
+        if self.attempts < Self::MAX_ATTEMPTS {
+            self.attempts += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modification_is_retried_until_attempts_are_used_up() {
+        // given
+        let mut attempts = ModifyAttempts::new();
+
+        // when
+        let retries = std::iter::from_fn(|| Some(attempts.retry()))
+            .take(ModifyAttempts::MAX_ATTEMPTS + 1)
+            .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(retries, [true, true, false, false]);
+    }
+}
@@ -1,6 +1,14 @@
 pub use sea_orm::{
     ConnectOptions as DatabaseConnectionOptions,
     Database as DatabaseConnection,
+    DatabaseConnection as DatabaseConnectionPool,
 };
 
+pub use crate::any_subscriber_repository::AnySubscriberRepository;
+pub use crate::delivery_sea_orm_repository::DeliverySeaOrmRepository;
+pub use crate::issue_sea_orm_repository::IssueSeaOrmRepository;
+pub use crate::mail_feedback_sea_orm_repository::MailFeedbackSeaOrmRepository;
+pub use crate::rendered_issue_sea_orm_cache::RenderedIssueSeaOrmCache;
+pub use crate::subscriber_event_sourced_sea_orm_repository::SubscriberEventSourcedSeaOrmRepository;
+pub use crate::subscriber_projection_sea_orm_rebuilder::SubscriberProjectionSeaOrmRebuilder;
 pub use crate::subscriber_sea_orm_repository::SubscriberSeaOrmRepository;
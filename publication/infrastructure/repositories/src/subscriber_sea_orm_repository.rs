@@ -15,6 +15,9 @@ use domain::prelude::{
     SubscriberRepository,
 };
 
+use crate::modify_attempts::ModifyAttempts;
+use crate::subscriber_event_sea_orm_repository;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "subscribers")]
 pub struct Model {
@@ -25,6 +28,7 @@ pub struct Model {
     pub email_verification_status: EmailVerificationStatus,
     #[sea_orm(column_type = "Text")]
     pub name: String,
+    pub version: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
@@ -76,6 +80,7 @@ impl From<&mut Subscriber> for ActiveModel {
                 &subscriber.email.verification_status,
             )),
             name: ActiveValue::Set(subscriber.name.clone()),
+            version: ActiveValue::Set(subscriber.version),
         }
     }
 }
@@ -92,6 +97,7 @@ impl From<Model> for Subscriber {
             },
             name: data_model.name,
             pending_events: vec![],
+            version: data_model.version,
         }
     }
 }
@@ -105,34 +111,8 @@ impl SubscriberSeaOrmRepository {
     pub fn new(pool: DatabaseConnection) -> Self {
         Self { pool }
     }
-}
-
-// TODO: Implement saving pending events
-#[async_trait::async_trait]
-impl SubscriberRepository for SubscriberSeaOrmRepository {
-    #[tracing::instrument(name = "Saving subscriber details", skip(self))]
-    async fn save(&self, subscriber: &mut Subscriber) -> Result<(), SubscriberError> {
-        let data_model = ActiveModel::from(subscriber);
-
-        Entity::insert(data_model)
-            .exec(&self.pool)
-            .await
-            .map_err(|error| {
-                if error
-                    .to_string()
-                    .contains("duplicate key value violates unique constraint")
-                {
-                    SubscriberError::InvalidSubscriberEmail
-                } else {
-                    SubscriberError::RepositoryOperationFailed(error.into())
-                }
-            })?;
-
-        Ok(())
-    }
 
-    #[tracing::instrument(name = "Modifying subscriber details", skip(self, modifier))]
-    async fn modify<F>(&self, id: Uuid, mut modifier: F) -> Result<(), SubscriberError>
+    async fn try_modify<F>(&self, id: Uuid, modifier: &mut F) -> Result<(), SubscriberError>
     where
         F: FnMut(&mut Subscriber) -> Result<(), SubscriberError> + Send,
     {
@@ -149,13 +129,14 @@ impl SubscriberRepository for SubscriberSeaOrmRepository {
             .await
             .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?
             .map(Subscriber::from)
-            .unwrap_or_default();
+            .ok_or(SubscriberError::SubscriberNotFound(id))?;
 
         modifier(&mut subscriber)?;
 
         let data_model = ActiveModel::from(&mut subscriber);
 
-        Entity::insert(data_model)
+        // update only if nobody else has updated the subscriber since it was read
+        let rows_affected = Entity::insert(data_model)
             .on_conflict(
                 OnConflict::column(Column::Id)
                     .update_columns([
@@ -163,9 +144,11 @@ impl SubscriberRepository for SubscriberSeaOrmRepository {
                         Column::EmailVerificationStatus,
                         Column::Name,
                     ])
+                    .value(Column::Version, Expr::col((Entity, Column::Version)).add(1))
+                    .action_and_where(Expr::col((Entity, Column::Version)).eq(subscriber.version))
                     .to_owned(),
             )
-            .exec(&transaction)
+            .exec_without_returning(&transaction)
             .await
             .map_err(|error| {
                 if error
@@ -178,6 +161,13 @@ impl SubscriberRepository for SubscriberSeaOrmRepository {
                 }
             })?;
 
+        if rows_affected == 0 {
+            return Err(SubscriberError::ConcurrentModification(id));
+        }
+
+        subscriber_event_sea_orm_repository::append(&transaction, id, &subscriber.pending_events)
+            .await?;
+
         transaction
             .commit()
             .await
@@ -186,6 +176,68 @@ impl SubscriberRepository for SubscriberSeaOrmRepository {
 
         Ok(())
     }
+}
+
+#[async_trait::async_trait]
+impl SubscriberRepository for SubscriberSeaOrmRepository {
+    #[tracing::instrument(name = "Saving subscriber details", skip(self))]
+    async fn save(&self, subscriber: &mut Subscriber) -> Result<(), SubscriberError> {
+        let id = subscriber.id;
+        let transaction = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to start a transaction")
+            .map_err(SubscriberError::RepositoryOperationFailed)?;
+
+        Entity::insert(ActiveModel::from(&mut *subscriber))
+            .exec(&transaction)
+            .await
+            .map_err(|error| {
+                let message = error.to_string();
+                if message.contains("subscribers_pkey") {
+                    SubscriberError::SubscriberAlreadyExists(id)
+                } else if message.contains("duplicate key value violates unique constraint") {
+                    SubscriberError::InvalidSubscriberEmail
+                } else {
+                    SubscriberError::RepositoryOperationFailed(error.into())
+                }
+            })?;
+
+        subscriber_event_sea_orm_repository::append(&transaction, id, &subscriber.pending_events)
+            .await?;
+
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit a transaction")
+            .map_err(SubscriberError::RepositoryOperationFailed)?;
+
+        // events are persisted, so they aren't pending any more
+        subscriber.pending_events.clear();
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "Modifying subscriber details", skip(self, modifier))]
+    async fn modify<F>(&self, id: Uuid, mut modifier: F) -> Result<(), SubscriberError>
+    where
+        F: FnMut(&mut Subscriber) -> Result<(), SubscriberError> + Send,
+    {
+        let mut attempts = ModifyAttempts::new();
+
+        loop {
+            match self.try_modify(id, &mut modifier).await {
+                Err(SubscriberError::ConcurrentModification(_)) if attempts.retry() => {
+                    tracing::warn!(
+                        "Subscriber (ID: {}) was modified concurrently, retrying",
+                        id
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
 
     #[tracing::instrument(name = "Searching subscriber details by ID", skip(self))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Subscriber>, SubscriberError> {
@@ -207,6 +259,36 @@ impl SubscriberRepository for SubscriberSeaOrmRepository {
             .map(Subscriber::from)
             .collect())
     }
+
+    #[tracing::instrument(name = "Removing subscriber details", skip(self))]
+    async fn remove(&self, id: Uuid) -> Result<(), SubscriberError> {
+        let transaction = self
+            .pool
+            .begin()
+            .await
+            .context("Failed to start a transaction")
+            .map_err(SubscriberError::RepositoryOperationFailed)?;
+
+        let result = Entity::delete_by_id(id)
+            .exec(&transaction)
+            .await
+            .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?;
+
+        if result.rows_affected == 0 {
+            return Err(SubscriberError::SubscriberNotFound(id));
+        }
+
+        // events go along with the subscriber, so that rebuilding projections doesn't bring it back
+        subscriber_event_sea_orm_repository::delete_stream(&transaction, id).await?;
+
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit a transaction")
+            .map_err(SubscriberError::RepositoryOperationFailed)?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -214,7 +296,10 @@ mod tests {
     use fake::faker::internet::en::SafeEmail;
     use fake::faker::name::en::FirstName;
     use fake::Fake;
-    use sea_orm::ConnectionTrait;
+    use sea_orm::{
+        ConnectionTrait,
+        QueryOrder,
+    };
     use uuid::Uuid;
 
     use super::*;
@@ -267,6 +352,7 @@ mod tests {
             },
             name: FirstName().fake(),
             pending_events: vec![],
+            version: 0,
         }
     }
 
@@ -304,6 +390,25 @@ mod tests {
         assert!(not_existing_subscriber.is_none());
     }
 
+    #[tokio::test]
+    async fn saving_subscriber_with_existing_id_returns_subscriber_already_exists_error() {
+        // given
+        let repository = get_repository(false).await;
+        let mut subscriber = generate_subscriber();
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        let mut same_subscriber = generate_subscriber();
+        same_subscriber.id = subscriber.id;
+        let response = repository.save(&mut same_subscriber).await;
+
+        // then
+        assert!(matches!(
+            response,
+            Err(SubscriberError::SubscriberAlreadyExists(id)) if id == subscriber.id
+        ));
+    }
+
     #[tokio::test]
     async fn modifying_subscriber_name_succeeds_when_no_errors_from_repository() {
         // given
@@ -349,4 +454,144 @@ mod tests {
             SubscriberEmailVerifiationStatus::Unverified,
         );
     }
+
+    #[tokio::test]
+    async fn modifying_not_existing_subscriber_returns_subscriber_not_found_error() {
+        // given
+        let repository = get_repository(false).await;
+        let subscriber = generate_subscriber();
+
+        // when
+        let response = repository
+            .modify(subscriber.id, |subscriber| {
+                subscriber.name = "New name".to_string();
+                Ok(())
+            })
+            .await;
+
+        // then
+        assert!(matches!(
+            response,
+            Err(SubscriberError::SubscriberNotFound(id)) if id == subscriber.id
+        ));
+    }
+
+    #[tokio::test]
+    async fn saving_and_modifying_subscriber_appends_its_events_to_its_stream() {
+        // given
+        let repository = get_repository(true).await;
+        let mut subscriber = Subscriber::default();
+        subscriber.create(
+            Uuid::new_v4(),
+            SafeEmail().fake(),
+            FirstName().fake::<String>(),
+        );
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        repository
+            .modify(subscriber.id, |subscriber| {
+                subscriber.update("New name".to_string());
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let response = repository
+            .modify(subscriber.id, |subscriber| {
+                subscriber.update("Discarded name".to_string());
+                Err(SubscriberError::Unexpected(anyhow::anyhow!("Some errors")))
+            })
+            .await;
+
+        // then
+        assert!(response.is_err());
+        assert!(subscriber.pending_events.is_empty());
+        let events = subscriber_event_sea_orm_repository::Entity::find()
+            .filter(subscriber_event_sea_orm_repository::Column::StreamId.eq(subscriber.id))
+            .order_by_asc(subscriber_event_sea_orm_repository::Column::Sequence)
+            .all(&repository.pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.sequence, event.event_type.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "SubscriberCreated"), (2, "SubscriberUpdated")]
+        );
+    }
+
+    #[tokio::test]
+    async fn removing_subscriber_makes_it_not_found() {
+        // given
+        let repository = get_repository(false).await;
+        let mut subscriber = generate_subscriber();
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        repository.remove(subscriber.id).await.unwrap();
+
+        // then
+        let removed_subscriber = repository.find_by_id(subscriber.id).await.unwrap();
+        assert!(removed_subscriber.is_none());
+        let events = subscriber_event_sea_orm_repository::find_stream_after(
+            &repository.pool,
+            subscriber.id,
+            0,
+        )
+        .await
+        .unwrap();
+        assert!(events.is_empty());
+        assert!(matches!(
+            repository.remove(subscriber.id).await,
+            Err(SubscriberError::SubscriberNotFound(id)) if id == subscriber.id
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn modifying_subscriber_concurrently_keeps_both_modifications() {
+        // given
+        let repository = get_repository(false).await;
+        let mut subscriber = generate_subscriber();
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        // modifiers are synchronous, so block each worker to make both read the same version
+        let renaming = tokio::spawn({
+            let repository = repository.clone();
+            async move {
+                repository
+                    .modify(subscriber.id, |subscriber| {
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                        subscriber.name = "New name".to_string();
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+        let verifying = tokio::spawn({
+            let repository = repository.clone();
+            async move {
+                repository
+                    .modify(subscriber.id, |subscriber| {
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                        subscriber.email.verification_status =
+                            SubscriberEmailVerifiationStatus::Valid;
+                        Ok(())
+                    })
+                    .await
+            }
+        });
+
+        // then
+        assert!(renaming.await.unwrap().is_ok());
+        assert!(verifying.await.unwrap().is_ok());
+        let persisted_subscriber = repository.find_by_id(subscriber.id).await.unwrap().unwrap();
+        assert_eq!(persisted_subscriber.name, "New name");
+        assert_eq!(
+            persisted_subscriber.email.verification_status,
+            SubscriberEmailVerifiationStatus::Valid,
+        );
+        assert_eq!(persisted_subscriber.version, 2);
+    }
 }
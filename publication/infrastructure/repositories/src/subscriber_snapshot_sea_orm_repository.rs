@@ -0,0 +1,155 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ActiveValue,
+    ConnectionTrait,
+};
+use uuid::Uuid;
+
+use domain::prelude::{
+    Subscriber,
+    SubscriberEmail,
+    SubscriberEmailVerifiationStatus,
+    SubscriberError,
+};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
+#[sea_orm(table_name = "subscriber_snapshots")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub stream_id: Uuid,
+    // sequence of the last event applied to the state
+    pub sequence: i64,
+    pub state: Json,
+    pub taken_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SubscriberState {
+    id: Uuid,
+    email_address: String,
+    email_verification_status: String,
+    name: String,
+}
+
+impl TryFrom<&Subscriber> for ActiveModel {
+    type Error = SubscriberError;
+
+    fn try_from(subscriber: &Subscriber) -> Result<Self, Self::Error> {
+        let email_verification_status = match subscriber.email.verification_status {
+            SubscriberEmailVerifiationStatus::Unverified => "Unverified",
+            SubscriberEmailVerifiationStatus::Valid => "Valid",
+            SubscriberEmailVerifiationStatus::Invalid => "Invalid",
+        };
+        let state = SubscriberState {
+            id: subscriber.id,
+            email_address: subscriber.email.address.clone(),
+            email_verification_status: email_verification_status.to_string(),
+            name: subscriber.name.clone(),
+        };
+
+        Ok(Self {
+            stream_id: ActiveValue::Set(subscriber.id),
+            sequence: ActiveValue::Set(subscriber.version),
+            state: ActiveValue::Set(
+                serde_json::to_value(state)
+                    .map_err(|error| SubscriberError::Unexpected(error.into()))?,
+            ),
+            taken_at: ActiveValue::NotSet,
+        })
+    }
+}
+
+impl TryFrom<Model> for Subscriber {
+    type Error = SubscriberError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let state: SubscriberState = serde_json::from_value(model.state)
+            .map_err(|error| SubscriberError::Unexpected(error.into()))?;
+        let verification_status = match state.email_verification_status.as_str() {
+            "Unverified" => SubscriberEmailVerifiationStatus::Unverified,
+            "Valid" => SubscriberEmailVerifiationStatus::Valid,
+            "Invalid" => SubscriberEmailVerifiationStatus::Invalid,
+            status => {
+                return Err(SubscriberError::Unexpected(anyhow::anyhow!(
+                    "Email verification status {} is unknown",
+                    status
+                )))
+            }
+        };
+
+        Ok(Self {
+            id: state.id,
+            email: SubscriberEmail {
+                address: state.email_address,
+                verification_status,
+            },
+            name: state.name,
+            pending_events: vec![],
+            version: model.sequence,
+        })
+    }
+}
+
+// Snapshot of the subscriber, whose version is the sequence of the last event applied
+pub(crate) async fn find<C>(connection: &C, id: Uuid) -> Result<Option<Subscriber>, SubscriberError>
+where
+    C: ConnectionTrait,
+{
+    Entity::find_by_id(id)
+        .one(connection)
+        .await
+        .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?
+        .map(Subscriber::try_from)
+        .transpose()
+}
+
+pub(crate) async fn find_all<C>(connection: &C) -> Result<Vec<Subscriber>, SubscriberError>
+where
+    C: ConnectionTrait,
+{
+    Entity::find()
+        .all(connection)
+        .await
+        .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?
+        .into_iter()
+        .map(Subscriber::try_from)
+        .collect()
+}
+
+// Replace the snapshot of the subscriber with its current state
+pub(crate) async fn save<C>(connection: &C, subscriber: &Subscriber) -> Result<(), SubscriberError>
+where
+    C: ConnectionTrait,
+{
+    Entity::insert(ActiveModel::try_from(subscriber)?)
+        .on_conflict(
+            OnConflict::column(Column::StreamId)
+                .update_columns([Column::Sequence, Column::State])
+                .value(Column::TakenAt, Expr::current_timestamp())
+                .to_owned(),
+        )
+        .exec_without_returning(connection)
+        .await
+        .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete<C>(connection: &C, id: Uuid) -> Result<(), SubscriberError>
+where
+    C: ConnectionTrait,
+{
+    Entity::delete_by_id(id)
+        .exec(connection)
+        .await
+        .map_err(|error| SubscriberError::RepositoryOperationFailed(error.into()))?;
+
+    Ok(())
+}
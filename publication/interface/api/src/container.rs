@@ -1,48 +1,241 @@
 use axum::extract::FromRef;
 
 use domain::prelude::{
+    DeliveryRepository,
+    IssueCommandExecutor,
+    IssueQueryReader,
+    IssueRenderer,
+    IssueRepository,
+    MailFeedbackHandler,
+    MailFeedbackRepository,
+    RenderedIssueCache,
     SubscriberCommandExecutor,
+    SubscriberProjectionMaintainer,
+    SubscriberProjectionRebuilder,
     SubscriberQueryReader,
+    SubscriberReconciler,
     SubscriberRepository,
+    SubscriptionGateway,
 };
 
+use crate::guards::admin_token::AdminToken;
+use crate::receivers::signature::WebhookSignature;
+
 #[derive(Clone)]
-pub struct Container<R>
+pub struct Container<R, G, P, I, C, D, F>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
     subscriber_command_executor: SubscriberCommandExecutor<R>,
     subscriber_query_reader: SubscriberQueryReader<R>,
+    subscriber_reconciler: SubscriberReconciler<R, G>,
+    subscriber_projection_maintainer: SubscriberProjectionMaintainer<P>,
+    issue_command_executor: IssueCommandExecutor<I>,
+    issue_query_reader: IssueQueryReader<I>,
+    issue_renderer: IssueRenderer<I, C>,
+    mail_feedback_handler: MailFeedbackHandler<R, D, F>,
+    webhook_signature: WebhookSignature,
+    admin_token: AdminToken,
 }
 
-impl<R> Container<R>
+impl<R, G, P, I, C, D, F> Container<R, G, P, I, C, D, F>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
-    pub fn new(subscriber_repository: R) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        subscriber_repository: R,
+        subscription_gateway: G,
+        subscriber_projection_rebuilder: P,
+        issue_repository: I,
+        rendered_issue_cache: C,
+        mail_feedback_handler: MailFeedbackHandler<R, D, F>,
+        // shared with mail providers, which sign their notifications by it
+        webhook_secret: String,
+        // sent by operators calling admin routes
+        admin_token: String,
+    ) -> Self {
         Self {
             subscriber_command_executor: SubscriberCommandExecutor::new(
                 subscriber_repository.clone(),
             ),
             subscriber_query_reader: SubscriberQueryReader::new(subscriber_repository.clone()),
+            subscriber_reconciler: SubscriberReconciler::new(
+                subscriber_repository.clone(),
+                subscription_gateway,
+            ),
+            subscriber_projection_maintainer: SubscriberProjectionMaintainer::new(
+                subscriber_projection_rebuilder,
+            ),
+            issue_command_executor: IssueCommandExecutor::new(issue_repository.clone()),
+            issue_query_reader: IssueQueryReader::new(issue_repository.clone()),
+            issue_renderer: IssueRenderer::new(issue_repository, rendered_issue_cache),
+            mail_feedback_handler,
+            webhook_signature: WebhookSignature::new(webhook_secret),
+            admin_token: AdminToken::new(admin_token),
         }
     }
 }
 
-impl<R> FromRef<Container<R>> for SubscriberCommandExecutor<R>
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for SubscriberCommandExecutor<R>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R>) -> Self {
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
         container.subscriber_command_executor.clone()
     }
 }
 
-impl<R> FromRef<Container<R>> for SubscriberQueryReader<R>
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for SubscriberQueryReader<R>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R>) -> Self {
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
         container.subscriber_query_reader.clone()
     }
 }
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for SubscriberReconciler<R, G>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.subscriber_reconciler.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>>
+    for SubscriberProjectionMaintainer<P>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.subscriber_projection_maintainer.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for IssueCommandExecutor<I>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.issue_command_executor.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for IssueQueryReader<I>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.issue_query_reader.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for IssueRenderer<I, C>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.issue_renderer.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for MailFeedbackHandler<R, D, F>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.mail_feedback_handler.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for WebhookSignature
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.webhook_signature.clone()
+    }
+}
+
+impl<R, G, P, I, C, D, F> FromRef<Container<R, G, P, I, C, D, F>> for AdminToken
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, G, P, I, C, D, F>) -> Self {
+        container.admin_token.clone()
+    }
+}
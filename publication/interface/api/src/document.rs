@@ -1,15 +1,60 @@
 use utoipa::OpenApi;
 
-use crate::checkers;
+use crate::{
+    checkers,
+    error,
+    executors,
+    readers,
+    receivers,
+};
 
-// TODO: utoipa crate doesn't support State with impl trait
 // TODO: Search auto search for handlers
 #[derive(OpenApi)]
 #[openapi(
     paths(
         checkers::liveness::handle,
         checkers::readiness::handle,
+        executors::create_subscriber::execute,
+        executors::update_subscriber::execute,
+        executors::verify_subscriber_email::execute,
+        readers::inquire_subscriber::read,
+        readers::inquire_all_subscribers::read,
+        executors::draft_issue::execute,
+        executors::revise_issue::execute,
+        executors::submit_issue_for_review::execute,
+        executors::return_issue_to_draft::execute,
+        executors::publish_issue::execute,
+        executors::schedule_issue::execute,
+        executors::cancel_issue::execute,
+        readers::inquire_issue::read,
+        readers::inquire_all_issues::read,
+        readers::preview_issue::read,
+        receivers::mail_feedback::receive,
+        executors::reconcile_subscribers::execute,
+        executors::rebuild_projection::execute,
+        readers::inquire_projection_rebuild::read,
     ),
+    components(schemas(
+        error::ErrorMessage,
+        executors::create_subscriber::Request,
+        executors::update_subscriber::Request,
+        executors::verify_subscriber_email::Request,
+        executors::verify_subscriber_email::EmailVerificationStatus,
+        readers::inquire_subscriber::Response,
+        executors::draft_issue::Request,
+        executors::draft_issue::Response,
+        executors::revise_issue::Request,
+        executors::submit_issue_for_review::Request,
+        executors::return_issue_to_draft::Request,
+        executors::publish_issue::Request,
+        executors::schedule_issue::Request,
+        executors::cancel_issue::Request,
+        readers::inquire_issue::Response,
+        readers::preview_issue::Response,
+        receivers::providers::MailProvider,
+        executors::reconcile_subscribers::Response,
+        readers::inquire_projection_rebuild::Response,
+    )),
     tags(
         (name = "Publication", description = "Publishing APIs of newsletter to subscribers")
     )
@@ -10,8 +10,30 @@ use axum::response::{
 };
 use axum::Json;
 
+use domain::prelude::{
+    IssueError,
+    MailFeedbackError,
+    RenderingError,
+    SubscriberError,
+};
+
 #[derive(thiserror::Error)]
 pub enum ApiError {
+    #[error("Bad Request")]
+    BadRequest(#[source] anyhow::Error),
+
+    #[error("Unauthorized")]
+    Unauthorized(#[source] anyhow::Error),
+
+    #[error("Not Found")]
+    NotFound(#[source] anyhow::Error),
+
+    #[error("Conflict")]
+    Conflict(#[source] anyhow::Error),
+
+    #[error("Service Unavailable")]
+    Unavailable(#[source] anyhow::Error),
+
     #[error("Internal Server Error")]
     Unexpected(#[source] anyhow::Error),
 }
@@ -22,8 +44,67 @@ impl Debug for ApiError {
     }
 }
 
-#[derive(serde::Serialize, serde::Deserialize)]
-struct ErrorMessage {
+impl From<SubscriberError> for ApiError {
+    fn from(error: SubscriberError) -> Self {
+        match error {
+            SubscriberError::InvalidSubscriberEmailVerificationStatus
+            | SubscriberError::InvalidSubscriberEmail => ApiError::BadRequest(error.into()),
+            SubscriberError::SubscriberNotFound(_) => ApiError::NotFound(error.into()),
+            SubscriberError::SubscriberAlreadyExists(_)
+            | SubscriberError::ConcurrentModification(_) => ApiError::Conflict(error.into()),
+            SubscriberError::MultipleSubscribersFound
+            | SubscriberError::InvalidSubscriberEvent(_)
+            | SubscriberError::RepositoryOperationFailed(_)
+            | SubscriberError::Unexpected(_) => ApiError::Unexpected(error.into()),
+        }
+    }
+}
+
+impl From<IssueError> for ApiError {
+    fn from(error: IssueError) -> Self {
+        match error {
+            IssueError::InvalidIssueTitle
+            | IssueError::InvalidIssueSubject
+            | IssueError::InvalidIssueBody
+            | IssueError::InvalidIssueTemplate(_)
+            | IssueError::InvalidIssueSchedule(_) => ApiError::BadRequest(error.into()),
+            IssueError::IssueNotFound(_) => ApiError::NotFound(error.into()),
+            IssueError::IssueNotEditable(_)
+            | IssueError::InvalidIssueStatusTransition { .. }
+            | IssueError::IssueNotDue(_)
+            | IssueError::IssueAlreadyExists(_)
+            | IssueError::ConcurrentModification(_) => ApiError::Conflict(error.into()),
+            IssueError::MultipleIssuesFound
+            | IssueError::RepositoryOperationFailed(_)
+            | IssueError::Unexpected(_) => ApiError::Unexpected(error.into()),
+        }
+    }
+}
+
+impl From<RenderingError> for ApiError {
+    fn from(error: RenderingError) -> Self {
+        match error {
+            RenderingError::IssueNotFound(_) => ApiError::NotFound(error.into()),
+            RenderingError::IssueOperationFailed(error) => error.into(),
+            RenderingError::InvalidTemplate(_)
+            | RenderingError::StyleInliningFailed(_)
+            | RenderingError::CacheOperationFailed(_) => ApiError::Unexpected(error.into()),
+        }
+    }
+}
+
+impl From<MailFeedbackError> for ApiError {
+    fn from(error: MailFeedbackError) -> Self {
+        match error {
+            MailFeedbackError::SubscriberOperationFailed(error) => error.into(),
+            MailFeedbackError::DeliveryOperationFailed(_)
+            | MailFeedbackError::RepositoryOperationFailed(_) => ApiError::Unexpected(error.into()),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub(crate) struct ErrorMessage {
     code: String,
     message: String,
 }
@@ -33,6 +114,41 @@ impl IntoResponse for ApiError {
         tracing::error!("{:?}", self);
 
         let response = match self {
+            ApiError::BadRequest(error) => (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorMessage {
+                    code: "BadRequest".to_string(),
+                    message: error.to_string(),
+                }),
+            ),
+            ApiError::Unauthorized(error) => (
+                StatusCode::UNAUTHORIZED,
+                Json(ErrorMessage {
+                    code: "Unauthorized".to_string(),
+                    message: error.to_string(),
+                }),
+            ),
+            ApiError::NotFound(error) => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorMessage {
+                    code: "NotFound".to_string(),
+                    message: error.to_string(),
+                }),
+            ),
+            ApiError::Conflict(error) => (
+                StatusCode::CONFLICT,
+                Json(ErrorMessage {
+                    code: "Conflict".to_string(),
+                    message: error.to_string(),
+                }),
+            ),
+            ApiError::Unavailable(error) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorMessage {
+                    code: "Unavailable".to_string(),
+                    message: error.to_string(),
+                }),
+            ),
             ApiError::Unexpected(error) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorMessage {
@@ -0,0 +1,43 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    CancelIssue,
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = CancelIssueRequest)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[utoipa::path(post, path = "/publication/command/cancel-issue/execute",
+    request_body = CancelIssueRequest,
+    responses(
+        (status = 200, description = "Issue is cancelled"),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue has started sending or is cancelled already", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Cancelling an issue", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let cancel_issue = IssueCommand::CancelIssue(CancelIssue::new(request.id));
+    issue_command_executor.execute(cancel_issue).await?;
+
+    Ok(StatusCode::OK)
+}
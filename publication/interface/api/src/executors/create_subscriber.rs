@@ -0,0 +1,107 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    CreateSubscriber,
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = CreateSubscriberRequest)]
+pub struct Request {
+    // same as the ID of the subscriber in subscription
+    id: Uuid,
+    email: String,
+    name: String,
+}
+
+#[utoipa::path(post, path = "/publication/command/create-subscriber/execute",
+    request_body = CreateSubscriberRequest,
+    responses(
+        (status = 201, description = "Subscriber is created"),
+        (status = 400, description = "Email is taken by another subscriber", body = ErrorMessage),
+        (status = 409, description = "Subscriber with the ID already exists", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Creating a subscriber", skip(subscriber_command_executor))]
+pub async fn execute<R>(
+    State(subscriber_command_executor): State<SubscriberCommandExecutor<R>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    R: SubscriberRepository,
+{
+    let create_subscriber = SubscriberCommand::CreateSubscriber(CreateSubscriber::new(
+        request.id,
+        request.email,
+        request.name,
+    ));
+    subscriber_command_executor
+        .execute(create_subscriber)
+        .await?;
+
+    Ok(StatusCode::CREATED)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::FakeSubscriberRepository;
+    use fake::faker::internet::en::SafeEmail;
+    use fake::faker::name::en::LastName;
+    use fake::Fake;
+
+    use super::*;
+
+    fn request(id: Uuid) -> Request {
+        Request {
+            id,
+            email: SafeEmail().fake(),
+            name: LastName().fake(),
+        }
+    }
+
+    #[tokio::test]
+    async fn creating_subscriber_returns_created() {
+        // given
+        let subscriber_command_executor =
+            SubscriberCommandExecutor::new(FakeSubscriberRepository::new());
+
+        // when
+        let response = execute(
+            State(subscriber_command_executor),
+            Json(request(Uuid::new_v4())),
+        )
+        .await;
+
+        // then
+        assert_eq!(response.unwrap(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn creating_subscriber_with_existing_id_returns_conflict() {
+        // given
+        let subscriber_command_executor =
+            SubscriberCommandExecutor::new(FakeSubscriberRepository::new());
+        let id = Uuid::new_v4();
+        execute(
+            State(subscriber_command_executor.clone()),
+            Json(request(id)),
+        )
+        .await
+        .unwrap();
+
+        // when
+        let response = execute(State(subscriber_command_executor), Json(request(id))).await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::Conflict(_))));
+    }
+}
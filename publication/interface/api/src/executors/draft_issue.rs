@@ -0,0 +1,89 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    DraftIssue,
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = DraftIssueRequest)]
+pub struct Request {
+    title: String,
+    subject: String,
+    #[serde(default)]
+    body: String,
+    author: String,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = DraftIssueResponse)]
+pub struct Response {
+    id: Uuid,
+}
+
+#[utoipa::path(post, path = "/publication/command/draft-issue/execute",
+    request_body = DraftIssueRequest,
+    responses(
+        (status = 201, description = "Issue is drafted", body = DraftIssueResponse),
+        (status = 400, description = "Title or subject of the issue is empty", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Drafting an issue", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<(StatusCode, Json<Response>), ApiError>
+where
+    I: IssueRepository,
+{
+    let id = Uuid::new_v4();
+    let draft_issue = IssueCommand::DraftIssue(DraftIssue::new(
+        id,
+        request.title,
+        request.subject,
+        request.body,
+        request.author,
+    ));
+    issue_command_executor.execute(draft_issue).await?;
+
+    Ok((StatusCode::CREATED, Json(Response { id })))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::FakeIssueRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn drafting_issue_without_subject_returns_bad_request() {
+        // given
+        let issue_command_executor = IssueCommandExecutor::new(FakeIssueRepository::new());
+
+        // when
+        let response = execute(
+            State(issue_command_executor),
+            Json(Request {
+                title: "Weekly #1".to_string(),
+                subject: "".to_string(),
+                body: "".to_string(),
+                author: "arine@example.com".to_string(),
+            }),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::BadRequest(_))));
+    }
+}
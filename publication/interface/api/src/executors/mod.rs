@@ -0,0 +1,12 @@
+pub mod cancel_issue;
+pub mod create_subscriber;
+pub mod draft_issue;
+pub mod publish_issue;
+pub mod rebuild_projection;
+pub mod reconcile_subscribers;
+pub mod return_issue_to_draft;
+pub mod revise_issue;
+pub mod schedule_issue;
+pub mod submit_issue_for_review;
+pub mod update_subscriber;
+pub mod verify_subscriber_email;
@@ -0,0 +1,76 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+    PublishIssue,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = PublishIssueRequest)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[utoipa::path(post, path = "/publication/command/publish-issue/execute",
+    request_body = PublishIssueRequest,
+    responses(
+        (status = 200, description = "Issue is scheduled to be sent as soon as possible"),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue isn't reviewed yet", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Publishing an issue", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let publish_issue = IssueCommand::PublishIssue(PublishIssue::new(request.id));
+    issue_command_executor.execute(publish_issue).await?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        DraftIssue,
+        FakeIssueRepository,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn publishing_issue_not_reviewed_yet_returns_conflict() {
+        // given
+        let issue_command_executor = IssueCommandExecutor::new(FakeIssueRepository::new());
+        let id = Uuid::new_v4();
+        issue_command_executor
+            .execute(IssueCommand::DraftIssue(DraftIssue::new(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, subscribers".to_string(),
+                "arine@example.com".to_string(),
+            )))
+            .await
+            .unwrap();
+
+        // when
+        let response = execute(State(issue_command_executor), Json(Request { id })).await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::Conflict(_))));
+    }
+}
@@ -0,0 +1,59 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::StatusCode;
+
+use domain::prelude::{
+    SubscriberProjection,
+    SubscriberProjectionMaintainer,
+    SubscriberProjectionRebuilder,
+};
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    #[param(value_type = String, example = "subscribers")]
+    projection: SubscriberProjection,
+    #[serde(default = "default_batch_size")]
+    batch_size: u64,
+}
+
+fn default_batch_size() -> u64 {
+    SubscriberProjection::DEFAULT_REBUILD_BATCH_SIZE
+}
+
+// Rebuilding outlives the request, so its progress is inquired separately
+#[utoipa::path(post, path = "/admin/command/rebuild-projection/execute",
+    params(
+        Request,
+        ("Authorization" = String, Header, description = "Admin token as \"Bearer <token>\""),
+    ),
+    responses(
+        (status = 202, description = "Rebuilding projection is started"),
+        (status = 401, description = "Admin token is missing or doesn't match", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Rebuilding projection", skip(subscriber_projection_maintainer))]
+pub async fn execute<P>(
+    State(subscriber_projection_maintainer): State<SubscriberProjectionMaintainer<P>>,
+    Query(request): Query<Request>,
+) -> StatusCode
+where
+    P: SubscriberProjectionRebuilder + 'static,
+{
+    tokio::spawn(async move {
+        let result = subscriber_projection_maintainer
+            .rebuild(request.projection, request.batch_size, |progress| {
+                tracing::info!(?progress, "Rebuilt a batch of projection")
+            })
+            .await;
+
+        match result {
+            Ok(progress) => tracing::info!(?progress, "Rebuilt projection"),
+            Err(error) => tracing::error!("Failed to rebuild projection: {:?}", error),
+        }
+    });
+
+    StatusCode::ACCEPTED
+}
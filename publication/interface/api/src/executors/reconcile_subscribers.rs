@@ -0,0 +1,84 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    SubscriberDriftReport,
+    SubscriberReconciler,
+    SubscriberReconciliationError,
+    SubscriberRepository,
+    SubscriptionGateway,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = SubscriberDriftReportResponse)]
+pub struct Response {
+    dry_run: bool,
+    missing: Vec<Uuid>,
+    outdated: Vec<Uuid>,
+    stale: Vec<Uuid>,
+    failed: Vec<Uuid>,
+}
+
+impl From<SubscriberDriftReport> for Response {
+    fn from(report: SubscriberDriftReport) -> Self {
+        Response {
+            dry_run: report.dry_run,
+            missing: report.missing,
+            outdated: report.outdated,
+            stale: report.stale,
+            failed: report.failed,
+        }
+    }
+}
+
+#[utoipa::path(post, path = "/admin/command/reconcile-subscribers/execute",
+    params(
+        Request,
+        ("Authorization" = String, Header, description = "Admin token as \"Bearer <token>\""),
+    ),
+    responses(
+        (status = 200, description = "Drift between subscription and publication",
+            body = SubscriberDriftReportResponse),
+        (status = 503, description = "Subscription service is unavailable", body = ErrorMessage),
+        (status = 401, description = "Admin token is missing or doesn't match", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Reconciling subscribers", skip(subscriber_reconciler))]
+pub async fn execute<R, G>(
+    State(subscriber_reconciler): State<SubscriberReconciler<R, G>>,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError>
+where
+    R: SubscriberRepository,
+    G: SubscriptionGateway,
+{
+    let report = subscriber_reconciler
+        .reconcile(request.dry_run)
+        .await
+        .map_err(|error| match error {
+            SubscriberReconciliationError::SubscriptionGatewayFailed(_) => {
+                ApiError::Unavailable(error.into())
+            }
+            SubscriberReconciliationError::RepositoryOperationFailed(_) => {
+                ApiError::Unexpected(error.into())
+            }
+        })?;
+    tracing::info!(?report, "Reconciled subscribers");
+
+    Ok(Json(report.into()))
+}
@@ -0,0 +1,46 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+    ReturnIssueToDraft,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = ReturnIssueToDraftRequest)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[utoipa::path(post, path = "/publication/command/return-issue-to-draft/execute",
+    request_body = ReturnIssueToDraftRequest,
+    responses(
+        (status = 200, description = "Issue is returned to draft"),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue isn't in review", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Returning an issue to draft", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let return_issue_to_draft =
+        IssueCommand::ReturnIssueToDraft(ReturnIssueToDraft::new(request.id));
+    issue_command_executor
+        .execute(return_issue_to_draft)
+        .await?;
+
+    Ok(StatusCode::OK)
+}
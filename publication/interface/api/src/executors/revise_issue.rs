@@ -0,0 +1,53 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+    ReviseIssue,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = ReviseIssueRequest)]
+pub struct Request {
+    id: Uuid,
+    title: String,
+    subject: String,
+    #[serde(default)]
+    body: String,
+}
+
+#[utoipa::path(post, path = "/publication/command/revise-issue/execute",
+    request_body = ReviseIssueRequest,
+    responses(
+        (status = 200, description = "Issue is revised"),
+        (status = 400, description = "Title or subject of the issue is empty", body = ErrorMessage),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue isn't a draft any more", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Revising an issue", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let revise_issue = IssueCommand::ReviseIssue(ReviseIssue::new(
+        request.id,
+        request.title,
+        request.subject,
+        request.body,
+    ));
+    issue_command_executor.execute(revise_issue).await?;
+
+    Ok(StatusCode::OK)
+}
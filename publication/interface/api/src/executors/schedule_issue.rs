@@ -0,0 +1,97 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+    ScheduleIssue,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = ScheduleIssueRequest)]
+pub struct Request {
+    id: Uuid,
+    scheduled_at: DateTime<Utc>,
+}
+
+#[utoipa::path(post, path = "/publication/command/schedule-issue/execute",
+    request_body = ScheduleIssueRequest,
+    responses(
+        (status = 200, description = "Issue is scheduled, or rescheduled, to be sent at the time"),
+        (status = 400, description = "Time isn't in the future", body = ErrorMessage),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue isn't reviewed yet or sending has started", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Scheduling an issue", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let schedule_issue =
+        IssueCommand::ScheduleIssue(ScheduleIssue::new(request.id, request.scheduled_at));
+    issue_command_executor.execute(schedule_issue).await?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        DraftIssue,
+        FakeIssueRepository,
+        SubmitIssueForReview,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn scheduling_issue_in_past_returns_bad_request() {
+        // given
+        let issue_command_executor = IssueCommandExecutor::new(FakeIssueRepository::new());
+        let id = Uuid::new_v4();
+        issue_command_executor
+            .execute(IssueCommand::DraftIssue(DraftIssue::new(
+                id,
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, subscribers".to_string(),
+                "arine@example.com".to_string(),
+            )))
+            .await
+            .unwrap();
+        issue_command_executor
+            .execute(IssueCommand::SubmitIssueForReview(
+                SubmitIssueForReview::new(id),
+            ))
+            .await
+            .unwrap();
+
+        // when
+        let response = execute(
+            State(issue_command_executor),
+            Json(Request {
+                id,
+                scheduled_at: Utc::now() - chrono::Duration::minutes(1),
+            }),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::BadRequest(_))));
+    }
+}
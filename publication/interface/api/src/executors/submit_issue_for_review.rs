@@ -0,0 +1,46 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueCommand,
+    IssueCommandExecutor,
+    IssueRepository,
+    SubmitIssueForReview,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = SubmitIssueForReviewRequest)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[utoipa::path(post, path = "/publication/command/submit-issue-for-review/execute",
+    request_body = SubmitIssueForReviewRequest,
+    responses(
+        (status = 200, description = "Issue is submitted for review"),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Issue isn't a draft", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Submitting an issue for review", skip(issue_command_executor))]
+pub async fn execute<I>(
+    State(issue_command_executor): State<IssueCommandExecutor<I>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    I: IssueRepository,
+{
+    let submit_issue_for_review =
+        IssueCommand::SubmitIssueForReview(SubmitIssueForReview::new(request.id));
+    issue_command_executor
+        .execute(submit_issue_for_review)
+        .await?;
+
+    Ok(StatusCode::OK)
+}
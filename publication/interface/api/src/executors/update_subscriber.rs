@@ -0,0 +1,74 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberRepository,
+    UpdateSubscriber,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = UpdateSubscriberRequest)]
+pub struct Request {
+    id: Uuid,
+    name: String,
+}
+
+#[utoipa::path(post, path = "/publication/command/update-subscriber/execute",
+    request_body = UpdateSubscriberRequest,
+    responses(
+        (status = 200, description = "Subscriber is updated"),
+        (status = 404, description = "Subscriber doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Subscriber was modified concurrently", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Updating a subscriber", skip(subscriber_command_executor))]
+pub async fn execute<R>(
+    State(subscriber_command_executor): State<SubscriberCommandExecutor<R>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    R: SubscriberRepository,
+{
+    let update_subscriber =
+        SubscriberCommand::UpdateSubscriber(UpdateSubscriber::new(request.id, request.name));
+    subscriber_command_executor
+        .execute(update_subscriber)
+        .await?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::FakeSubscriberRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn updating_not_existing_subscriber_returns_not_found() {
+        // given
+        let subscriber_command_executor =
+            SubscriberCommandExecutor::new(FakeSubscriberRepository::new());
+
+        // when
+        let response = execute(
+            State(subscriber_command_executor),
+            Json(Request {
+                id: Uuid::new_v4(),
+                name: "Arine".to_string(),
+            }),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::NotFound(_))));
+    }
+}
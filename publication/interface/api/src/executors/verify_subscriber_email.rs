@@ -0,0 +1,103 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberEmailVerifiationStatus,
+    SubscriberRepository,
+    VerifySubscriberEmailAs,
+};
+
+use crate::error::ApiError;
+
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+pub enum EmailVerificationStatus {
+    Unverified,
+    Valid,
+    Invalid,
+}
+
+impl From<EmailVerificationStatus> for SubscriberEmailVerifiationStatus {
+    fn from(status: EmailVerificationStatus) -> Self {
+        match status {
+            EmailVerificationStatus::Unverified => SubscriberEmailVerifiationStatus::Unverified,
+            EmailVerificationStatus::Valid => SubscriberEmailVerifiationStatus::Valid,
+            EmailVerificationStatus::Invalid => SubscriberEmailVerifiationStatus::Invalid,
+        }
+    }
+}
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = VerifySubscriberEmailRequest)]
+pub struct Request {
+    id: Uuid,
+    status: EmailVerificationStatus,
+}
+
+#[utoipa::path(post, path = "/publication/command/verify-subscriber-email/execute",
+    request_body = VerifySubscriberEmailRequest,
+    responses(
+        (status = 200, description = "Email of the subscriber is verified"),
+        (status = 400, description = "Email can't be verified as unverified", body = ErrorMessage),
+        (status = 404, description = "Subscriber doesn't exist", body = ErrorMessage),
+        (status = 409, description = "Subscriber was modified concurrently", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(
+    name = "Verifying email of a subscriber",
+    skip(subscriber_command_executor)
+)]
+pub async fn execute<R>(
+    State(subscriber_command_executor): State<SubscriberCommandExecutor<R>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError>
+where
+    R: SubscriberRepository,
+{
+    let verify_subscriber_email_as = SubscriberCommand::VerifySubscriberEmailAs(
+        VerifySubscriberEmailAs::new(request.id, request.status.into()),
+    );
+    subscriber_command_executor
+        .execute(verify_subscriber_email_as)
+        .await?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        FakeSubscriberRepository,
+        Subscriber,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifying_email_as_unverified_returns_bad_request() {
+        // given
+        let repository = FakeSubscriberRepository::new();
+        let id = Uuid::new_v4();
+        let mut subscriber = Subscriber::default();
+        subscriber.create(id, format!("{}@example.com", id), "Arine".to_string());
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        let response = execute(
+            State(SubscriberCommandExecutor::new(repository)),
+            Json(Request {
+                id,
+                status: EmailVerificationStatus::Unverified,
+            }),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::BadRequest(_))));
+    }
+}
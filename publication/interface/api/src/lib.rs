@@ -2,5 +2,9 @@ mod checkers;
 pub mod container;
 mod document;
 mod error;
+mod executors;
+mod guards;
+mod readers;
+mod receivers;
 mod router;
 pub mod runner;
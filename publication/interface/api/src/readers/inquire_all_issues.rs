@@ -0,0 +1,34 @@
+use axum::extract::State;
+use axum::Json;
+
+use domain::prelude::{
+    InquiryAllIssues,
+    Issue,
+    IssueQuery,
+    IssueQueryReader,
+    IssueRepository,
+};
+
+use crate::error::ApiError;
+use crate::readers::inquire_issue::Response;
+
+#[utoipa::path(get, path = "/publication/query/inquire-all-issues/read",
+    responses(
+        (status = 200, description = "All issues", body = [IssueResponse]),
+    )
+)]
+#[tracing::instrument(name = "Inquiring all issues", skip(issue_query_reader))]
+pub async fn read<I>(
+    State(issue_query_reader): State<IssueQueryReader<I>>,
+) -> Result<Json<Vec<Response>>, ApiError>
+where
+    I: IssueRepository,
+{
+    let inquiry_all_issues = IssueQuery::InquiryAllIssues(InquiryAllIssues::new());
+    let issues: Vec<Issue> = issue_query_reader
+        .read(inquiry_all_issues)
+        .await?
+        .try_into()?;
+
+    Ok(Json(issues.into_iter().map(Response::from).collect()))
+}
@@ -0,0 +1,35 @@
+use axum::extract::State;
+use axum::Json;
+
+use domain::prelude::{
+    InquiryAllSubscribers,
+    Subscriber,
+    SubscriberQuery,
+    SubscriberQueryReader,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+use crate::readers::inquire_subscriber::Response;
+
+#[utoipa::path(get, path = "/publication/query/inquire-all-subscribers/read",
+    responses(
+        (status = 200, description = "All subscribers", body = [SubscriberResponse]),
+    )
+)]
+#[tracing::instrument(name = "Inquiring all subscribers", skip(subscriber_query_reader))]
+pub async fn read<R>(
+    State(subscriber_query_reader): State<SubscriberQueryReader<R>>,
+) -> Result<Json<Vec<Response>>, ApiError>
+where
+    R: SubscriberRepository,
+{
+    let inquiry_all_subscribers =
+        SubscriberQuery::InquiryAllSubscribers(InquiryAllSubscribers::new());
+    let subscribers: Vec<Subscriber> = subscriber_query_reader
+        .read(inquiry_all_subscribers)
+        .await?
+        .try_into()?;
+
+    Ok(Json(subscribers.into_iter().map(Response::from).collect()))
+}
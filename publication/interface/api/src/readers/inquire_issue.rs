@@ -0,0 +1,75 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::Json;
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use domain::prelude::{
+    InquiryIssue,
+    Issue,
+    IssueQuery,
+    IssueQueryReader,
+    IssueRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = IssueResponse)]
+pub struct Response {
+    id: Uuid,
+    title: String,
+    subject: String,
+    body: String,
+    author: String,
+    status: String,
+    scheduled_at: Option<DateTime<Utc>>,
+}
+
+impl From<Issue> for Response {
+    fn from(issue: Issue) -> Self {
+        Response {
+            id: issue.id,
+            title: issue.title,
+            subject: issue.subject,
+            body: issue.body,
+            author: issue.author,
+            status: issue.status.to_string(),
+            scheduled_at: issue.scheduled_at,
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/publication/query/inquire-issue/read",
+    params(Request),
+    responses(
+        (status = 200, description = "Issue of the ID", body = IssueResponse),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Inquiring an issue", skip(issue_query_reader))]
+pub async fn read<I>(
+    State(issue_query_reader): State<IssueQueryReader<I>>,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError>
+where
+    I: IssueRepository,
+{
+    let inquiry_issue = IssueQuery::InquiryIssue(InquiryIssue::new(request.id));
+    let issue: Issue = issue_query_reader.read(inquiry_issue).await?.try_into()?;
+
+    Ok(Json(issue.into()))
+}
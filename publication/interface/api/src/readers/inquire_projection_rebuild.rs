@@ -0,0 +1,121 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::Json;
+
+use domain::prelude::{
+    SubscriberProjection,
+    SubscriberProjectionMaintainer,
+    SubscriberProjectionRebuildProgress,
+    SubscriberProjectionRebuilder,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    #[param(value_type = String, example = "subscribers")]
+    projection: SubscriberProjection,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = ProjectionRebuildResponse)]
+pub struct Response {
+    projection: String,
+    processed_position: i64,
+    last_position: i64,
+    processed_events: i64,
+    completed: bool,
+}
+
+impl From<SubscriberProjectionRebuildProgress> for Response {
+    fn from(progress: SubscriberProjectionRebuildProgress) -> Self {
+        Response {
+            projection: progress.projection.name().to_string(),
+            processed_position: progress.processed_position,
+            last_position: progress.last_position,
+            processed_events: progress.processed_events,
+            completed: progress.completed,
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/admin/query/inquire-projection-rebuild/read",
+    params(
+        Request,
+        ("Authorization" = String, Header, description = "Admin token as \"Bearer <token>\""),
+    ),
+    responses(
+        (status = 200, description = "Progress of the last rebuild", body = ProjectionRebuildResponse),
+        (status = 404, description = "Projection has never been rebuilt", body = ErrorMessage),
+        (status = 401, description = "Admin token is missing or doesn't match", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(
+    name = "Inquiring projection rebuild",
+    skip(subscriber_projection_maintainer)
+)]
+pub async fn read<P>(
+    State(subscriber_projection_maintainer): State<SubscriberProjectionMaintainer<P>>,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError>
+where
+    P: SubscriberProjectionRebuilder,
+{
+    let progress = subscriber_projection_maintainer
+        .inquire_progress(request.projection)
+        .await
+        .map_err(|error| ApiError::Unexpected(error.into()))?
+        .ok_or_else(|| {
+            ApiError::NotFound(anyhow::anyhow!(
+                "Projection {} has never been rebuilt",
+                request.projection.name()
+            ))
+        })?;
+
+    Ok(Json(progress.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::FakeSubscriberProjectionRebuilder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inquiring_projection_rebuild_returns_its_progress() {
+        // given
+        let rebuilder = FakeSubscriberProjectionRebuilder::new();
+        rebuilder.set_last_position(3);
+        rebuilder
+            .rebuild_next_batch(SubscriberProjection::Subscribers, 2)
+            .await
+            .unwrap();
+
+        // when
+        let response = read(
+            State(SubscriberProjectionMaintainer::new(rebuilder)),
+            Query(Request {
+                projection: SubscriberProjection::Subscribers,
+            }),
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert_eq!(
+            response.0,
+            Response {
+                projection: "subscribers".to_string(),
+                processed_position: 2,
+                last_position: 3,
+                processed_events: 2,
+                completed: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    InquirySubscriber,
+    Subscriber,
+    SubscriberEmailVerifiationStatus,
+    SubscriberQuery,
+    SubscriberQueryReader,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = SubscriberResponse)]
+pub struct Response {
+    id: Uuid,
+    email: String,
+    email_verification_status: String,
+    name: String,
+}
+
+impl From<Subscriber> for Response {
+    fn from(subscriber: Subscriber) -> Self {
+        let email_verification_status = match subscriber.email.verification_status {
+            SubscriberEmailVerifiationStatus::Unverified => "Unverified",
+            SubscriberEmailVerifiationStatus::Valid => "Valid",
+            SubscriberEmailVerifiationStatus::Invalid => "Invalid",
+        };
+
+        Response {
+            id: subscriber.id,
+            email: subscriber.email.address,
+            email_verification_status: email_verification_status.to_string(),
+            name: subscriber.name,
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/publication/query/inquire-subscriber/read",
+    params(Request),
+    responses(
+        (status = 200, description = "Subscriber of the ID", body = SubscriberResponse),
+        (status = 404, description = "Subscriber doesn't exist", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Inquiring a subscriber", skip(subscriber_query_reader))]
+pub async fn read<R>(
+    State(subscriber_query_reader): State<SubscriberQueryReader<R>>,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError>
+where
+    R: SubscriberRepository,
+{
+    let inquiry_subscriber = SubscriberQuery::InquirySubscriber(InquirySubscriber::new(request.id));
+    let subscriber: Subscriber = subscriber_query_reader
+        .read(inquiry_subscriber)
+        .await?
+        .try_into()?;
+
+    Ok(Json(subscriber.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::FakeSubscriberRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inquiring_subscriber_returns_it() {
+        // given
+        let repository = FakeSubscriberRepository::new();
+        let id = Uuid::new_v4();
+        let mut subscriber = Subscriber::default();
+        subscriber.create(id, "arine@example.com".to_string(), "Arine".to_string());
+        repository.save(&mut subscriber).await.unwrap();
+
+        // when
+        let response = read(
+            State(SubscriberQueryReader::new(repository)),
+            Query(Request { id }),
+        )
+        .await
+        .unwrap();
+
+        // then
+        assert_eq!(
+            response.0,
+            Response {
+                id,
+                email: "arine@example.com".to_string(),
+                email_verification_status: "Unverified".to_string(),
+                name: "Arine".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn inquiring_not_existing_subscriber_returns_not_found() {
+        // given
+        let subscriber_query_reader = SubscriberQueryReader::new(FakeSubscriberRepository::new());
+
+        // when
+        let response = read(
+            State(subscriber_query_reader),
+            Query(Request { id: Uuid::new_v4() }),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::NotFound(_))));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod inquire_all_issues;
+pub mod inquire_all_subscribers;
+pub mod inquire_issue;
+pub mod inquire_projection_rebuild;
+pub mod inquire_subscriber;
+pub mod preview_issue;
@@ -0,0 +1,106 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    IssueRenderer,
+    IssueRepository,
+    RenderedIssue,
+    RenderedIssueCache,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    id: Uuid,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "PascalCase")]
+#[schema(as = IssuePreviewResponse)]
+pub struct Response {
+    id: Uuid,
+    version: i64,
+    subject: String,
+    html: String,
+    text: String,
+}
+
+impl From<RenderedIssue> for Response {
+    fn from(rendered_issue: RenderedIssue) -> Self {
+        Response {
+            id: rendered_issue.issue_id,
+            version: rendered_issue.version,
+            subject: rendered_issue.subject,
+            html: rendered_issue.html,
+            text: rendered_issue.text,
+        }
+    }
+}
+
+#[utoipa::path(get, path = "/publication/query/preview-issue/read",
+    params(Request),
+    responses(
+        (status = 200, description = "Issue rendered as subscribers receive it", body = IssuePreviewResponse),
+        (status = 404, description = "Issue doesn't exist", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(name = "Previewing an issue", skip(issue_renderer))]
+pub async fn read<I, C>(
+    State(issue_renderer): State<IssueRenderer<I, C>>,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError>
+where
+    I: IssueRepository,
+    C: RenderedIssueCache,
+{
+    let rendered_issue = issue_renderer.render(request.id).await?;
+
+    Ok(Json(rendered_issue.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        FakeIssueRepository,
+        FakeRenderedIssueCache,
+        Issue,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn previewing_issue_returns_sanitized_html_and_text() {
+        // given
+        let issue_repository = FakeIssueRepository::new();
+        let mut issue = Issue::default();
+        issue
+            .draft(
+                Uuid::new_v4(),
+                "Weekly #1".to_string(),
+                "What's new this week".to_string(),
+                "Hello, **subscribers**\n\n<script>alert(1)</script>".to_string(),
+                "arine@example.com".to_string(),
+            )
+            .unwrap();
+        issue_repository.save(&mut issue).await.unwrap();
+        let issue_renderer = IssueRenderer::new(issue_repository, FakeRenderedIssueCache::new());
+
+        // when
+        let response = read(State(issue_renderer), Query(Request { id: issue.id }))
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(response.subject, "What's new this week");
+        assert!(response.html.contains("<strong"));
+        assert!(!response.html.contains("<script"));
+        assert_eq!(response.text, "Hello, subscribers\n");
+    }
+}
@@ -0,0 +1,192 @@
+use axum::body::Bytes;
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::{
+    HeaderMap,
+    StatusCode,
+};
+
+use domain::prelude::{
+    DeliveryRepository,
+    MailFeedbackHandler,
+    MailFeedbackRepository,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+use crate::receivers::providers::MailProvider;
+use crate::receivers::signature::WebhookSignature;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug, utoipa::IntoParams)]
+pub struct Request {
+    provider: MailProvider,
+}
+
+#[utoipa::path(post, path = "/publication/webhook/mail-feedback/receive",
+    params(
+        Request,
+        ("X-Webhook-Signature" = String, Header,
+            description = "HMAC-SHA256 of the payload by the shared secret in hex"),
+    ),
+    request_body(content = String, content_type = "application/json",
+        description = "Bounce or complaint notification in the payload shape of the provider"),
+    responses(
+        (status = 200, description = "Notification is handled, or ignored if it isn't about a subscriber"),
+        (status = 400, description = "Payload isn't in the shape of the provider", body = ErrorMessage),
+        (status = 401, description = "Signature is missing or doesn't match the payload", body = ErrorMessage),
+    )
+)]
+#[tracing::instrument(
+    name = "Receiving mail feedback",
+    skip(mail_feedback_handler, webhook_signature, headers, payload)
+)]
+pub async fn receive<R, D, F>(
+    State(mail_feedback_handler): State<MailFeedbackHandler<R, D, F>>,
+    State(webhook_signature): State<WebhookSignature>,
+    Query(request): Query<Request>,
+    headers: HeaderMap,
+    payload: Bytes,
+) -> Result<StatusCode, ApiError>
+where
+    R: SubscriberRepository,
+    D: DeliveryRepository,
+    F: MailFeedbackRepository,
+{
+    let signature = headers
+        .get(WebhookSignature::HEADER)
+        .and_then(|signature| signature.to_str().ok())
+        .unwrap_or_default();
+    if !webhook_signature.verify(&payload, signature) {
+        return Err(ApiError::Unauthorized(anyhow::anyhow!(
+            "Webhook signature doesn't match the payload"
+        )));
+    }
+
+    let feedbacks = request
+        .provider
+        .parse(&payload)
+        .map_err(ApiError::BadRequest)?;
+    for feedback in feedbacks {
+        let outcome = mail_feedback_handler.handle(&feedback).await?;
+        tracing::info!(id = %feedback.id, kind = ?feedback.kind, ?outcome, "Handled mail feedback");
+    }
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        Delivery,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+        FakeSubscriberRepository,
+        MailFeedbackOptions,
+        Subscriber,
+        SubscriberEmailVerifiationStatus,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    type Handler = MailFeedbackHandler<
+        FakeSubscriberRepository,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+    >;
+
+    const HARD_BOUNCE: &[u8] = br#"{"RecordType": "Bounce", "ID": 42, "Type": "HardBounce",
+        "Email": "arine@example.com", "BouncedAt": "2024-06-22T07:34:18Z"}"#;
+
+    async fn prepare() -> (Handler, FakeSubscriberRepository, Uuid) {
+        let subscriber_repository = FakeSubscriberRepository::new();
+        let delivery_repository = FakeDeliveryRepository::new();
+        let id = Uuid::new_v4();
+        let mut subscriber = Subscriber::default();
+        subscriber.create(id, "arine@example.com".to_string(), "Arine".to_string());
+        subscriber_repository.save(&mut subscriber).await.unwrap();
+        delivery_repository
+            .enqueue(&[Delivery::new(
+                Uuid::new_v4(),
+                id,
+                "arine@example.com".to_string(),
+            )])
+            .await
+            .unwrap();
+
+        let handler = MailFeedbackHandler::new(
+            subscriber_repository.clone(),
+            delivery_repository,
+            FakeMailFeedbackRepository::new(),
+            MailFeedbackOptions {
+                soft_bounce_threshold: 3,
+                soft_bounce_window: chrono::Duration::days(7),
+            },
+        );
+        (handler, subscriber_repository, id)
+    }
+
+    fn signed_headers(signature: String) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(WebhookSignature::HEADER, signature.parse().unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn signed_hard_bounce_makes_email_of_subscriber_invalid() {
+        // given
+        let (handler, subscriber_repository, id) = prepare().await;
+        let webhook_signature = WebhookSignature::new("welcome".to_string());
+        let headers = signed_headers(webhook_signature.sign(HARD_BOUNCE));
+
+        // when
+        let response = receive(
+            State(handler),
+            State(webhook_signature),
+            Query(Request {
+                provider: MailProvider::Postmark,
+            }),
+            headers,
+            Bytes::from_static(HARD_BOUNCE),
+        )
+        .await;
+
+        // then
+        assert_eq!(response.unwrap(), StatusCode::OK);
+        let subscriber = subscriber_repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(
+            subscriber.email.verification_status,
+            SubscriberEmailVerifiationStatus::Invalid
+        );
+    }
+
+    #[tokio::test]
+    async fn notification_signed_by_other_secret_returns_unauthorized() {
+        // given
+        let (handler, subscriber_repository, id) = prepare().await;
+        let headers = signed_headers(WebhookSignature::new("guess".to_string()).sign(HARD_BOUNCE));
+
+        // when
+        let response = receive(
+            State(handler),
+            State(WebhookSignature::new("welcome".to_string())),
+            Query(Request {
+                provider: MailProvider::Postmark,
+            }),
+            headers,
+            Bytes::from_static(HARD_BOUNCE),
+        )
+        .await;
+
+        // then
+        assert!(matches!(response, Err(ApiError::Unauthorized(_))));
+        let subscriber = subscriber_repository.find_by_id(id).await.unwrap().unwrap();
+        assert_eq!(
+            subscriber.email.verification_status,
+            SubscriberEmailVerifiationStatus::Unverified
+        );
+    }
+}
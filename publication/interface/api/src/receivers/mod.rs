@@ -0,0 +1,3 @@
+pub mod mail_feedback;
+pub mod providers;
+pub mod signature;
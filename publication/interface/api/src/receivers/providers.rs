@@ -0,0 +1,211 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+
+use domain::prelude::{
+    MailFeedback,
+    MailFeedbackKind,
+};
+
+// Mail providers whose bounce and complaint notifications are understood
+#[derive(serde::Deserialize, Clone, Copy, Debug, utoipa::ToSchema)]
+pub enum MailProvider {
+    Postmark,
+    // notification content published by SES, e.g. by raw message delivery of an SNS topic
+    AmazonSes,
+}
+
+impl MailProvider {
+    // Normalize a notification into feedback per recipient, notifications which are neither
+    // bounces nor complaints give none
+    pub fn parse(&self, payload: &[u8]) -> Result<Vec<MailFeedback>, anyhow::Error> {
+        match self {
+            MailProvider::Postmark => Ok(serde_json::from_slice::<PostmarkNotification>(payload)?
+                .into_feedback()
+                .into_iter()
+                .collect()),
+            MailProvider::AmazonSes => {
+                Ok(serde_json::from_slice::<SesNotification>(payload)?.into_feedbacks())
+            }
+        }
+    }
+}
+
+// Refer to https://postmarkapp.com/developer/webhooks/bounce-webhook
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct PostmarkNotification {
+    record_type: String,
+    #[serde(rename = "ID")]
+    id: i64,
+    #[serde(rename = "Type")]
+    kind: String,
+    email: String,
+    description: Option<String>,
+    bounced_at: DateTime<Utc>,
+}
+
+impl PostmarkNotification {
+    fn into_feedback(self) -> Option<MailFeedback> {
+        let kind = match (self.record_type.as_str(), self.kind.as_str()) {
+            ("SpamComplaint", _) | ("Bounce", "SpamComplaint") => MailFeedbackKind::Complaint,
+            ("Bounce", "HardBounce" | "BadEmailAddress") => MailFeedbackKind::HardBounce,
+            ("Bounce", "SoftBounce" | "Transient" | "DnsError") => MailFeedbackKind::SoftBounce,
+            // e.g. auto responders and deliveries
+            _ => return None,
+        };
+
+        Some(MailFeedback::new(
+            format!("postmark:{}", self.id),
+            kind,
+            self.email,
+            self.description,
+            self.bounced_at,
+        ))
+    }
+}
+
+// Refer to https://docs.aws.amazon.com/ses/latest/dg/notification-contents.html
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SesNotification {
+    notification_type: String,
+    bounce: Option<SesBounce>,
+    complaint: Option<SesComplaint>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SesBounce {
+    bounce_type: String,
+    bounced_recipients: Vec<SesRecipient>,
+    timestamp: DateTime<Utc>,
+    feedback_id: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SesComplaint {
+    complained_recipients: Vec<SesRecipient>,
+    timestamp: DateTime<Utc>,
+    feedback_id: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SesRecipient {
+    email_address: String,
+    diagnostic_code: Option<String>,
+}
+
+impl SesNotification {
+    fn into_feedbacks(self) -> Vec<MailFeedback> {
+        let (kind, recipients, timestamp, feedback_id) =
+            match (self.notification_type.as_str(), self.bounce, self.complaint) {
+                ("Bounce", Some(bounce), _) => (
+                    // undetermined bounces are counted as soft ones rather than trusted
+                    if bounce.bounce_type == "Permanent" {
+                        MailFeedbackKind::HardBounce
+                    } else {
+                        MailFeedbackKind::SoftBounce
+                    },
+                    bounce.bounced_recipients,
+                    bounce.timestamp,
+                    bounce.feedback_id,
+                ),
+                ("Complaint", _, Some(complaint)) => (
+                    MailFeedbackKind::Complaint,
+                    complaint.complained_recipients,
+                    complaint.timestamp,
+                    complaint.feedback_id,
+                ),
+                // e.g. deliveries
+                _ => return Vec::new(),
+            };
+
+        // a notification lists every recipient of the mail, so the feedback ID is shared
+        recipients
+            .into_iter()
+            .map(|recipient| {
+                MailFeedback::new(
+                    format!("ses:{}:{}", feedback_id, recipient.email_address),
+                    kind,
+                    recipient.email_address,
+                    recipient.diagnostic_code,
+                    timestamp,
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn postmark_bounces_and_complaints_are_normalized() {
+        // given
+        let hard_bounce = br#"{"RecordType": "Bounce", "ID": 42, "Type": "HardBounce",
+            "TypeCode": 1, "Email": "arine@example.com", "Description": "Unknown user",
+            "BouncedAt": "2024-06-22T07:34:18Z"}"#;
+        let complaint = br#"{"RecordType": "SpamComplaint", "ID": 43, "Type": "SpamComplaint",
+            "Email": "arine@example.com", "BouncedAt": "2024-06-22T07:34:18Z"}"#;
+        let auto_responder = br#"{"RecordType": "Bounce", "ID": 44, "Type": "AutoResponder",
+            "Email": "arine@example.com", "BouncedAt": "2024-06-22T07:34:18Z"}"#;
+
+        // when
+        let hard_bounce = MailProvider::Postmark.parse(hard_bounce).unwrap();
+        let complaint = MailProvider::Postmark.parse(complaint).unwrap();
+        let auto_responder = MailProvider::Postmark.parse(auto_responder).unwrap();
+
+        // then
+        assert_eq!(
+            hard_bounce,
+            vec![MailFeedback::new(
+                "postmark:42".to_string(),
+                MailFeedbackKind::HardBounce,
+                "arine@example.com".to_string(),
+                Some("Unknown user".to_string()),
+                "2024-06-22T07:34:18Z".parse().unwrap(),
+            )]
+        );
+        assert_eq!(complaint[0].kind, MailFeedbackKind::Complaint);
+        assert!(auto_responder.is_empty());
+    }
+
+    #[test]
+    fn amazon_ses_bounce_is_normalized_per_recipient() {
+        // given
+        let payload = br#"{"notificationType": "Bounce", "bounce": {"bounceType": "Transient",
+            "bounceSubType": "MailboxFull", "feedbackId": "0100abc",
+            "timestamp": "2024-06-22T07:34:18.000Z",
+            "bouncedRecipients": [{"emailAddress": "arine@example.com"},
+                {"emailAddress": "you@example.com", "diagnosticCode": "552 Mailbox full"}]},
+            "mail": {"messageId": "0100def"}}"#;
+
+        // when
+        let feedbacks = MailProvider::AmazonSes.parse(payload).unwrap();
+
+        // then
+        assert_eq!(feedbacks.len(), 2);
+        assert!(feedbacks
+            .iter()
+            .all(|feedback| feedback.kind == MailFeedbackKind::SoftBounce));
+        assert_eq!(feedbacks[1].id, "ses:0100abc:you@example.com");
+        assert_eq!(
+            feedbacks[1].description.as_deref(),
+            Some("552 Mailbox full")
+        );
+    }
+
+    #[test]
+    fn payload_not_in_shape_of_provider_fails() {
+        // when
+        let response = MailProvider::Postmark.parse(br#"{"notificationType": "Bounce"}"#);
+
+        // then
+        assert!(response.is_err());
+    }
+}
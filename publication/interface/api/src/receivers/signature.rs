@@ -0,0 +1,77 @@
+use hmac::{
+    Hmac,
+    Mac,
+};
+use sha2::Sha256;
+
+// Webhook payloads are signed by HMAC-SHA256 with the secret shared with the mail provider,
+// and the signature is sent in hex, optionally prefixed by the algorithm as "sha256="
+#[derive(Clone)]
+pub struct WebhookSignature {
+    secret: Vec<u8>,
+}
+
+impl WebhookSignature {
+    pub const HEADER: &'static str = "X-Webhook-Signature";
+
+    pub fn new(secret: String) -> Self {
+        Self {
+            secret: secret.into_bytes(),
+        }
+    }
+
+    pub fn verify(&self, payload: &[u8], signature: &str) -> bool {
+        // nothing is signed by an empty secret, so a missing configuration doesn't let anyone in
+        if self.secret.is_empty() {
+            return false;
+        }
+        let signature = match hex::decode(signature.trim().trim_start_matches("sha256=")) {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+
+        // compared in constant time, so the signature can't be guessed byte by byte
+        self.mac(payload).verify_slice(&signature).is_ok()
+    }
+
+    #[cfg(test)]
+    pub fn sign(&self, payload: &[u8]) -> String {
+        format!(
+            "sha256={}",
+            hex::encode(self.mac(payload).finalize().into_bytes())
+        )
+    }
+
+    fn mac(&self, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC takes a key of any size");
+        mac.update(payload);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_signature_of_same_payload_by_same_secret_is_verified() {
+        // given
+        let webhook_signature = WebhookSignature::new("welcome".to_string());
+        let signature = webhook_signature.sign(b"{}");
+
+        // when
+        let verified = webhook_signature.verify(b"{}", &signature);
+        let tampered = webhook_signature.verify(b"{ }", &signature);
+        let forged = webhook_signature.verify(
+            b"{}",
+            &WebhookSignature::new("guess".to_string()).sign(b"{}"),
+        );
+
+        // then
+        assert!(verified);
+        assert!(!tampered);
+        assert!(!forged);
+        assert!(!webhook_signature.verify(b"{}", "not hex"));
+    }
+}
@@ -1,29 +1,137 @@
 use axum::extract::MatchedPath;
 use axum::http::Request;
-use axum::routing::get;
-use axum::Router;
+use axum::routing::{
+    get,
+    post,
+};
+use axum::{
+    middleware,
+    Router,
+};
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 use uuid::Uuid;
 
-use domain::prelude::SubscriberRepository;
+use domain::prelude::{
+    DeliveryRepository,
+    IssueRepository,
+    MailFeedbackRepository,
+    RenderedIssueCache,
+    SubscriberProjectionRebuilder,
+    SubscriberRepository,
+    SubscriptionGateway,
+};
 
 use crate::container::Container;
 use crate::{
     checkers,
     document,
+    executors,
+    guards,
+    readers,
+    receivers,
 };
 
-pub async fn get_router<R>(container: Container<R>) -> Router
+pub async fn get_router<R, G, P, I, C, D, F>(container: Container<R, G, P, I, C, D, F>) -> Router
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
+    // admin routes change or expose every subscriber, so only operators holding the token call them
+    let admin_router = Router::new()
+        .route(
+            "/admin/command/reconcile-subscribers/execute",
+            post(executors::reconcile_subscribers::execute),
+        )
+        .route(
+            "/admin/command/rebuild-projection/execute",
+            post(executors::rebuild_projection::execute),
+        )
+        .route(
+            "/admin/query/inquire-projection-rebuild/read",
+            get(readers::inquire_projection_rebuild::read),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            container.clone(),
+            guards::admin_token::authorize,
+        ));
+
     Router::new()
         .merge(SwaggerUi::new("/swagger-ui").url(
             "/api-docs/openapi.json",
             document::OpenApiDocument::openapi(),
         ))
+        .route(
+            "/publication/command/create-subscriber/execute",
+            post(executors::create_subscriber::execute),
+        )
+        .route(
+            "/publication/command/update-subscriber/execute",
+            post(executors::update_subscriber::execute),
+        )
+        .route(
+            "/publication/command/verify-subscriber-email/execute",
+            post(executors::verify_subscriber_email::execute),
+        )
+        .route(
+            "/publication/query/inquire-subscriber/read",
+            get(readers::inquire_subscriber::read),
+        )
+        .route(
+            "/publication/query/inquire-all-subscribers/read",
+            get(readers::inquire_all_subscribers::read),
+        )
+        .route(
+            "/publication/command/draft-issue/execute",
+            post(executors::draft_issue::execute),
+        )
+        .route(
+            "/publication/command/revise-issue/execute",
+            post(executors::revise_issue::execute),
+        )
+        .route(
+            "/publication/command/submit-issue-for-review/execute",
+            post(executors::submit_issue_for_review::execute),
+        )
+        .route(
+            "/publication/command/return-issue-to-draft/execute",
+            post(executors::return_issue_to_draft::execute),
+        )
+        .route(
+            "/publication/command/publish-issue/execute",
+            post(executors::publish_issue::execute),
+        )
+        .route(
+            "/publication/command/schedule-issue/execute",
+            post(executors::schedule_issue::execute),
+        )
+        .route(
+            "/publication/command/cancel-issue/execute",
+            post(executors::cancel_issue::execute),
+        )
+        .route(
+            "/publication/query/inquire-issue/read",
+            get(readers::inquire_issue::read),
+        )
+        .route(
+            "/publication/query/inquire-all-issues/read",
+            get(readers::inquire_all_issues::read),
+        )
+        .route(
+            "/publication/query/preview-issue/read",
+            get(readers::preview_issue::read),
+        )
+        .route(
+            "/publication/webhook/mail-feedback/receive",
+            post(receivers::mail_feedback::receive),
+        )
+        .merge(admin_router)
         .route("/checkers/readiness", get(checkers::readiness::handle))
         .with_state(container)
         .route("/checkers/liveness", get(checkers::liveness::handle))
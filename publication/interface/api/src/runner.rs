@@ -1,13 +1,29 @@
 use tokio::net::TcpListener;
 
-use domain::prelude::SubscriberRepository;
+use domain::prelude::{
+    DeliveryRepository,
+    IssueRepository,
+    MailFeedbackRepository,
+    RenderedIssueCache,
+    SubscriberProjectionRebuilder,
+    SubscriberRepository,
+    SubscriptionGateway,
+};
 
 use crate::container::Container;
 use crate::router;
 
-pub async fn run<R>(listener: TcpListener, container: Container<R>)
-where
+pub async fn run<R, G, P, I, C, D, F>(
+    listener: TcpListener,
+    container: Container<R, G, P, I, C, D, F>,
+) where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
+    G: SubscriptionGateway + Clone + Send + Sync + 'static,
+    P: SubscriberProjectionRebuilder + Clone + Send + Sync + 'static,
+    I: IssueRepository + Clone + Send + Sync + 'static,
+    C: RenderedIssueCache + Clone + Send + Sync + 'static,
+    D: DeliveryRepository + Clone + Send + Sync + 'static,
+    F: MailFeedbackRepository + Clone + Send + Sync + 'static,
 {
     let app = router::get_router(container).await;
 
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::error::QueueError;
+
+#[derive(Clone, Debug)]
+pub struct Message {
+    pub id: Uuid,
+    pub payload: serde_json::Value,
+    // number of deliveries including the current one
+    pub attempts: u32,
+}
+
+// Messages are delivered at least once: a received message is hidden from other consumers
+// until it is settled, and delivered again if it isn't settled in time
+#[async_trait::async_trait]
+pub trait MessageBroker: Send + Sync + 'static {
+    // Receive up to the given number of messages in the order they were published
+    // A message isn't received while an earlier one with the same ordering key is unsettled, so
+    // that messages of the same key are handled one at a time in order, even when retried
+    async fn receive(&self, max_messages: u64) -> Result<Vec<Message>, QueueError>;
+    async fn acknowledge(&self, id: Uuid) -> Result<(), QueueError>;
+    // Deliver the message again after the delay
+    async fn retry(&self, id: Uuid, delay: Duration, reason: &str) -> Result<(), QueueError>;
+    // Never deliver the message again, but keep it for inspection
+    async fn dead_letter(&self, id: Uuid, reason: &str) -> Result<(), QueueError>;
+}
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+
+use domain::prelude::backoff_delay;
+
+use crate::broker::{
+    Message,
+    MessageBroker,
+};
+use crate::error::QueueError;
+use crate::handler::MessageHandler;
+
+#[derive(Clone, Debug)]
+pub struct ConsumerOptions {
+    // maximum number of messages handled at the same time
+    pub concurrency: usize,
+    // wait between receiving when the queue is empty
+    pub poll_interval: Duration,
+    // deliveries of a failing message before it is dead-lettered
+    pub max_attempts: u32,
+    // delay before the first retry, doubled on every later retry
+    pub retry_backoff: Duration,
+}
+
+pub struct Consumer<B, H>
+where
+    B: MessageBroker,
+    H: MessageHandler,
+{
+    broker: Arc<B>,
+    handler: Arc<H>,
+    options: ConsumerOptions,
+}
+
+impl<B, H> Consumer<B, H>
+where
+    B: MessageBroker,
+    H: MessageHandler,
+{
+    pub fn new(broker: B, handler: H, options: ConsumerOptions) -> Self {
+        Self {
+            broker: Arc::new(broker),
+            handler: Arc::new(handler),
+            options,
+        }
+    }
+
+    // Consume messages until the application stops, failures are reported and retried next time
+    pub async fn run(&self) {
+        loop {
+            match self.consume().await {
+                Ok(0) => tokio::time::sleep(self.options.poll_interval).await,
+                Ok(_) => {}
+                Err(error) => {
+                    tracing::error!("Failed to receive messages: {:?}", error);
+                    tokio::time::sleep(self.options.poll_interval).await;
+                }
+            }
+        }
+    }
+
+    // Receive and handle a batch of messages concurrently, returns the number of received messages
+    // The broker holds back messages of the same ordering key, e.g. events of the same subscriber,
+    // until the earlier one is settled, so a batch never has two of them to reorder
+    pub async fn consume(&self) -> Result<usize, QueueError> {
+        let messages = self.broker.receive(self.options.concurrency as u64).await?;
+        let received = messages.len();
+
+        let mut tasks = JoinSet::new();
+        for message in messages {
+            let broker = self.broker.clone();
+            let handler = self.handler.clone();
+            let options = self.options.clone();
+
+            tasks.spawn(async move {
+                let result = handler.handle(&message).await;
+                Self::settle(broker.as_ref(), &options, &message, result).await;
+            });
+        }
+        while let Some(task) = tasks.join_next().await {
+            if let Err(error) = task {
+                tracing::error!("Message handling task failed: {:?}", error);
+            }
+        }
+
+        Ok(received)
+    }
+
+    // Acknowledge only handled messages, messages left unsettled by failures are delivered again
+    async fn settle(
+        broker: &B,
+        options: &ConsumerOptions,
+        message: &Message,
+        result: Result<(), QueueError>,
+    ) {
+        let settled = match result {
+            Ok(()) => broker.acknowledge(message.id).await,
+            Err(error @ QueueError::UnprocessableMessage(_)) => {
+                tracing::error!("Dead-lettering message (ID: {}): {:?}", message.id, error);
+                broker
+                    .dead_letter(message.id, &format!("{:?}", error))
+                    .await
+            }
+            Err(error) if message.attempts >= options.max_attempts => {
+                tracing::error!(
+                    "Dead-lettering message (ID: {}) after {} attempts: {:?}",
+                    message.id,
+                    message.attempts,
+                    error
+                );
+                broker
+                    .dead_letter(message.id, &format!("{:?}", error))
+                    .await
+            }
+            Err(error) => {
+                let delay = backoff_delay(options.retry_backoff, message.attempts);
+                tracing::warn!(
+                    "Retrying message (ID: {}) in {:?}: {:?}",
+                    message.id,
+                    delay,
+                    error
+                );
+                broker
+                    .retry(message.id, delay, &format!("{:?}", error))
+                    .await
+            }
+        };
+
+        if let Err(error) = settled {
+            tracing::error!("Failed to settle message (ID: {}): {:?}", message.id, error);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::sync::Mutex;
+
+    use uuid::Uuid;
+
+    use crate::in_memory_broker::InMemoryMessageBroker;
+
+    use super::*;
+
+    // fails on messages with the "Fail" field, as an unprocessable message if it is "Poison" and
+    // only on the first attempt if it is "Once"
+    #[derive(Default)]
+    struct TestHandler {
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+        handled: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait::async_trait]
+    impl MessageHandler for Arc<TestHandler> {
+        async fn handle(&self, message: &Message) -> Result<(), QueueError> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            match message.payload["Fail"].as_str() {
+                Some("Poison") => Err(QueueError::UnprocessableMessage(anyhow::anyhow!("Poison"))),
+                Some("Once") if message.attempts > 1 => {
+                    self.handled.lock().unwrap().push(message.id);
+                    Ok(())
+                }
+                Some(_) => Err(QueueError::HandlingFailed(anyhow::anyhow!("Failure"))),
+                None => {
+                    self.handled.lock().unwrap().push(message.id);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    fn get_consumer(
+        concurrency: usize,
+    ) -> (
+        Consumer<InMemoryMessageBroker, Arc<TestHandler>>,
+        InMemoryMessageBroker,
+        Arc<TestHandler>,
+    ) {
+        let broker = InMemoryMessageBroker::new(Duration::from_secs(60));
+        let handler = Arc::new(TestHandler::default());
+        let options = ConsumerOptions {
+            concurrency,
+            poll_interval: Duration::from_millis(10),
+            max_attempts: 2,
+            retry_backoff: Duration::ZERO,
+        };
+
+        (
+            Consumer::new(broker.clone(), handler.clone(), options),
+            broker,
+            handler,
+        )
+    }
+
+    #[tokio::test]
+    async fn consuming_acknowledges_handled_messages() {
+        // given
+        let (consumer, broker, _) = get_consumer(2);
+        let id = broker.publish(serde_json::json!({}));
+
+        // when
+        let received = consumer.consume().await.unwrap();
+
+        // then
+        assert_eq!(received, 1);
+        assert_eq!(broker.acknowledged(), vec![id]);
+    }
+
+    #[tokio::test]
+    async fn consuming_handles_messages_concurrently_up_to_the_limit() {
+        // given
+        let (consumer, broker, handler) = get_consumer(3);
+        for _ in 0..5 {
+            broker.publish(serde_json::json!({}));
+        }
+
+        // when
+        let received = consumer.consume().await.unwrap();
+
+        // then
+        assert_eq!(received, 3);
+        assert_eq!(handler.max_running.load(Ordering::SeqCst), 3);
+        assert_eq!(broker.acknowledged().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn failing_message_is_retried_and_dead_lettered_after_max_attempts() {
+        // given
+        let (consumer, broker, _) = get_consumer(1);
+        let id = broker.publish(serde_json::json!({ "Fail": "Failure" }));
+
+        // when
+        consumer.consume().await.unwrap();
+        let dead_lettered_after_first_attempt = broker.dead_lettered();
+        consumer.consume().await.unwrap();
+
+        // then
+        assert!(dead_lettered_after_first_attempt.is_empty());
+        assert_eq!(broker.dead_lettered().len(), 1);
+        assert_eq!(broker.dead_lettered()[0].0, id);
+        assert!(broker.acknowledged().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unprocessable_message_is_dead_lettered_without_retrying() {
+        // given
+        let (consumer, broker, _) = get_consumer(2);
+        let poison = broker.publish(serde_json::json!({ "Fail": "Poison" }));
+        let healthy = broker.publish(serde_json::json!({}));
+
+        // when
+        consumer.consume().await.unwrap();
+
+        // then
+        assert_eq!(broker.dead_lettered()[0].0, poison);
+        assert_eq!(broker.acknowledged(), vec![healthy]);
+        assert_eq!(consumer.consume().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn messages_of_same_ordering_key_are_handled_in_order_even_when_retried() {
+        // given
+        let (consumer, broker, handler) = get_consumer(2);
+        let confirmed = broker
+            .publish_with_ordering_key(Some("subscriber"), serde_json::json!({ "Fail": "Once" }));
+        let unsubscribed =
+            broker.publish_with_ordering_key(Some("subscriber"), serde_json::json!({}));
+
+        // when
+        let received_first = consumer.consume().await.unwrap();
+        while consumer.consume().await.unwrap() > 0 {}
+
+        // then
+        assert_eq!(received_first, 1);
+        assert_eq!(
+            *handler.handled.lock().unwrap(),
+            vec![confirmed, unsubscribed]
+        );
+        assert_eq!(broker.acknowledged(), vec![confirmed, unsubscribed]);
+    }
+}
@@ -0,0 +1,12 @@
+#[derive(thiserror::Error, Debug)]
+pub enum QueueError {
+    #[error("Failed to operate on message broker")]
+    BrokerOperationFailed(#[source] anyhow::Error),
+
+    // retrying never helps, e.g. malformed message or message of unsupported schema version
+    #[error("Message can't be processed")]
+    UnprocessableMessage(#[source] anyhow::Error),
+
+    #[error("Failed to handle message")]
+    HandlingFailed(#[source] anyhow::Error),
+}
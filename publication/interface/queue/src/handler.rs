@@ -0,0 +1,528 @@
+use anyhow::anyhow;
+use uuid::Uuid;
+
+use domain::prelude::{
+    CreateSubscriber,
+    DeliveryRepository,
+    MailFeedbackRepository,
+    RemoveSubscriber,
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberError,
+    SubscriberRepository,
+    UpdateSubscriber,
+};
+
+use crate::broker::Message;
+use crate::error::QueueError;
+
+#[async_trait::async_trait]
+pub trait MessageHandler: Send + Sync + 'static {
+    async fn handle(&self, message: &Message) -> Result<(), QueueError>;
+}
+
+// Subscriber event published by subscription, the data is decoded by its type and schema version
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SubscriberEventEnvelope {
+    id: Uuid,
+    #[serde(rename = "Type")]
+    event_type: String,
+    schema_version: u32,
+    data: serde_json::Value,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SubscriberConfirmedV2 {
+    subscriber_id: Uuid,
+    email: String,
+    name: String,
+}
+
+// Content format of the profile isn't used since issues are sent in both formats
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SubscriberProfileUpdatedV1 {
+    subscriber_id: Uuid,
+    name: String,
+}
+
+// Shape of the events which carry the ID of the subscriber only
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SubscriberIdentifiedV1 {
+    subscriber_id: Uuid,
+}
+
+#[derive(Clone)]
+pub struct SubscriberEventHandler<R, D, F>
+where
+    R: SubscriberRepository + 'static,
+    D: DeliveryRepository + 'static,
+    F: MailFeedbackRepository + 'static,
+{
+    subscriber_command_executor: SubscriberCommandExecutor<R>,
+    delivery_repository: D,
+    feedback_repository: F,
+}
+
+impl<R, D, F> SubscriberEventHandler<R, D, F>
+where
+    R: SubscriberRepository + 'static,
+    D: DeliveryRepository + 'static,
+    F: MailFeedbackRepository + 'static,
+{
+    pub fn new(
+        subscriber_command_executor: SubscriberCommandExecutor<R>,
+        delivery_repository: D,
+        feedback_repository: F,
+    ) -> Self {
+        Self {
+            subscriber_command_executor,
+            delivery_repository,
+            feedback_repository,
+        }
+    }
+
+    // Confirmed event is delivered at least once, and sent again when a subscriber subscribes
+    // again, so an existing subscriber is updated instead
+    async fn handle_subscriber_confirmed(
+        &self,
+        event: SubscriberConfirmedV2,
+    ) -> Result<(), QueueError> {
+        let create_subscriber = SubscriberCommand::CreateSubscriber(CreateSubscriber::new(
+            event.subscriber_id,
+            event.email,
+            event.name.clone(),
+        ));
+
+        match self
+            .subscriber_command_executor
+            .execute(create_subscriber)
+            .await
+        {
+            Err(SubscriberError::SubscriberAlreadyExists(id)) => {
+                let update_subscriber =
+                    SubscriberCommand::UpdateSubscriber(UpdateSubscriber::new(id, event.name));
+                self.subscriber_command_executor
+                    .execute(update_subscriber)
+                    .await
+            }
+            result => result,
+        }
+        .map_err(handling_failed)
+    }
+
+    async fn handle_subscriber_profile_updated(
+        &self,
+        event: SubscriberProfileUpdatedV1,
+    ) -> Result<(), QueueError> {
+        let update_subscriber = SubscriberCommand::UpdateSubscriber(UpdateSubscriber::new(
+            event.subscriber_id,
+            event.name,
+        ));
+
+        match self
+            .subscriber_command_executor
+            .execute(update_subscriber)
+            .await
+        {
+            // not confirmed yet, the confirmed event carries the updated profile
+            Err(SubscriberError::SubscriberNotFound(_)) => Ok(()),
+            result => result,
+        }
+        .map_err(handling_failed)
+    }
+
+    // Unsubscribed subscribers don't receive issues any more, so they aren't kept
+    async fn handle_subscriber_unsubscribed(
+        &self,
+        event: SubscriberIdentifiedV1,
+    ) -> Result<(), QueueError> {
+        let remove_subscriber =
+            SubscriberCommand::RemoveSubscriber(RemoveSubscriber::new(event.subscriber_id));
+
+        match self
+            .subscriber_command_executor
+            .execute(remove_subscriber)
+            .await
+        {
+            // removed by the event delivered before, or never confirmed
+            Err(SubscriberError::SubscriberNotFound(_)) => Ok(()),
+            result => result,
+        }
+        .map_err(handling_failed)
+    }
+
+    // Erased subscribers are removed along with deliveries and mail feedback keeping their email
+    async fn handle_subscriber_erased(
+        &self,
+        event: SubscriberIdentifiedV1,
+    ) -> Result<(), QueueError> {
+        let subscriber_id = event.subscriber_id;
+        self.handle_subscriber_unsubscribed(event).await?;
+
+        self.delivery_repository
+            .remove_by_subscriber_id(subscriber_id)
+            .await
+            .map_err(|error| QueueError::HandlingFailed(error.into()))?;
+        self.feedback_repository
+            .remove_by_subscriber_id(subscriber_id)
+            .await
+            .map_err(|error| QueueError::HandlingFailed(error.into()))?;
+
+        Ok(())
+    }
+}
+
+fn handling_failed(error: SubscriberError) -> QueueError {
+    match error {
+        SubscriberError::InvalidSubscriberEmail
+        | SubscriberError::InvalidSubscriberEmailVerificationStatus => {
+            QueueError::UnprocessableMessage(error.into())
+        }
+        SubscriberError::SubscriberNotFound(_)
+        | SubscriberError::SubscriberAlreadyExists(_)
+        | SubscriberError::MultipleSubscribersFound
+        | SubscriberError::ConcurrentModification(_)
+        | SubscriberError::InvalidSubscriberEvent(_)
+        | SubscriberError::RepositoryOperationFailed(_)
+        | SubscriberError::Unexpected(_) => QueueError::HandlingFailed(error.into()),
+    }
+}
+
+fn decode<T>(data: serde_json::Value) -> Result<T, QueueError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_value(data).map_err(|error| QueueError::UnprocessableMessage(error.into()))
+}
+
+#[async_trait::async_trait]
+impl<R, D, F> MessageHandler for SubscriberEventHandler<R, D, F>
+where
+    R: SubscriberRepository + 'static,
+    D: DeliveryRepository + 'static,
+    F: MailFeedbackRepository + 'static,
+{
+    #[tracing::instrument(name = "Handling subscriber event", skip(self), fields(message_id = %message.id))]
+    async fn handle(&self, message: &Message) -> Result<(), QueueError> {
+        let envelope: SubscriberEventEnvelope = serde_json::from_value(message.payload.clone())
+            .map_err(|error| QueueError::UnprocessableMessage(error.into()))?;
+
+        match (envelope.event_type.as_str(), envelope.schema_version) {
+            ("SubscriberConfirmed", 2) => {
+                self.handle_subscriber_confirmed(decode(envelope.data)?)
+                    .await
+            }
+            ("SubscriberUnsubscribed", 1) => {
+                self.handle_subscriber_unsubscribed(decode(envelope.data)?)
+                    .await
+            }
+            ("SubscriberErased", 1) => self.handle_subscriber_erased(decode(envelope.data)?).await,
+            ("SubscriberProfileUpdated", 1) => {
+                self.handle_subscriber_profile_updated(decode(envelope.data)?)
+                    .await
+            }
+            // publication keeps confirmed subscribers only
+            ("SubscriberRegistered", _) => {
+                tracing::debug!(
+                    "Skipping subscriber event (ID: {}) of type {}",
+                    envelope.id,
+                    envelope.event_type
+                );
+                Ok(())
+            }
+            ("SubscriberConfirmed", schema_version)
+            | ("SubscriberUnsubscribed", schema_version)
+            | ("SubscriberErased", schema_version)
+            | ("SubscriberProfileUpdated", schema_version) => {
+                Err(QueueError::UnprocessableMessage(anyhow!(
+                    "Schema version {} of {} is not supported",
+                    schema_version,
+                    envelope.event_type
+                )))
+            }
+            // events added later are skipped until they are supported
+            (event_type, _) => {
+                tracing::warn!(
+                    "Skipping subscriber event (ID: {}) of unknown type {}",
+                    envelope.id,
+                    event_type
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        Delivery,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+        FakeSubscriberRepository,
+    };
+
+    use super::*;
+
+    type Handler = SubscriberEventHandler<
+        FakeSubscriberRepository,
+        FakeDeliveryRepository,
+        FakeMailFeedbackRepository,
+    >;
+
+    fn get_handler() -> (Handler, FakeSubscriberRepository, FakeDeliveryRepository) {
+        let repository = FakeSubscriberRepository::new();
+        let delivery_repository = FakeDeliveryRepository::new();
+        let handler = SubscriberEventHandler::new(
+            SubscriberCommandExecutor::new(repository.clone()),
+            delivery_repository.clone(),
+            FakeMailFeedbackRepository::new(),
+        );
+        (handler, repository, delivery_repository)
+    }
+
+    fn subscriber_event(event_type: &str, schema_version: u32, data: serde_json::Value) -> Message {
+        Message {
+            id: Uuid::new_v4(),
+            payload: serde_json::json!({
+                "Id": Uuid::new_v4(),
+                "SchemaVersion": schema_version,
+                "OccurredAt": "2024-04-27T09:30:00Z",
+                "Type": event_type,
+                "Data": data,
+            }),
+            attempts: 1,
+        }
+    }
+
+    fn subscriber_confirmed(subscriber_id: Uuid, name: &str, schema_version: u32) -> Message {
+        subscriber_event(
+            "SubscriberConfirmed",
+            schema_version,
+            serde_json::json!({
+                "SubscriberId": subscriber_id,
+                "Email": format!("{}@example.com", subscriber_id),
+                "Name": name,
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_confirmed_creates_subscriber() {
+        // given
+        let (handler, repository, _) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+
+        // when
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine", 2))
+            .await
+            .unwrap();
+
+        // then
+        let subscriber = repository.find_by_id(subscriber_id).await.unwrap().unwrap();
+        assert_eq!(subscriber.name, "Arine");
+        assert_eq!(
+            subscriber.email.address,
+            format!("{}@example.com", subscriber_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_confirmed_again_updates_existing_subscriber() {
+        // given
+        let (handler, repository, _) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine", 2))
+            .await
+            .unwrap();
+
+        // when
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine You", 2))
+            .await
+            .unwrap();
+
+        // then
+        let subscriber = repository.find_by_id(subscriber_id).await.unwrap().unwrap();
+        assert_eq!(subscriber.name, "Arine You");
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_confirmed_of_unsupported_schema_version_is_unprocessable() {
+        // given
+        let (handler, _, _) = get_handler();
+
+        // when
+        let response = handler
+            .handle(&subscriber_confirmed(Uuid::new_v4(), "Arine", 1))
+            .await;
+
+        // then
+        assert!(matches!(response, Err(QueueError::UnprocessableMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn handling_malformed_message_is_unprocessable() {
+        // given
+        let (handler, _, _) = get_handler();
+        let message = Message {
+            id: Uuid::new_v4(),
+            payload: serde_json::json!({ "Unknown": true }),
+            attempts: 1,
+        };
+
+        // when
+        let response = handler.handle(&message).await;
+
+        // then
+        assert!(matches!(response, Err(QueueError::UnprocessableMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_registered_before_confirmation_is_skipped() {
+        // given
+        let (handler, repository, _) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+        let message = subscriber_event(
+            "SubscriberRegistered",
+            1,
+            serde_json::json!({
+                "SubscriberId": subscriber_id,
+                "Email": format!("{}@example.com", subscriber_id),
+                "Name": "Arine",
+            }),
+        );
+
+        // when
+        let response = handler.handle(&message).await;
+
+        // then
+        assert!(response.is_ok());
+        assert!(repository.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_unsubscribed_removes_subscriber_even_if_delivered_again() {
+        // given
+        let (handler, repository, _) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine", 2))
+            .await
+            .unwrap();
+        let message = subscriber_event(
+            "SubscriberUnsubscribed",
+            1,
+            serde_json::json!({ "SubscriberId": subscriber_id }),
+        );
+
+        // when
+        let response = handler.handle(&message).await;
+        let response_again = handler.handle(&message).await;
+
+        // then
+        assert!(response.is_ok());
+        assert!(response_again.is_ok());
+        assert!(repository
+            .find_by_id(subscriber_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_erased_removes_subscriber_along_with_its_deliveries() {
+        // given
+        let (handler, repository, delivery_repository) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+        let issue_id = Uuid::new_v4();
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine", 2))
+            .await
+            .unwrap();
+        delivery_repository
+            .enqueue(&[
+                Delivery::new(issue_id, subscriber_id, "arine@example.com".to_string()),
+                Delivery::new(issue_id, Uuid::new_v4(), "you@example.com".to_string()),
+            ])
+            .await
+            .unwrap();
+
+        // when
+        let response = handler
+            .handle(&subscriber_event(
+                "SubscriberErased",
+                1,
+                serde_json::json!({ "SubscriberId": subscriber_id }),
+            ))
+            .await;
+
+        // then
+        assert!(response.is_ok());
+        assert!(repository
+            .find_by_id(subscriber_id)
+            .await
+            .unwrap()
+            .is_none());
+        let deliveries = delivery_repository
+            .find_by_issue_id(issue_id)
+            .await
+            .unwrap();
+        assert_eq!(deliveries.len(), 1);
+        assert_ne!(deliveries[0].subscriber_id, subscriber_id);
+    }
+
+    fn subscriber_profile_updated(subscriber_id: Uuid, name: &str) -> Message {
+        subscriber_event(
+            "SubscriberProfileUpdated",
+            1,
+            serde_json::json!({
+                "SubscriberId": subscriber_id,
+                "Name": name,
+                "ContentFormat": "PlainText",
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_profile_updated_updates_subscriber() {
+        // given
+        let (handler, repository, _) = get_handler();
+        let subscriber_id = Uuid::new_v4();
+        handler
+            .handle(&subscriber_confirmed(subscriber_id, "Arine", 2))
+            .await
+            .unwrap();
+
+        // when
+        let response = handler
+            .handle(&subscriber_profile_updated(subscriber_id, "Arine You"))
+            .await;
+
+        // then
+        assert!(response.is_ok());
+        let subscriber = repository.find_by_id(subscriber_id).await.unwrap().unwrap();
+        assert_eq!(subscriber.name, "Arine You");
+    }
+
+    #[tokio::test]
+    async fn handling_subscriber_profile_updated_of_unconfirmed_subscriber_is_skipped() {
+        // given
+        let (handler, repository, _) = get_handler();
+
+        // when
+        let response = handler
+            .handle(&subscriber_profile_updated(Uuid::new_v4(), "Arine You"))
+            .await;
+
+        // then
+        assert!(response.is_ok());
+        assert!(repository.find_all().await.unwrap().is_empty());
+    }
+}
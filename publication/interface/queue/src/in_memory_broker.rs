@@ -0,0 +1,150 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+use std::time::Duration;
+
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::broker::{
+    Message,
+    MessageBroker,
+};
+use crate::error::QueueError;
+
+#[derive(Debug)]
+struct QueuedMessage {
+    message: Message,
+    ordering_key: Option<String>,
+    visible_at: Instant,
+    acknowledged: bool,
+    dead_letter_reason: Option<String>,
+}
+
+// Keeps messages in memory, clones share them so that tests can publish and check messages
+#[derive(Clone)]
+pub struct InMemoryMessageBroker {
+    messages: Arc<Mutex<Vec<QueuedMessage>>>,
+    visibility_timeout: Duration,
+}
+
+impl InMemoryMessageBroker {
+    pub fn new(visibility_timeout: Duration) -> Self {
+        Self {
+            messages: Arc::new(Mutex::new(Vec::new())),
+            visibility_timeout,
+        }
+    }
+
+    pub fn publish(&self, payload: serde_json::Value) -> Uuid {
+        self.publish_with_ordering_key(None, payload)
+    }
+
+    pub fn publish_with_ordering_key(
+        &self,
+        ordering_key: Option<&str>,
+        payload: serde_json::Value,
+    ) -> Uuid {
+        let id = Uuid::new_v4();
+        self.messages.lock().unwrap().push(QueuedMessage {
+            message: Message {
+                id,
+                payload,
+                attempts: 0,
+            },
+            ordering_key: ordering_key.map(str::to_string),
+            visible_at: Instant::now(),
+            acknowledged: false,
+            dead_letter_reason: None,
+        });
+        id
+    }
+
+    pub fn acknowledged(&self) -> Vec<Uuid> {
+        self.messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|queued| queued.acknowledged)
+            .map(|queued| queued.message.id)
+            .collect()
+    }
+
+    pub fn dead_lettered(&self) -> Vec<(Uuid, String)> {
+        self.messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|queued| {
+                queued
+                    .dead_letter_reason
+                    .clone()
+                    .map(|reason| (queued.message.id, reason))
+            })
+            .collect()
+    }
+
+    fn settle<F>(&self, id: Uuid, settle: F) -> Result<(), QueueError>
+    where
+        F: FnOnce(&mut QueuedMessage),
+    {
+        let mut messages = self.messages.lock().unwrap();
+        let queued = messages
+            .iter_mut()
+            .find(|queued| queued.message.id == id)
+            .ok_or_else(|| {
+                QueueError::BrokerOperationFailed(anyhow::anyhow!("Message {} doesn't exist", id))
+            })?;
+
+        settle(queued);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageBroker for InMemoryMessageBroker {
+    async fn receive(&self, max_messages: u64) -> Result<Vec<Message>, QueueError> {
+        let now = Instant::now();
+        let mut messages = self.messages.lock().unwrap();
+
+        // ordering keys of unsettled messages met so far, whose later messages wait for them
+        let mut waiting_keys = Vec::new();
+        let mut received = Vec::new();
+        for queued in messages.iter_mut() {
+            if queued.acknowledged || queued.dead_letter_reason.is_some() {
+                continue;
+            }
+            let waiting = queued
+                .ordering_key
+                .as_ref()
+                .is_some_and(|key| waiting_keys.contains(key));
+            if let Some(key) = &queued.ordering_key {
+                waiting_keys.push(key.clone());
+            }
+            if waiting || queued.visible_at > now || received.len() >= max_messages as usize {
+                continue;
+            }
+
+            queued.visible_at = now + self.visibility_timeout;
+            queued.message.attempts += 1;
+            received.push(queued.message.clone());
+        }
+
+        Ok(received)
+    }
+
+    async fn acknowledge(&self, id: Uuid) -> Result<(), QueueError> {
+        self.settle(id, |queued| queued.acknowledged = true)
+    }
+
+    async fn retry(&self, id: Uuid, delay: Duration, _reason: &str) -> Result<(), QueueError> {
+        self.settle(id, |queued| queued.visible_at = Instant::now() + delay)
+    }
+
+    async fn dead_letter(&self, id: Uuid, reason: &str) -> Result<(), QueueError> {
+        self.settle(id, |queued| {
+            queued.dead_letter_reason = Some(reason.to_string())
+        })
+    }
+}
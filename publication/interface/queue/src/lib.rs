@@ -1 +1,8 @@
-
+mod broker;
+mod consumer;
+mod error;
+mod handler;
+mod in_memory_broker;
+mod postgres_broker;
+pub mod prelude;
+pub mod runner;
@@ -0,0 +1,15 @@
+pub use crate::broker::{
+    Message,
+    MessageBroker,
+};
+pub use crate::consumer::{
+    Consumer,
+    ConsumerOptions,
+};
+pub use crate::error::QueueError;
+pub use crate::handler::{
+    MessageHandler,
+    SubscriberEventHandler,
+};
+pub use crate::in_memory_broker::InMemoryMessageBroker;
+pub use crate::postgres_broker::PostgresMessageBroker;
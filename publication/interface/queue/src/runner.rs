@@ -0,0 +1,34 @@
+use domain::prelude::{
+    DeliveryRepository,
+    MailFeedbackRepository,
+    SubscriberCommandExecutor,
+    SubscriberRepository,
+};
+
+use crate::broker::MessageBroker;
+use crate::consumer::{
+    Consumer,
+    ConsumerOptions,
+};
+use crate::handler::SubscriberEventHandler;
+
+pub async fn run<B, R, D, F>(
+    broker: B,
+    subscriber_repository: R,
+    delivery_repository: D,
+    feedback_repository: F,
+    options: ConsumerOptions,
+) where
+    B: MessageBroker,
+    R: SubscriberRepository + 'static,
+    D: DeliveryRepository + 'static,
+    F: MailFeedbackRepository + 'static,
+{
+    let handler = SubscriberEventHandler::new(
+        SubscriberCommandExecutor::new(subscriber_repository),
+        delivery_repository,
+        feedback_repository,
+    );
+
+    Consumer::new(broker, handler, options).run().await;
+}
@@ -1,9 +1,15 @@
-use std::time::Duration;
-
+use domain::prelude::{
+    MailFeedbackHandler,
+    MailFeedbackOptions,
+};
 use secrecy::ExposeSecret;
 use tokio::net::TcpListener;
 
-use crate::configuration;
+use crate::{
+    configuration,
+    database,
+    gateway,
+};
 
 pub async fn run(configuration: &configuration::Configuration) {
     // configure api listener
@@ -15,36 +21,54 @@ pub async fn run(configuration: &configuration::Configuration) {
     .expect("Failed to bind a port for application");
 
     // configure database connection pool
-    let mut database_connection_options = repositories::prelude::DatabaseConnectionOptions::new(
-        configuration
-            .database
-            .connection_string_with_database()
-            .expose_secret(),
+    let database_connection_pool = database::get_database_connection_pool(
+        &configuration.database.connection_string_with_database(),
+        &configuration.database.pool_options,
+    )
+    .await;
+
+    let subscriber_repository = database::get_subscriber_repository(
+        database_connection_pool.clone(),
+        &configuration.database.subscriber_persistence,
     );
 
-    database_connection_options
-        .min_connections(configuration.database.pool_options.min_connections)
-        .max_connections(configuration.database.pool_options.max_connections)
-        .connect_timeout(Duration::from_secs(
-            configuration.database.pool_options.connect_timeout,
-        ))
-        .sqlx_logging(true)
-        .sqlx_logging_level(tracing_log::log::LevelFilter::Debug)
-        .sqlx_slow_statements_logging_settings(
-            tracing_log::log::LevelFilter::Warn,
-            Duration::from_secs(1),
+    let subscription_gateway =
+        gateway::get_subscription_gateway(&configuration.gateways.subscription);
+
+    let subscriber_projection_rebuilder =
+        repositories::prelude::SubscriberProjectionSeaOrmRebuilder::new(
+            database_connection_pool.clone(),
         );
 
-    let database_connection_pool =
-        repositories::prelude::DatabaseConnection::connect(database_connection_options)
-            .await
-            .expect("Failed to create repository connection pool");
+    let issue_repository =
+        repositories::prelude::IssueSeaOrmRepository::new(database_connection_pool.clone());
 
-    let subscriber_repository =
-        repositories::prelude::SubscriberSeaOrmRepository::new(database_connection_pool.clone());
+    let rendered_issue_cache =
+        repositories::prelude::RenderedIssueSeaOrmCache::new(database_connection_pool.clone());
+
+    let mail_feedback_handler = MailFeedbackHandler::new(
+        subscriber_repository.clone(),
+        repositories::prelude::DeliverySeaOrmRepository::new(database_connection_pool.clone()),
+        repositories::prelude::MailFeedbackSeaOrmRepository::new(database_connection_pool),
+        MailFeedbackOptions {
+            soft_bounce_threshold: configuration.feedback.soft_bounce_threshold,
+            soft_bounce_window: chrono::Duration::seconds(
+                configuration.feedback.soft_bounce_window as i64,
+            ),
+        },
+    );
 
     // configure container which of the api context
-    let container = api::container::Container::new(subscriber_repository);
+    let container = api::container::Container::new(
+        subscriber_repository,
+        subscription_gateway,
+        subscriber_projection_rebuilder,
+        issue_repository,
+        rendered_issue_cache,
+        mail_feedback_handler,
+        configuration.api.webhook.secret.expose_secret().clone(),
+        configuration.api.admin.token.expose_secret().clone(),
+    );
 
     // run the api
     api::runner::run(listener, container).await;
@@ -17,6 +17,18 @@ pub struct Configuration {
     #[config(nested)]
     pub gateways: GatewaysConfiguration,
 
+    #[config(nested)]
+    pub queue: QueueConfiguration,
+
+    #[config(nested)]
+    pub reconciliation: ReconciliationConfiguration,
+
+    #[config(nested)]
+    pub delivery: DeliveryConfiguration,
+
+    #[config(nested)]
+    pub feedback: FeedbackConfiguration,
+
     #[config(nested)]
     pub logging: LoggingConfiguration,
 }
@@ -25,6 +37,12 @@ pub struct Configuration {
 pub struct ApiConfiguration {
     #[config(nested)]
     pub listening: ApiListening,
+
+    #[config(nested)]
+    pub webhook: ApiWebhook,
+
+    #[config(nested)]
+    pub admin: ApiAdmin,
 }
 
 #[derive(Debug, Config, Clone)]
@@ -36,6 +54,20 @@ pub struct ApiListening {
     pub port: u16,
 }
 
+#[derive(Debug, Config, Clone)]
+pub struct ApiWebhook {
+    // shared with mail providers, which sign their notifications by it
+    #[config(env = "APP_API_WEBHOOK_SECRET")]
+    pub secret: Secret<String>,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct ApiAdmin {
+    // sent by operators as a bearer token to call admin routes, which are closed while it's empty
+    #[config(env = "APP_API_ADMIN_TOKEN")]
+    pub token: Secret<String>,
+}
+
 #[derive(Debug, Config, Clone)]
 pub struct DatabaseConfiguration {
     #[config(nested)]
@@ -43,6 +75,9 @@ pub struct DatabaseConfiguration {
 
     #[config(nested)]
     pub pool_options: DatabasePoolOptions,
+
+    #[config(nested)]
+    pub subscriber_persistence: SubscriberPersistenceConfiguration,
 }
 
 #[derive(Debug, Config, Clone)]
@@ -70,6 +105,22 @@ pub struct DatabasePoolOptions {
     pub connect_timeout: u64,
 }
 
+#[derive(Debug, Config, Clone)]
+pub struct SubscriberPersistenceConfiguration {
+    #[config(env = "APP_DATABASE_SUBSCRIBER_PERSISTENCE_STRATEGY")]
+    pub strategy: SubscriberPersistenceStrategy,
+    // events appended between snapshots of an event sourced subscriber
+    pub snapshot_interval: u64,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub enum SubscriberPersistenceStrategy {
+    // subscribers are loaded from their rows
+    StateBased,
+    // subscribers are rebuilt from their events
+    EventSourced,
+}
+
 impl DatabaseConfiguration {
     pub fn connection_string_without_database(&self) -> Secret<String> {
         Secret::new(format!(
@@ -93,12 +144,141 @@ impl DatabaseConfiguration {
 pub struct GatewaysConfiguration {
     #[config(nested)]
     pub subscription: SubscriptionGatewayAddress,
+
+    #[config(nested)]
+    pub mail: MailGatewayAddress,
 }
 
 #[derive(Debug, Config, Clone)]
 pub struct SubscriptionGatewayAddress {
     #[config(env = "APP_GATEWAYS_SUBSCRIPTION_ORIGIN")]
     pub origin: String,
+
+    #[config(nested)]
+    pub pool_options: GatewayClientPoolOptions,
+
+    // number of subscribers requested at once
+    pub page_size: u64,
+    // retries of a request failed by an unavailable service
+    pub max_retries: u32,
+    pub retry_backoff: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct MailGatewayAddress {
+    #[config(env = "APP_GATEWAYS_MAIL_ORIGIN")]
+    pub origin: String,
+
+    #[config(env = "APP_GATEWAYS_MAIL_TOKEN")]
+    pub token: Secret<String>,
+
+    #[config(nested)]
+    pub pool_options: GatewayClientPoolOptions,
+
+    // address the issues are sent from
+    pub sender: String,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct GatewayClientPoolOptions {
+    pub connection_timeout: u64,
+    pub request_timeout: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct QueueConfiguration {
+    #[config(nested)]
+    pub broker: QueueBrokerConfiguration,
+
+    #[config(nested)]
+    pub consumer: QueueConsumerConfiguration,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct QueueBrokerConfiguration {
+    #[config(nested)]
+    pub source: QueueBrokerSource,
+
+    #[config(nested)]
+    pub pool_options: DatabasePoolOptions,
+
+    // table of the messages to consume
+    pub queue: String,
+    // seconds for a received message to be settled before it is delivered again
+    pub visibility_timeout: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct QueueBrokerSource {
+    #[config(env = "APP_QUEUE_BROKER_SOURCE_HOST")]
+    pub host: String,
+
+    #[config(env = "APP_QUEUE_BROKER_SOURCE_PORT")]
+    pub port: u16,
+
+    #[config(env = "APP_QUEUE_BROKER_SOURCE_USERNAME")]
+    pub username: String,
+
+    #[config(env = "APP_QUEUE_BROKER_SOURCE_PASSWORD")]
+    pub password: Secret<String>,
+
+    #[config(env = "APP_QUEUE_BROKER_SOURCE_DATABASE")]
+    pub database: String,
+}
+
+impl QueueBrokerConfiguration {
+    pub fn connection_string_with_database(&self) -> Secret<String> {
+        Secret::new(format!(
+            "postgresql://{}:{}@{}:{}/{}",
+            self.source.username,
+            self.source.password.expose_secret(),
+            self.source.host,
+            self.source.port,
+            self.source.database,
+        ))
+    }
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct QueueConsumerConfiguration {
+    pub concurrency: usize,
+    pub poll_interval: u64,
+    pub max_attempts: u32,
+    pub retry_backoff: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct ReconciliationConfiguration {
+    // seconds between reconciliations
+    pub interval: u64,
+    // only report the drift found
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct DeliveryConfiguration {
+    // seconds between starting due issues and delivering the ones sending
+    pub interval: u64,
+    // maximum number of deliveries sent at the same time
+    pub concurrency: usize,
+    // attempts of a delivery failed by an unavailable mail service before it is given up
+    pub max_attempts: u32,
+    pub retry_backoff: u64,
+    // seconds for a claimed delivery to be sent before another instance takes it over
+    pub lease: u64,
+    // page subscribers unsubscribe at, linked from issues personalized for them
+    pub unsubscribe_url: String,
+    // shared with the subscription service to sign the subscriber in the unsubscribe link
+    #[config(env = "APP_DELIVERY_UNSUBSCRIBE_SECRET")]
+    pub unsubscribe_secret: Secret<String>,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct FeedbackConfiguration {
+    // soft bounces within the window making the email of a subscriber invalid
+    pub soft_bounce_threshold: u64,
+    // seconds
+    pub soft_bounce_window: u64,
 }
 
 #[derive(Debug, Config, Clone)]
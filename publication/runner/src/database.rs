@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use secrecy::{
+    ExposeSecret,
+    Secret,
+};
+
+use crate::configuration::{
+    DatabasePoolOptions,
+    SubscriberPersistenceConfiguration,
+    SubscriberPersistenceStrategy,
+};
+
+pub async fn get_database_connection_pool(
+    connection_string: &Secret<String>,
+    pool_options: &DatabasePoolOptions,
+) -> repositories::prelude::DatabaseConnectionPool {
+    let mut database_connection_options =
+        repositories::prelude::DatabaseConnectionOptions::new(connection_string.expose_secret());
+
+    database_connection_options
+        .min_connections(pool_options.min_connections)
+        .max_connections(pool_options.max_connections)
+        .connect_timeout(Duration::from_secs(pool_options.connect_timeout))
+        .sqlx_logging(true)
+        .sqlx_logging_level(tracing_log::log::LevelFilter::Debug)
+        .sqlx_slow_statements_logging_settings(
+            tracing_log::log::LevelFilter::Warn,
+            Duration::from_secs(1),
+        );
+
+    repositories::prelude::DatabaseConnection::connect(database_connection_options)
+        .await
+        .expect("Failed to create repository connection pool")
+}
+
+pub fn get_subscriber_repository(
+    database_connection_pool: repositories::prelude::DatabaseConnectionPool,
+    configuration: &SubscriberPersistenceConfiguration,
+) -> repositories::prelude::AnySubscriberRepository {
+    match configuration.strategy {
+        SubscriberPersistenceStrategy::StateBased => {
+            repositories::prelude::AnySubscriberRepository::StateBased(
+                repositories::prelude::SubscriberSeaOrmRepository::new(database_connection_pool),
+            )
+        }
+        SubscriberPersistenceStrategy::EventSourced => {
+            repositories::prelude::AnySubscriberRepository::EventSourced(
+                repositories::prelude::SubscriberEventSourcedSeaOrmRepository::new(
+                    database_connection_pool,
+                    configuration.snapshot_interval,
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use domain::prelude::{
+    DeliveryOptions,
+    IssueDeliverer,
+    IssueRepository,
+    IssueScheduler,
+    IssueStatus,
+};
+use repositories::prelude::{
+    DeliverySeaOrmRepository,
+    IssueSeaOrmRepository,
+    RenderedIssueSeaOrmCache,
+};
+use secrecy::ExposeSecret;
+
+use crate::configuration::Configuration;
+use crate::{
+    database,
+    gateway,
+};
+
+// Start due issues and deliver the ones sending periodically along with the application, every
+// instance takes part as starting an issue and its deliveries are claimed, and failures are
+// reported and resumed next time
+pub async fn run(configuration: Configuration) {
+    let database_connection_pool = database::get_database_connection_pool(
+        &configuration.database.connection_string_with_database(),
+        &configuration.database.pool_options,
+    )
+    .await;
+    let issue_repository = IssueSeaOrmRepository::new(database_connection_pool.clone());
+    let scheduler = IssueScheduler::new(issue_repository.clone());
+    let deliverer = IssueDeliverer::new(
+        issue_repository.clone(),
+        database::get_subscriber_repository(
+            database_connection_pool.clone(),
+            &configuration.database.subscriber_persistence,
+        ),
+        DeliverySeaOrmRepository::new(database_connection_pool.clone()),
+        RenderedIssueSeaOrmCache::new(database_connection_pool),
+        gateway::get_mail_gateway(&configuration.gateways.mail),
+        DeliveryOptions {
+            concurrency: configuration.delivery.concurrency,
+            max_attempts: configuration.delivery.max_attempts,
+            retry_backoff: Duration::from_secs(configuration.delivery.retry_backoff),
+            lease: Duration::from_secs(configuration.delivery.lease),
+            unsubscribe_url: configuration.delivery.unsubscribe_url.clone(),
+            unsubscribe_secret: configuration
+                .delivery
+                .unsubscribe_secret
+                .expose_secret()
+                .clone(),
+        },
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(configuration.delivery.interval));
+
+    loop {
+        interval.tick().await;
+
+        match scheduler.start_due_issues(Utc::now()).await {
+            Ok(report) if !report.failed.is_empty() => {
+                tracing::warn!(?report, "Started due issues partially")
+            }
+            Ok(report) if !report.started.is_empty() => {
+                tracing::info!(?report, "Started due issues")
+            }
+            Ok(_) => {}
+            Err(error) => tracing::error!("Failed to start due issues: {:?}", error),
+        }
+
+        // issues started earlier are resumed as well, e.g. after a crash or a failed delivery
+        let issues = match issue_repository.find_by_status(IssueStatus::Sending).await {
+            Ok(issues) => issues,
+            Err(error) => {
+                tracing::error!("Failed to find sending issues: {:?}", error);
+                continue;
+            }
+        };
+
+        for issue in issues {
+            match deliverer.deliver(issue.id).await {
+                Ok(summary) if summary.is_settled() => {
+                    tracing::info!(issue_id = %issue.id, ?summary, "Delivered issue")
+                }
+                Ok(summary) => {
+                    tracing::info!(issue_id = %issue.id, ?summary, "Delivered issue partially")
+                }
+                Err(error) => {
+                    tracing::error!(issue_id = %issue.id, "Failed to deliver issue: {:?}", error)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use gateways::prelude::{
+    HttpClient,
+    HttpUrl,
+    MailHttpGateway,
+    MailHttpGatewayOptions,
+    SubscriptionHttpGateway,
+    SubscriptionHttpGatewayOptions,
+};
+use secrecy::ExposeSecret;
+
+use crate::configuration::{
+    MailGatewayAddress,
+    SubscriptionGatewayAddress,
+};
+
+pub fn get_subscription_gateway(
+    configuration: &SubscriptionGatewayAddress,
+) -> SubscriptionHttpGateway {
+    let client = HttpClient::builder()
+        .connect_timeout(Duration::from_secs(
+            configuration.pool_options.connection_timeout,
+        ))
+        .timeout(Duration::from_secs(
+            configuration.pool_options.request_timeout,
+        ))
+        .build()
+        .expect("Failed to create subscription client pool");
+
+    SubscriptionHttpGateway::new(
+        client,
+        HttpUrl::parse(&configuration.origin).expect("Failed to parse subscription's origin"),
+        SubscriptionHttpGatewayOptions {
+            page_size: configuration.page_size,
+            max_retries: configuration.max_retries,
+            retry_backoff: Duration::from_secs(configuration.retry_backoff),
+        },
+    )
+}
+
+pub fn get_mail_gateway(configuration: &MailGatewayAddress) -> MailHttpGateway {
+    let client = HttpClient::builder()
+        .connect_timeout(Duration::from_secs(
+            configuration.pool_options.connection_timeout,
+        ))
+        .timeout(Duration::from_secs(
+            configuration.pool_options.request_timeout,
+        ))
+        .build()
+        .expect("Failed to create mail client pool");
+
+    MailHttpGateway::new(
+        client,
+        HttpUrl::parse(&configuration.origin).expect("Failed to parse mail service's origin"),
+        MailHttpGatewayOptions {
+            sender: configuration.sender.clone(),
+            token: configuration.token.expose_secret().clone(),
+        },
+    )
+}
@@ -1,3 +1,9 @@
 pub mod api;
 pub mod configuration;
+pub mod database;
+pub mod delivery;
+pub mod gateway;
+pub mod projection;
+pub mod queue;
+pub mod reconciliation;
 pub mod telemetry;
@@ -9,6 +9,47 @@ async fn main() {
     );
     runner::telemetry::initialize_subscriber(subscriber);
 
-    // run api
-    runner::api::run(&configuration).await;
+    let mut arguments = std::env::args().skip(1);
+    match arguments.next().as_deref() {
+        // run queue consumer, scheduled reconciliation and issue delivery along with api
+        None => {
+            tokio::spawn(runner::queue::run(configuration.clone()));
+            tokio::spawn(runner::reconciliation::run(configuration.clone()));
+            tokio::spawn(runner::delivery::run(configuration.clone()));
+            runner::api::run(&configuration).await;
+        }
+        // rebuild a projection from the event log, e.g. `app rebuild-projection subscribers`
+        Some("rebuild-projection") => {
+            let projection = arguments
+                .next()
+                .unwrap_or_default()
+                .parse()
+                .unwrap_or_else(|error| {
+                    eprintln!("{}, available projections: subscribers", error);
+                    std::process::exit(2);
+                });
+            let batch_size = match (arguments.next().as_deref(), arguments.next()) {
+                (Some("--batch-size"), Some(batch_size)) => {
+                    batch_size.parse().expect("Batch size must be a number")
+                }
+                _ => domain::prelude::SubscriberProjection::DEFAULT_REBUILD_BATCH_SIZE,
+            };
+            let progress = runner::projection::run_once(configuration, projection, batch_size)
+                .await
+                .expect("Failed to rebuild projection, run again to resume");
+
+            println!(
+                "Rebuilt projection {} from {} events",
+                progress.projection.name(),
+                progress.processed_events,
+            );
+        }
+        Some(command) => {
+            eprintln!(
+                "Unknown command: {}, available commands: rebuild-projection <projection> [--batch-size <size>]",
+                command
+            );
+            std::process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,31 @@
+use domain::prelude::{
+    SubscriberProjection,
+    SubscriberProjectionError,
+    SubscriberProjectionMaintainer,
+    SubscriberProjectionRebuildProgress,
+};
+
+use crate::configuration::Configuration;
+use crate::database;
+
+// Rebuild a projection once, resuming the rebuild left by a crash if any
+pub async fn run_once(
+    configuration: Configuration,
+    projection: SubscriberProjection,
+    batch_size: u64,
+) -> Result<SubscriberProjectionRebuildProgress, SubscriberProjectionError> {
+    let database_connection_pool = database::get_database_connection_pool(
+        &configuration.database.connection_string_with_database(),
+        &configuration.database.pool_options,
+    )
+    .await;
+    let maintainer = SubscriberProjectionMaintainer::new(
+        repositories::prelude::SubscriberProjectionSeaOrmRebuilder::new(database_connection_pool),
+    );
+
+    maintainer
+        .rebuild(projection, batch_size, |progress| {
+            tracing::info!(?progress, "Rebuilt a batch of projection")
+        })
+        .await
+}
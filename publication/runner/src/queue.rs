@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use queue::prelude::{
+    ConsumerOptions,
+    PostgresMessageBroker,
+};
+use repositories::prelude::{
+    DeliverySeaOrmRepository,
+    MailFeedbackSeaOrmRepository,
+};
+
+use crate::configuration::Configuration;
+use crate::database;
+
+pub async fn run(configuration: Configuration) {
+    // configure database connection pools of the repository and the broker
+    let database_connection_pool = database::get_database_connection_pool(
+        &configuration.database.connection_string_with_database(),
+        &configuration.database.pool_options,
+    )
+    .await;
+    let broker_configuration = configuration.queue.broker;
+    let broker_connection_pool = database::get_database_connection_pool(
+        &broker_configuration.connection_string_with_database(),
+        &broker_configuration.pool_options,
+    )
+    .await;
+
+    let subscriber_repository = database::get_subscriber_repository(
+        database_connection_pool.clone(),
+        &configuration.database.subscriber_persistence,
+    );
+    let delivery_repository = DeliverySeaOrmRepository::new(database_connection_pool.clone());
+    let feedback_repository = MailFeedbackSeaOrmRepository::new(database_connection_pool);
+    let broker = PostgresMessageBroker::new(
+        broker_connection_pool,
+        &broker_configuration.queue,
+        Duration::from_secs(broker_configuration.visibility_timeout),
+    );
+
+    let consumer_configuration = configuration.queue.consumer;
+    let options = ConsumerOptions {
+        concurrency: consumer_configuration.concurrency,
+        poll_interval: Duration::from_secs(consumer_configuration.poll_interval),
+        max_attempts: consumer_configuration.max_attempts,
+        retry_backoff: Duration::from_secs(consumer_configuration.retry_backoff),
+    };
+
+    // run the consumer
+    queue::runner::run(
+        broker,
+        subscriber_repository,
+        delivery_repository,
+        feedback_repository,
+        options,
+    )
+    .await;
+}
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use domain::prelude::SubscriberReconciler;
+
+use crate::configuration::Configuration;
+use crate::{
+    database,
+    gateway,
+};
+
+// Reconcile periodically along with the application, failures are reported and retried next time
+pub async fn run(configuration: Configuration) {
+    let database_connection_pool = database::get_database_connection_pool(
+        &configuration.database.connection_string_with_database(),
+        &configuration.database.pool_options,
+    )
+    .await;
+    let reconciler = SubscriberReconciler::new(
+        database::get_subscriber_repository(
+            database_connection_pool,
+            &configuration.database.subscriber_persistence,
+        ),
+        gateway::get_subscription_gateway(&configuration.gateways.subscription),
+    );
+
+    let reconciliation_configuration = configuration.reconciliation;
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(reconciliation_configuration.interval));
+
+    loop {
+        interval.tick().await;
+
+        match reconciler
+            .reconcile(reconciliation_configuration.dry_run)
+            .await
+        {
+            Ok(report) if report.has_drifted() || !report.failed.is_empty() => {
+                tracing::warn!(?report, "Reconciled drifted subscribers")
+            }
+            Ok(report) => tracing::info!(?report, "Reconciled subscribers without drift"),
+            Err(error) => tracing::error!("Failed to reconcile subscribers: {:?}", error),
+        }
+    }
+}
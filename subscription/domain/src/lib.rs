@@ -1,3 +1,6 @@
 pub mod prelude;
 mod subscriber;
 mod subscription_token;
+mod topic;
+mod topic_membership;
+mod unit_of_work;
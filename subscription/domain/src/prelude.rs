@@ -1,2 +1,5 @@
 pub use crate::subscriber::prelude::*;
 pub use crate::subscription_token::prelude::*;
+pub use crate::topic::prelude::*;
+pub use crate::topic_membership::prelude::*;
+pub use crate::unit_of_work::prelude::*;
@@ -8,18 +8,33 @@ pub enum SubscriberError {
     #[error("Subscriber's email is invalid")]
     InvalidSubscriberEmail,
 
+    #[error("Subscriber's email domain ({0}) is not allowed to subscribe")]
+    BlockedSubscriberEmailDomain(String),
+
+    #[error("Email domain ({0}) is invalid")]
+    InvalidSubscriberEmailDomain(String),
+
     #[error("Subscriber's status is invalid")]
     InvalidSubscriberStatus,
 
     #[error("Subscriber (ID: {0}) doesn't exist")]
     SubscriberNotFound(Uuid),
 
+    #[error("Subscriber with the given email doesn't exist")]
+    SubscriberEmailNotFound,
+
+    #[error("Subscriber (ID: {0}) was modified concurrently")]
+    ConcurrentModification(Uuid),
+
     #[error("Failed to operate on repository")]
     RepositoryOperationFailed(#[source] anyhow::Error),
 
     #[error("Failed to send a message through messenger")]
     MessengerOperationFailed(#[source] anyhow::Error),
 
+    #[error("Failed to publish an event through publisher")]
+    PublisherOperationFailed(#[source] anyhow::Error),
+
     #[error("Failed unexpectedly")]
     Unexpected(#[source] anyhow::Error),
 }
@@ -0,0 +1,240 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::subscriber::model::{
+    Subscriber,
+    SubscriberContentFormat,
+};
+
+// Events are published to other bounded contexts, so their JSON form is a contract:
+// a breaking change of an event's data must come with a new schema version of that event
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "Type", content = "Data")]
+pub enum SubscriberEvent {
+    SubscriberRegistered(SubscriberRegistered),
+    SubscriberConfirmed(SubscriberConfirmed),
+    SubscriberUnsubscribed(SubscriberUnsubscribed),
+    SubscriberErased(SubscriberErased),
+    SubscriberProfileUpdated(SubscriberProfileUpdated),
+}
+
+impl SubscriberEvent {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            SubscriberEvent::SubscriberRegistered(_) => "SubscriberRegistered",
+            SubscriberEvent::SubscriberConfirmed(_) => "SubscriberConfirmed",
+            SubscriberEvent::SubscriberUnsubscribed(_) => "SubscriberUnsubscribed",
+            SubscriberEvent::SubscriberErased(_) => "SubscriberErased",
+            SubscriberEvent::SubscriberProfileUpdated(_) => "SubscriberProfileUpdated",
+        }
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        match self {
+            SubscriberEvent::SubscriberRegistered(_) => SubscriberRegistered::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberConfirmed(_) => SubscriberConfirmed::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberUnsubscribed(_) => SubscriberUnsubscribed::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberErased(_) => SubscriberErased::SCHEMA_VERSION,
+            SubscriberEvent::SubscriberProfileUpdated(_) => {
+                SubscriberProfileUpdated::SCHEMA_VERSION
+            }
+        }
+    }
+
+    pub fn subscriber_id(&self) -> Uuid {
+        match self {
+            SubscriberEvent::SubscriberRegistered(event) => event.subscriber_id,
+            SubscriberEvent::SubscriberConfirmed(event) => event.subscriber_id,
+            SubscriberEvent::SubscriberUnsubscribed(event) => event.subscriber_id,
+            SubscriberEvent::SubscriberErased(event) => event.subscriber_id,
+            SubscriberEvent::SubscriberProfileUpdated(event) => event.subscriber_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberRegistered {
+    pub subscriber_id: Uuid,
+    pub email: String,
+    pub name: String,
+}
+
+impl SubscriberRegistered {
+    const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(subscriber: &Subscriber) -> Self {
+        Self {
+            subscriber_id: subscriber.id,
+            email: subscriber.email.as_ref().to_string(),
+            name: subscriber.name.as_ref().to_string(),
+        }
+    }
+}
+
+// Carries the profile since consumers keep confirmed subscribers only, version 1 carried the ID
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberConfirmed {
+    pub subscriber_id: Uuid,
+    pub email: String,
+    pub name: String,
+}
+
+impl SubscriberConfirmed {
+    const SCHEMA_VERSION: u32 = 2;
+
+    pub fn new(subscriber: &Subscriber) -> Self {
+        Self {
+            subscriber_id: subscriber.id,
+            email: subscriber.email.as_ref().to_string(),
+            name: subscriber.name.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberUnsubscribed {
+    pub subscriber_id: Uuid,
+}
+
+impl SubscriberUnsubscribed {
+    const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(subscriber_id: Uuid) -> Self {
+        Self { subscriber_id }
+    }
+}
+
+// Carries no personal data, consumers are expected to forget the subscriber on receiving it
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberErased {
+    pub subscriber_id: Uuid,
+}
+
+impl SubscriberErased {
+    const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(subscriber_id: Uuid) -> Self {
+        Self { subscriber_id }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberProfileUpdated {
+    pub subscriber_id: Uuid,
+    pub name: String,
+    pub content_format: SubscriberContentFormat,
+}
+
+impl SubscriberProfileUpdated {
+    const SCHEMA_VERSION: u32 = 1;
+
+    pub fn new(subscriber: &Subscriber) -> Self {
+        Self {
+            subscriber_id: subscriber.id,
+            name: subscriber.name.as_ref().to_string(),
+            content_format: subscriber.preferences.content_format.clone(),
+        }
+    }
+}
+
+// Event as it is stored in the outbox and published, the ID lets consumers drop duplicates
+// since an event can be delivered more than once
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscriberEventEnvelope {
+    pub id: Uuid,
+    pub schema_version: u32,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: SubscriberEvent,
+}
+
+impl SubscriberEventEnvelope {
+    pub fn new(event: SubscriberEvent) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            schema_version: event.schema_version(),
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn subscriber_event_envelope_is_serialized_with_type_schema_version_and_data() {
+        // given
+        let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let subscriber_id = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8").unwrap();
+        let envelope = SubscriberEventEnvelope {
+            id,
+            schema_version: 1,
+            occurred_at: Utc.with_ymd_and_hms(2024, 4, 27, 9, 30, 0).unwrap(),
+            event: SubscriberEvent::SubscriberRegistered(SubscriberRegistered {
+                subscriber_id,
+                email: "arine@example.com".to_string(),
+                name: "Arine".to_string(),
+            }),
+        };
+
+        // when
+        let json = serde_json::to_value(&envelope).unwrap();
+
+        // then
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                "SchemaVersion": 1,
+                "OccurredAt": "2024-04-27T09:30:00Z",
+                "Type": "SubscriberRegistered",
+                "Data": {
+                    "SubscriberId": "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+                    "Email": "arine@example.com",
+                    "Name": "Arine",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn deserializing_serialized_subscriber_event_envelope_makes_the_same_envelope() {
+        // given
+        let envelope = SubscriberEventEnvelope::new(SubscriberEvent::SubscriberErased(
+            SubscriberErased::new(Uuid::new_v4()),
+        ));
+
+        // when
+        let json = serde_json::to_string(&envelope).unwrap();
+        let deserialized: SubscriberEventEnvelope = serde_json::from_str(&json).unwrap();
+
+        // then
+        assert_eq!(deserialized, envelope);
+    }
+
+    #[test]
+    fn event_type_matches_the_serialized_type() {
+        // given
+        let event =
+            SubscriberEvent::SubscriberUnsubscribed(SubscriberUnsubscribed::new(Uuid::new_v4()));
+
+        // when
+        let json = serde_json::to_value(&event).unwrap();
+
+        // then
+        assert_eq!(json["Type"], event.event_type());
+    }
+}
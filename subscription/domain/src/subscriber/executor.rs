@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use uuid::Uuid;
 
 use crate::subscriber::error::SubscriberError;
@@ -6,7 +8,9 @@ use crate::subscriber::model::{
     Subscriber,
     SubscriberEmail,
     SubscriberName,
+    SubscriberPreferences,
 };
+use crate::subscriber::policy::SubscriberEmailDomainPolicy;
 use crate::subscriber::repository::SubscriberRepository;
 
 pub enum SubscriberCommand {
@@ -24,6 +28,23 @@ pub enum SubscriberCommand {
     ConfirmSubscription {
         id: Uuid,
     },
+    // TODO: This is not actually command either, same as SendConfirmationMessage
+    SendProfileManagementMessage {
+        id: Uuid,
+        token: String,
+    },
+    UpdateProfile {
+        id: Uuid,
+        name: String,
+        preferences: SubscriberPreferences,
+    },
+    Unsubscribe {
+        id: Uuid,
+    },
+    // Delete the subscriber and its personal data for good, e.g. on a data erasure request
+    EraseSubscriber {
+        id: Uuid,
+    },
 }
 
 #[derive(Clone)]
@@ -33,7 +54,8 @@ where
     M: SubscriberMessenger,
 {
     repository: R,
-    messenger: M,
+    messenger: Arc<M>,
+    email_domain_policy: SubscriberEmailDomainPolicy,
     exposing_address: String,
 }
 
@@ -42,19 +64,40 @@ where
     R: SubscriberRepository,
     M: SubscriberMessenger,
 {
-    pub fn new(repository: R, messenger: M, exposing_address: String) -> Self {
+    pub fn new(
+        repository: R,
+        messenger: M,
+        email_domain_policy: SubscriberEmailDomainPolicy,
+        exposing_address: String,
+    ) -> Self {
         Self {
             repository,
-            messenger,
+            messenger: Arc::new(messenger),
+            email_domain_policy,
             exposing_address,
         }
     }
 
+    // Executor with the same messenger and policy but persisting through the given repository,
+    // e.g. the one handed out by a unit of work
+    pub fn with_repository<S>(&self, repository: S) -> SubscriberCommandExecutor<S, M>
+    where
+        S: SubscriberRepository,
+    {
+        SubscriberCommandExecutor {
+            repository,
+            messenger: self.messenger.clone(),
+            email_domain_policy: self.email_domain_policy.clone(),
+            exposing_address: self.exposing_address.clone(),
+        }
+    }
+
     pub async fn execute(&self, command: SubscriberCommand) -> Result<(), SubscriberError> {
         match command {
             SubscriberCommand::RegisterSubscriber { id, email, name } => {
                 let name = SubscriberName::parse(name)?;
                 let email = SubscriberEmail::parse(email)?;
+                self.email_domain_policy.evaluate(&email)?;
                 let subscriber = Subscriber::new(id, email, name);
 
                 self.repository.save(&subscriber).await
@@ -86,6 +129,62 @@ where
                     })
                     .await
             }
+            SubscriberCommand::SendProfileManagementMessage { id, token } => {
+                let subscriber = self
+                    .repository
+                    .find_by_id(id)
+                    .await?
+                    .ok_or(SubscriberError::SubscriberNotFound(id))?;
+
+                let profile_url = format!(
+                    "{}/subscriptions/profile?token={}",
+                    self.exposing_address, token,
+                );
+                let title = "Manage your newsletter subscription";
+                let content = &format!(
+                    r#"Click <a href="{}">here</a> to view and update your subscription. The link expires in 15 minutes."#,
+                    profile_url
+                );
+
+                self.messenger.send(&subscriber, title, content).await
+            }
+            SubscriberCommand::UpdateProfile {
+                id,
+                name,
+                preferences,
+            } => {
+                let name = SubscriberName::parse(name)?;
+
+                self.repository
+                    .modify(id, move |mut subscriber| {
+                        let name = name.clone();
+                        let preferences = preferences.clone();
+                        async move {
+                            subscriber.update_profile(name, preferences);
+                            Ok(subscriber)
+                        }
+                    })
+                    .await
+            }
+            SubscriberCommand::Unsubscribe { id } => {
+                self.repository
+                    .modify(id, |mut subscriber| async move {
+                        subscriber.unsubscribe();
+                        Ok(subscriber)
+                    })
+                    .await
+            }
+            SubscriberCommand::EraseSubscriber { id } => {
+                let mut subscriber = self
+                    .repository
+                    .find_by_id(id)
+                    .await?
+                    .ok_or(SubscriberError::SubscriberNotFound(id))?;
+
+                subscriber.erase();
+
+                self.repository.delete(&subscriber).await
+            }
         }
     }
 }
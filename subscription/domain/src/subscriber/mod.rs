@@ -1,7 +1,13 @@
 mod error;
+mod event;
 mod executor;
 mod messenger;
 mod model;
+mod outbox;
+mod overrides;
+mod policy;
 pub mod prelude;
+mod publisher;
 mod reader;
+mod relay;
 mod repository;
@@ -1,6 +1,19 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use uuid::Uuid;
 
 use crate::subscriber::error::SubscriberError;
+use crate::subscriber::event::{
+    SubscriberConfirmed,
+    SubscriberErased,
+    SubscriberEvent,
+    SubscriberEventEnvelope,
+    SubscriberProfileUpdated,
+    SubscriberRegistered,
+    SubscriberUnsubscribed,
+};
 
 #[derive(Debug)]
 pub struct Subscriber {
@@ -8,27 +21,92 @@ pub struct Subscriber {
     pub email: SubscriberEmail,
     pub name: SubscriberName,
     pub status: SubscriberStatus,
+    pub preferences: SubscriberPreferences,
+    pub subscribed_at: DateTime<Utc>,
+    // version of the persisted state this subscriber was loaded from, for optimistic locking
+    pub version: i64,
+    // events recorded by changes not persisted yet, saved along with the subscriber
+    pub pending_events: Vec<SubscriberEventEnvelope>,
 }
 
 impl Subscriber {
     pub fn new(id: Uuid, email: SubscriberEmail, name: SubscriberName) -> Self {
-        Self {
+        let mut subscriber = Self {
             id,
             email,
             name,
             status: SubscriberStatus::Unconfirmed,
-        }
+            preferences: SubscriberPreferences::default(),
+            subscribed_at: Utc::now(),
+            version: 0,
+            pending_events: Vec::new(),
+        };
+        subscriber.record(SubscriberEvent::SubscriberRegistered(
+            SubscriberRegistered::new(&subscriber),
+        ));
+        subscriber
     }
 
     pub fn confirm(&mut self) {
-        self.status = SubscriberStatus::Confirmed;
+        if self.status != SubscriberStatus::Confirmed {
+            self.status = SubscriberStatus::Confirmed;
+            self.record(SubscriberEvent::SubscriberConfirmed(
+                SubscriberConfirmed::new(self),
+            ));
+        }
+    }
+
+    pub fn unsubscribe(&mut self) {
+        if self.status != SubscriberStatus::Unsubscribed {
+            self.status = SubscriberStatus::Unsubscribed;
+            self.record(SubscriberEvent::SubscriberUnsubscribed(
+                SubscriberUnsubscribed::new(self.id),
+            ));
+        }
+    }
+
+    // Mark the subscriber to be erased, the repository deletes it along with its data
+    pub fn erase(&mut self) {
+        self.record(SubscriberEvent::SubscriberErased(SubscriberErased::new(
+            self.id,
+        )));
+    }
+
+    pub fn update_profile(&mut self, name: SubscriberName, preferences: SubscriberPreferences) {
+        if self.name != name || self.preferences != preferences {
+            self.name = name;
+            self.preferences = preferences;
+            self.record(SubscriberEvent::SubscriberProfileUpdated(
+                SubscriberProfileUpdated::new(self),
+            ));
+        }
+    }
+
+    fn record(&mut self, event: SubscriberEvent) {
+        self.pending_events
+            .push(SubscriberEventEnvelope::new(event));
     }
 }
 
+// Preferences managed by subscribers themselves through the profile management link
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct SubscriberPreferences {
+    pub content_format: SubscriberContentFormat,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SubscriberContentFormat {
+    #[default]
+    Html,
+    PlainText,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum SubscriberStatus {
     Confirmed,
     Unconfirmed,
+    Unsubscribed,
 }
 
 // The next two functions can be simplified by using the strum crate
@@ -37,6 +115,7 @@ impl AsRef<str> for SubscriberStatus {
         match self {
             SubscriberStatus::Confirmed => "Confirmed",
             SubscriberStatus::Unconfirmed => "Unconfirmed",
+            SubscriberStatus::Unsubscribed => "Unsubscribed",
         }
     }
 }
@@ -46,6 +125,7 @@ impl SubscriberStatus {
         match s.as_str() {
             "Confirmed" => Ok(Self::Confirmed),
             "Unconfirmed" => Ok(Self::Unconfirmed),
+            "Unsubscribed" => Ok(Self::Unsubscribed),
             _ => Err(SubscriberError::InvalidSubscriberStatus),
         }
     }
@@ -55,12 +135,37 @@ impl SubscriberStatus {
 pub struct SubscriberEmail(String);
 
 impl SubscriberEmail {
+    // Parse and canonicalize the email address, so that the same mailbox written differently
+    // (e.g. surrounding whitespaces, upper case or unicode domain) is stored and searched as one
+    // The local part is kept verbatim since it can be case sensitive by RFC 5321
     pub fn parse(s: String) -> Result<Self, SubscriberError> {
-        if validator::validate_email(&s) {
-            Ok(Self(s))
-        } else {
-            Err(SubscriberError::InvalidSubscriberEmail)
+        let s = s.trim();
+
+        if !validator::validate_email(s) {
+            return Err(SubscriberError::InvalidSubscriberEmail);
         }
+
+        let (local_part, domain_part) = s
+            .rsplit_once('@')
+            .ok_or(SubscriberError::InvalidSubscriberEmail)?;
+        let domain_part = Self::canonicalize_domain(domain_part)?;
+
+        Ok(Self(format!("{}@{}", local_part, domain_part)))
+    }
+
+    pub fn domain(&self) -> &str {
+        // parsed email always has '@'
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    fn canonicalize_domain(domain: &str) -> Result<String, SubscriberError> {
+        // address literal such as [127.0.0.1] is not a domain name
+        if domain.starts_with('[') {
+            return Ok(domain.to_lowercase());
+        }
+
+        // domain_to_ascii lowercases and converts internationalized domain to punycode
+        idna::domain_to_ascii(domain).map_err(|_| SubscriberError::InvalidSubscriberEmail)
     }
 }
 
@@ -70,7 +175,7 @@ impl AsRef<str> for SubscriberEmail {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SubscriberName(String);
 
 impl SubscriberName {
@@ -116,9 +221,15 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
+    use uuid::Uuid;
+
+    use crate::subscriber::event::SubscriberEvent;
     use crate::subscriber::model::{
+        Subscriber,
+        SubscriberContentFormat,
         SubscriberEmail,
         SubscriberName,
+        SubscriberPreferences,
     };
 
     #[derive(Debug, Clone)]
@@ -155,6 +266,31 @@ mod tests {
         assert_err!(SubscriberEmail::parse(email));
     }
 
+    #[test]
+    fn emails_are_trimmed() {
+        let email = SubscriberEmail::parse("  arine@example.com \n".to_string()).unwrap();
+        assert_eq!(email.as_ref(), "arine@example.com");
+    }
+
+    #[test]
+    fn domain_of_emails_is_lowercased_but_local_part_is_kept() {
+        let email = SubscriberEmail::parse("Arine.You@Example.COM".to_string()).unwrap();
+        assert_eq!(email.as_ref(), "Arine.You@example.com");
+    }
+
+    #[test]
+    fn internationalized_domain_of_emails_is_converted_to_punycode() {
+        let email = SubscriberEmail::parse("arine@BÜCHER.example".to_string()).unwrap();
+        assert_eq!(email.as_ref(), "arine@xn--bcher-kva.example");
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn parsing_canonical_emails_again_makes_the_same_email(valid_email: ValidEmailFixture) -> bool {
+        let email = SubscriberEmail::parse(valid_email.0.to_uppercase()).unwrap();
+        let reparsed_email = SubscriberEmail::parse(email.as_ref().to_string()).unwrap();
+        email == reparsed_email
+    }
+
     #[test]
     fn short_names_are_rejected() {
         let name = "a".repeat(SubscriberName::NAME_MIN_LENGTH - 1);
@@ -201,4 +337,79 @@ mod tests {
         let name = "Arine You".to_string();
         assert_ok!(SubscriberName::parse(name));
     }
+
+    fn generate_subscriber() -> Subscriber {
+        Subscriber::new(
+            Uuid::new_v4(),
+            SubscriberEmail::parse("arine@example.com".to_string()).unwrap(),
+            SubscriberName::parse("Arine You".to_string()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn new_subscriber_records_subscriber_registered_event() {
+        let subscriber = generate_subscriber();
+
+        assert_eq!(subscriber.pending_events.len(), 1);
+        assert!(matches!(
+            &subscriber.pending_events[0].event,
+            SubscriberEvent::SubscriberRegistered(event)
+                if event.subscriber_id == subscriber.id && event.email == "arine@example.com"
+        ));
+    }
+
+    #[test]
+    fn confirming_subscriber_twice_records_subscriber_confirmed_event_once() {
+        let mut subscriber = generate_subscriber();
+        subscriber.pending_events.clear();
+
+        subscriber.confirm();
+        subscriber.confirm();
+
+        assert_eq!(subscriber.pending_events.len(), 1);
+        assert!(matches!(
+            subscriber.pending_events[0].event,
+            SubscriberEvent::SubscriberConfirmed(_)
+        ));
+    }
+
+    #[test]
+    fn unsubscribing_subscriber_twice_records_subscriber_unsubscribed_event_once() {
+        let mut subscriber = generate_subscriber();
+        subscriber.pending_events.clear();
+
+        subscriber.unsubscribe();
+        subscriber.unsubscribe();
+
+        assert_eq!(subscriber.pending_events.len(), 1);
+        assert!(matches!(
+            subscriber.pending_events[0].event,
+            SubscriberEvent::SubscriberUnsubscribed(_)
+        ));
+    }
+
+    #[test]
+    fn updating_profile_twice_with_the_same_values_records_subscriber_profile_updated_event_once() {
+        let mut subscriber = generate_subscriber();
+        subscriber.pending_events.clear();
+        let preferences = SubscriberPreferences {
+            content_format: SubscriberContentFormat::PlainText,
+        };
+
+        subscriber.update_profile(
+            SubscriberName::parse("Arine".to_string()).unwrap(),
+            preferences.clone(),
+        );
+        subscriber.update_profile(
+            SubscriberName::parse("Arine".to_string()).unwrap(),
+            preferences,
+        );
+
+        assert_eq!(subscriber.pending_events.len(), 1);
+        assert!(matches!(
+            &subscriber.pending_events[0].event,
+            SubscriberEvent::SubscriberProfileUpdated(event)
+                if event.name == "Arine" && event.content_format == SubscriberContentFormat::PlainText
+        ));
+    }
 }
@@ -0,0 +1,17 @@
+use uuid::Uuid;
+
+use crate::subscriber::error::SubscriberError;
+use crate::subscriber::event::SubscriberEventEnvelope;
+
+// Events are written to the outbox in the same transaction as the subscriber changes,
+// and relayed to the publisher afterwards
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait SubscriberEventOutbox: Send + Sync {
+    // Returns unpublished events in the order they were recorded
+    async fn find_unpublished(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<SubscriberEventEnvelope>, SubscriberError>;
+    async fn mark_as_published(&self, id: Uuid) -> Result<(), SubscriberError>;
+}
@@ -0,0 +1,140 @@
+use crate::subscriber::error::SubscriberError;
+use crate::subscriber::policy::{
+    SubscriberEmailDomainPolicy,
+    SubscriberEmailDomainRule,
+};
+
+// Runtime overrides of the email domain policy, shared by every replica and kept over restarts
+// Domains are stored in the canonical form of the policy
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait SubscriberEmailDomainOverrideRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<(String, SubscriberEmailDomainRule)>, SubscriberError>;
+    async fn save(
+        &self,
+        domain: &str,
+        rule: SubscriberEmailDomainRule,
+    ) -> Result<(), SubscriberError>;
+    // Returns whether the override existed
+    async fn remove(&self, domain: &str) -> Result<bool, SubscriberError>;
+}
+
+// Overrides are stored before being applied to the policy of this replica,
+// other replicas apply them on reloading
+#[derive(Clone)]
+pub struct SubscriberEmailDomainOverrides<O>
+where
+    O: SubscriberEmailDomainOverrideRepository,
+{
+    policy: SubscriberEmailDomainPolicy,
+    repository: O,
+}
+
+impl<O> SubscriberEmailDomainOverrides<O>
+where
+    O: SubscriberEmailDomainOverrideRepository,
+{
+    pub fn new(policy: SubscriberEmailDomainPolicy, repository: O) -> Self {
+        Self { policy, repository }
+    }
+
+    pub async fn override_domain(
+        &self,
+        domain: &str,
+        rule: SubscriberEmailDomainRule,
+    ) -> Result<(), SubscriberError> {
+        let domain = SubscriberEmailDomainPolicy::parse_domain(domain)?;
+
+        self.repository.save(&domain, rule).await?;
+        self.policy.override_domain(&domain, rule)
+    }
+
+    // Returns whether the override existed
+    pub async fn remove_override(&self, domain: &str) -> Result<bool, SubscriberError> {
+        let domain = SubscriberEmailDomainPolicy::parse_domain(domain)?;
+
+        let removed = self.repository.remove(&domain).await?;
+        self.policy.remove_override(&domain)?;
+
+        Ok(removed)
+    }
+
+    // Read from the repository, so overrides made through any replica are listed
+    pub async fn overrides(
+        &self,
+    ) -> Result<Vec<(String, SubscriberEmailDomainRule)>, SubscriberError> {
+        self.repository.find_all().await
+    }
+
+    pub async fn reload(&self) -> Result<(), SubscriberError> {
+        let overrides = self.repository.find_all().await?;
+
+        self.policy.replace_overrides(overrides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::{
+        assert_err,
+        assert_ok,
+    };
+
+    use super::*;
+    use crate::subscriber::model::SubscriberEmail;
+
+    fn email(s: &str) -> SubscriberEmail {
+        SubscriberEmail::parse(s.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn invalid_domain_is_not_stored() {
+        let mut repository = MockSubscriberEmailDomainOverrideRepository::new();
+        repository.expect_save().never();
+        let overrides =
+            SubscriberEmailDomainOverrides::new(SubscriberEmailDomainPolicy::default(), repository);
+
+        let result = overrides
+            .override_domain(" ", SubscriberEmailDomainRule::Allowed)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SubscriberError::InvalidSubscriberEmailDomain(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn override_is_applied_only_after_being_stored() {
+        let mut repository = MockSubscriberEmailDomainOverrideRepository::new();
+        repository
+            .expect_save()
+            .returning(|_, _| Err(SubscriberError::Unexpected(anyhow::anyhow!("unavailable"))));
+        let policy = SubscriberEmailDomainPolicy::default();
+        let overrides = SubscriberEmailDomainOverrides::new(policy.clone(), repository);
+
+        let result = overrides
+            .override_domain("example.com", SubscriberEmailDomainRule::Denied)
+            .await;
+
+        assert_err!(result);
+        assert_ok!(policy.evaluate(&email("arine@example.com")));
+    }
+
+    #[tokio::test]
+    async fn reloading_applies_overrides_stored_through_other_replicas() {
+        let mut repository = MockSubscriberEmailDomainOverrideRepository::new();
+        repository.expect_find_all().returning(|| {
+            Ok(vec![(
+                "example.com".to_string(),
+                SubscriberEmailDomainRule::Denied,
+            )])
+        });
+        let policy = SubscriberEmailDomainPolicy::default();
+        let overrides = SubscriberEmailDomainOverrides::new(policy.clone(), repository);
+
+        overrides.reload().await.unwrap();
+
+        assert_err!(policy.evaluate(&email("arine@example.com")));
+    }
+}
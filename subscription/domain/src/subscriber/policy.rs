@@ -0,0 +1,325 @@
+use std::collections::{
+    BTreeMap,
+    HashSet,
+};
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use crate::subscriber::error::SubscriberError;
+use crate::subscriber::model::SubscriberEmail;
+
+const DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SubscriberEmailDomainRule {
+    Allowed,
+    Denied,
+}
+
+// Rules usually loaded from a policy file, which can be replaced without restart
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(default)]
+pub struct SubscriberEmailDomainRules {
+    pub block_disposable_domains: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+}
+
+impl Default for SubscriberEmailDomainRules {
+    fn default() -> Self {
+        Self {
+            block_disposable_domains: true,
+            allowed_domains: vec![],
+            denied_domains: vec![],
+        }
+    }
+}
+
+// Decides whether an email domain is accepted for signups
+//
+// The most specific domain wins, e.g. a rule for mail.example.com takes precedence over
+// a rule for example.com, and for the same domain the precedence is
+// runtime overrides > allowed domains > denied domains > bundled disposable domains
+#[derive(Clone)]
+pub struct SubscriberEmailDomainPolicy {
+    state: Arc<RwLock<PolicyState>>,
+}
+
+struct PolicyState {
+    block_disposable_domains: bool,
+    allowed_domains: HashSet<String>,
+    denied_domains: HashSet<String>,
+    disposable_domains: HashSet<String>,
+    overrides: BTreeMap<String, SubscriberEmailDomainRule>,
+}
+
+impl SubscriberEmailDomainPolicy {
+    pub fn new(rules: SubscriberEmailDomainRules) -> Self {
+        let disposable_domains = DISPOSABLE_DOMAINS
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+
+        Self {
+            state: Arc::new(RwLock::new(PolicyState {
+                block_disposable_domains: rules.block_disposable_domains,
+                allowed_domains: Self::normalize_domains(rules.allowed_domains),
+                denied_domains: Self::normalize_domains(rules.denied_domains),
+                disposable_domains,
+                overrides: BTreeMap::new(),
+            })),
+        }
+    }
+
+    // Replace rules from the policy file, runtime overrides are kept
+    pub fn reload(&self, rules: SubscriberEmailDomainRules) -> Result<(), SubscriberError> {
+        let mut state = self.state.write().map_err(|_| Self::poisoned())?;
+
+        state.block_disposable_domains = rules.block_disposable_domains;
+        state.allowed_domains = Self::normalize_domains(rules.allowed_domains);
+        state.denied_domains = Self::normalize_domains(rules.denied_domains);
+
+        Ok(())
+    }
+
+    pub fn evaluate(&self, email: &SubscriberEmail) -> Result<(), SubscriberError> {
+        let state = self.state.read().map_err(|_| Self::poisoned())?;
+        let blocked = Err(SubscriberError::BlockedSubscriberEmailDomain(
+            email.domain().to_string(),
+        ));
+
+        let mut domain = email.domain();
+        loop {
+            if let Some(rule) = state.overrides.get(domain) {
+                return match rule {
+                    SubscriberEmailDomainRule::Allowed => Ok(()),
+                    SubscriberEmailDomainRule::Denied => blocked,
+                };
+            }
+            if state.allowed_domains.contains(domain) {
+                return Ok(());
+            }
+            if state.denied_domains.contains(domain)
+                || (state.block_disposable_domains && state.disposable_domains.contains(domain))
+            {
+                return blocked;
+            }
+
+            match domain.split_once('.') {
+                Some((_, parent)) => domain = parent,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    pub fn overrides(&self) -> Result<Vec<(String, SubscriberEmailDomainRule)>, SubscriberError> {
+        let state = self.state.read().map_err(|_| Self::poisoned())?;
+
+        Ok(state
+            .overrides
+            .iter()
+            .map(|(domain, rule)| (domain.clone(), *rule))
+            .collect())
+    }
+
+    pub fn override_domain(
+        &self,
+        domain: &str,
+        rule: SubscriberEmailDomainRule,
+    ) -> Result<(), SubscriberError> {
+        let domain = Self::parse_domain(domain)?;
+        let mut state = self.state.write().map_err(|_| Self::poisoned())?;
+
+        state.overrides.insert(domain, rule);
+        Ok(())
+    }
+
+    // Returns whether the override existed
+    pub fn remove_override(&self, domain: &str) -> Result<bool, SubscriberError> {
+        let domain = Self::parse_domain(domain)?;
+        let mut state = self.state.write().map_err(|_| Self::poisoned())?;
+
+        Ok(state.overrides.remove(&domain).is_some())
+    }
+
+    // Replace runtime overrides by the stored ones, which other replicas may have changed
+    pub fn replace_overrides(
+        &self,
+        overrides: Vec<(String, SubscriberEmailDomainRule)>,
+    ) -> Result<(), SubscriberError> {
+        let overrides = overrides
+            .into_iter()
+            .filter_map(|(domain, rule)| Some((Self::normalize_domain(&domain)?, rule)))
+            .collect();
+        let mut state = self.state.write().map_err(|_| Self::poisoned())?;
+
+        state.overrides = overrides;
+        Ok(())
+    }
+
+    // Canonical form of a domain to override, in which it is stored as well
+    pub fn parse_domain(domain: &str) -> Result<String, SubscriberError> {
+        Self::normalize_domain(domain).ok_or(SubscriberError::InvalidSubscriberEmailDomain(
+            domain.to_string(),
+        ))
+    }
+
+    // Domains are compared in the same canonical form as SubscriberEmail
+    fn normalize_domain(domain: &str) -> Option<String> {
+        idna::domain_to_ascii(domain.trim())
+            .ok()
+            .filter(|domain| !domain.is_empty())
+    }
+
+    fn normalize_domains(domains: Vec<String>) -> HashSet<String> {
+        domains
+            .iter()
+            .filter_map(|domain| Self::normalize_domain(domain))
+            .collect()
+    }
+
+    fn poisoned() -> SubscriberError {
+        SubscriberError::Unexpected(anyhow::anyhow!("Failed to get email domain policy state"))
+    }
+}
+
+impl Default for SubscriberEmailDomainPolicy {
+    fn default() -> Self {
+        Self::new(SubscriberEmailDomainRules::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::{
+        assert_err,
+        assert_ok,
+    };
+
+    use super::*;
+
+    fn email(s: &str) -> SubscriberEmail {
+        SubscriberEmail::parse(s.to_string()).unwrap()
+    }
+
+    fn policy(allowed_domains: &[&str], denied_domains: &[&str]) -> SubscriberEmailDomainPolicy {
+        SubscriberEmailDomainPolicy::new(SubscriberEmailDomainRules {
+            block_disposable_domains: true,
+            allowed_domains: allowed_domains.iter().map(|d| d.to_string()).collect(),
+            denied_domains: denied_domains.iter().map(|d| d.to_string()).collect(),
+        })
+    }
+
+    #[test]
+    fn ordinary_domains_are_accepted() {
+        let policy = SubscriberEmailDomainPolicy::default();
+        assert_ok!(policy.evaluate(&email("arine@example.com")));
+    }
+
+    #[test]
+    fn bundled_disposable_domains_and_their_subdomains_are_rejected() {
+        let policy = SubscriberEmailDomainPolicy::default();
+
+        assert!(matches!(
+            policy.evaluate(&email("arine@mailinator.com")),
+            Err(SubscriberError::BlockedSubscriberEmailDomain(domain)) if domain == "mailinator.com"
+        ));
+        assert_err!(policy.evaluate(&email("arine@inbox.mailinator.com")));
+    }
+
+    #[test]
+    fn disposable_domains_are_accepted_if_blocking_is_disabled() {
+        let policy = SubscriberEmailDomainPolicy::new(SubscriberEmailDomainRules {
+            block_disposable_domains: false,
+            ..Default::default()
+        });
+        assert_ok!(policy.evaluate(&email("arine@mailinator.com")));
+    }
+
+    #[test]
+    fn denied_domains_are_rejected_case_insensitively() {
+        let policy = policy(&[], &["Example.COM"]);
+        assert_err!(policy.evaluate(&email("arine@example.com")));
+    }
+
+    #[test]
+    fn allowed_domains_take_precedence_over_denied_and_disposable_domains() {
+        let policy = policy(&["mailinator.com", "example.com"], &["example.com"]);
+
+        assert_ok!(policy.evaluate(&email("arine@mailinator.com")));
+        assert_ok!(policy.evaluate(&email("arine@example.com")));
+    }
+
+    #[test]
+    fn more_specific_domain_rule_takes_precedence() {
+        let policy = policy(&["trusted.example.com"], &["example.com"]);
+
+        assert_ok!(policy.evaluate(&email("arine@trusted.example.com")));
+        assert_err!(policy.evaluate(&email("arine@other.example.com")));
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_rules_and_survive_reload() {
+        let policy = policy(&["example.com"], &[]);
+        policy
+            .override_domain("example.com", SubscriberEmailDomainRule::Denied)
+            .unwrap();
+        policy
+            .override_domain("mailinator.com", SubscriberEmailDomainRule::Allowed)
+            .unwrap();
+
+        policy
+            .reload(SubscriberEmailDomainRules::default())
+            .unwrap();
+
+        assert_err!(policy.evaluate(&email("arine@example.com")));
+        assert_ok!(policy.evaluate(&email("arine@mailinator.com")));
+        assert_eq!(policy.overrides().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn removing_override_restores_rules() {
+        let policy = SubscriberEmailDomainPolicy::default();
+        policy
+            .override_domain("mailinator.com", SubscriberEmailDomainRule::Allowed)
+            .unwrap();
+
+        assert!(policy.remove_override("MAILINATOR.com").unwrap());
+        assert!(!policy.remove_override("mailinator.com").unwrap());
+        assert_err!(policy.evaluate(&email("arine@mailinator.com")));
+    }
+
+    #[test]
+    fn replacing_overrides_drops_ones_not_given() {
+        let policy = SubscriberEmailDomainPolicy::default();
+        policy
+            .override_domain("example.com", SubscriberEmailDomainRule::Denied)
+            .unwrap();
+
+        policy
+            .replace_overrides(vec![(
+                "mailinator.com".to_string(),
+                SubscriberEmailDomainRule::Allowed,
+            )])
+            .unwrap();
+
+        assert_ok!(policy.evaluate(&email("arine@example.com")));
+        assert_ok!(policy.evaluate(&email("arine@mailinator.com")));
+        assert_eq!(policy.overrides().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn reloading_rules_replaces_previous_rules() {
+        let policy = policy(&[], &["example.com"]);
+
+        policy
+            .reload(SubscriberEmailDomainRules::default())
+            .unwrap();
+
+        assert_ok!(policy.evaluate(&email("arine@example.com")));
+    }
+}
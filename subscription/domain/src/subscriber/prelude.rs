@@ -1,4 +1,12 @@
 pub use crate::subscriber::error::SubscriberError;
+pub use crate::subscriber::event::{
+    SubscriberConfirmed,
+    SubscriberErased,
+    SubscriberEvent,
+    SubscriberEventEnvelope,
+    SubscriberRegistered,
+    SubscriberUnsubscribed,
+};
 pub use crate::subscriber::executor::{
     SubscriberCommand,
     SubscriberCommandExecutor,
@@ -9,14 +17,35 @@ pub use crate::subscriber::messenger::{
 };
 pub use crate::subscriber::model::{
     Subscriber,
+    SubscriberContentFormat,
     SubscriberEmail,
     SubscriberName,
+    SubscriberPreferences,
     SubscriberStatus,
 };
+pub use crate::subscriber::outbox::{
+    MockSubscriberEventOutbox,
+    SubscriberEventOutbox,
+};
+pub use crate::subscriber::overrides::{
+    MockSubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+};
+pub use crate::subscriber::policy::{
+    SubscriberEmailDomainPolicy,
+    SubscriberEmailDomainRule,
+    SubscriberEmailDomainRules,
+};
+pub use crate::subscriber::publisher::{
+    MockSubscriberEventPublisher,
+    SubscriberEventPublisher,
+};
 pub use crate::subscriber::reader::{
     SubscriberQuery,
     SubscriberQueryReader,
 };
+pub use crate::subscriber::relay::SubscriberEventRelay;
 pub use crate::subscriber::repository::{
     MockSubscriberRepository,
     SubscriberRepository,
@@ -0,0 +1,9 @@
+use crate::subscriber::error::SubscriberError;
+use crate::subscriber::event::SubscriberEventEnvelope;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait SubscriberEventPublisher: Send + Sync {
+    // Publishing the same event again must be harmless, it happens when relaying is retried
+    async fn publish(&self, event: &SubscriberEventEnvelope) -> Result<(), SubscriberError>;
+}
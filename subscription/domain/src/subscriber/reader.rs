@@ -1,3 +1,5 @@
+use uuid::Uuid;
+
 use crate::subscriber::error::SubscriberError;
 use crate::subscriber::model::{
     Subscriber,
@@ -7,6 +9,11 @@ use crate::subscriber::repository::SubscriberRepository;
 
 pub enum SubscriberQuery {
     InquireConfirmedSubscribers,
+    // Page of confirmed subscribers ordered by ID, following the subscriber of the given ID
+    InquireConfirmedSubscribersPage { after: Option<Uuid>, limit: u64 },
+    // Single subscriber queries return one subscriber or SubscriberNotFound error
+    InquireSubscriber { id: Uuid },
+    InquireSubscriberByEmail { email: String },
 }
 
 #[derive(Clone)]
@@ -32,6 +39,23 @@ where
                     .find_by_status(SubscriberStatus::Confirmed)
                     .await
             }
+            SubscriberQuery::InquireConfirmedSubscribersPage { after, limit } => {
+                self.repository
+                    .find_page_by_status(SubscriberStatus::Confirmed, after, limit)
+                    .await
+            }
+            SubscriberQuery::InquireSubscriber { id } => self
+                .repository
+                .find_by_id(id)
+                .await?
+                .map(|subscriber| vec![subscriber])
+                .ok_or(SubscriberError::SubscriberNotFound(id)),
+            SubscriberQuery::InquireSubscriberByEmail { email } => self
+                .repository
+                .find_by_email(&email)
+                .await?
+                .map(|subscriber| vec![subscriber])
+                .ok_or(SubscriberError::SubscriberEmailNotFound),
         }
     }
 }
@@ -0,0 +1,132 @@
+use crate::subscriber::error::SubscriberError;
+use crate::subscriber::outbox::SubscriberEventOutbox;
+use crate::subscriber::publisher::SubscriberEventPublisher;
+
+// Delivers events at least once: an event published but not marked due to a failure
+// is published again on the next relay
+#[derive(Clone)]
+pub struct SubscriberEventRelay<O, P>
+where
+    O: SubscriberEventOutbox,
+    P: SubscriberEventPublisher,
+{
+    outbox: O,
+    publisher: P,
+}
+
+impl<O, P> SubscriberEventRelay<O, P>
+where
+    O: SubscriberEventOutbox,
+    P: SubscriberEventPublisher,
+{
+    pub fn new(outbox: O, publisher: P) -> Self {
+        Self { outbox, publisher }
+    }
+
+    // Returns the number of relayed events, stops at the first failure to keep the order
+    pub async fn relay(&self, batch_size: u64) -> Result<usize, SubscriberError> {
+        let events = self.outbox.find_unpublished(batch_size).await?;
+
+        for event in &events {
+            self.publisher.publish(event).await?;
+            self.outbox.mark_as_published(event.id).await?;
+        }
+
+        Ok(events.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::subscriber::event::{
+        SubscriberEvent,
+        SubscriberEventEnvelope,
+        SubscriberUnsubscribed,
+    };
+    use crate::subscriber::outbox::MockSubscriberEventOutbox;
+    use crate::subscriber::publisher::MockSubscriberEventPublisher;
+
+    use super::*;
+
+    fn generate_events(count: usize) -> Vec<SubscriberEventEnvelope> {
+        (0..count)
+            .map(|_| {
+                SubscriberEventEnvelope::new(SubscriberEvent::SubscriberUnsubscribed(
+                    SubscriberUnsubscribed::new(Uuid::new_v4()),
+                ))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn relaying_publishes_and_marks_unpublished_events_in_order() {
+        // given
+        let events = generate_events(2);
+        let mut sequence = mockall::Sequence::new();
+
+        let mut outbox = MockSubscriberEventOutbox::new();
+        let mut publisher = MockSubscriberEventPublisher::new();
+        let unpublished_events = events.clone();
+        outbox
+            .expect_find_unpublished()
+            .with(eq(10))
+            .once()
+            .returning(move |_| Ok(unpublished_events.clone()));
+        for event in &events {
+            let published_event = event.clone();
+            publisher
+                .expect_publish()
+                .withf(move |event| event == &published_event)
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(|_| Ok(()));
+            outbox
+                .expect_mark_as_published()
+                .with(eq(event.id))
+                .once()
+                .in_sequence(&mut sequence)
+                .returning(|_| Ok(()));
+        }
+
+        let relay = SubscriberEventRelay::new(outbox, publisher);
+
+        // when
+        let relayed = relay.relay(10).await.unwrap();
+
+        // then
+        assert_eq!(relayed, 2);
+    }
+
+    #[tokio::test]
+    async fn relaying_stops_without_marking_when_publishing_fails() {
+        // given
+        let events = generate_events(2);
+
+        let mut outbox = MockSubscriberEventOutbox::new();
+        let mut publisher = MockSubscriberEventPublisher::new();
+        outbox
+            .expect_find_unpublished()
+            .once()
+            .returning(move |_| Ok(events.clone()));
+        publisher.expect_publish().once().returning(|_| {
+            Err(SubscriberError::PublisherOperationFailed(anyhow::anyhow!(
+                "Some errors"
+            )))
+        });
+        outbox.expect_mark_as_published().never();
+
+        let relay = SubscriberEventRelay::new(outbox, publisher);
+
+        // when
+        let response = relay.relay(10).await;
+
+        // then
+        assert!(matches!(
+            response,
+            Err(SubscriberError::PublisherOperationFailed(_))
+        ));
+    }
+}
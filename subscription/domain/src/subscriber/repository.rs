@@ -1,5 +1,9 @@
 use std::future::Future;
 
+use chrono::{
+    DateTime,
+    Utc,
+};
 use uuid::Uuid;
 
 use crate::subscriber::error::SubscriberError;
@@ -15,12 +19,30 @@ pub trait SubscriberRepository: Send + Sync {
     // TODO: Learn more about 'static and check if it is valid here
     async fn modify<F, Fut>(&self, id: Uuid, modifier: F) -> Result<(), SubscriberError>
     where
-        F: Fn(Subscriber) -> Fut + Send + 'static,
+        F: Fn(Subscriber) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<Subscriber, SubscriberError>> + Send + 'static;
+    // Delete the subscriber along with its data, recording its pending events
+    async fn delete(&self, subscriber: &Subscriber) -> Result<(), SubscriberError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Subscriber>, SubscriberError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<Subscriber>, SubscriberError>;
     async fn find_by_status(
         &self,
         status: SubscriberStatus,
     ) -> Result<Vec<Subscriber>, SubscriberError>;
+    // Returns up to limit subscribers ordered by ID, starting after the given ID if any
+    async fn find_page_by_status(
+        &self,
+        status: SubscriberStatus,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<Subscriber>, SubscriberError>;
+    async fn count_unconfirmed_subscribed_before(
+        &self,
+        subscribed_before: DateTime<Utc>,
+    ) -> Result<u64, SubscriberError>;
+    // Returns the number of deleted subscribers, each of them is recorded as erased
+    async fn delete_unconfirmed_subscribed_before(
+        &self,
+        subscribed_before: DateTime<Utc>,
+    ) -> Result<u64, SubscriberError>;
 }
@@ -3,8 +3,21 @@ pub enum SubscriptionTokenError {
     #[error("Failed to issue a subscription token")]
     IssuanceFailed(#[source] anyhow::Error),
 
-    #[error("Subscription token {0} doesn't exist")]
-    SubscriptionTokenNotFound(String),
+    // the token itself is a secret, so it isn't included in errors
+    #[error("Subscription token doesn't exist")]
+    SubscriptionTokenNotFound,
+
+    #[error("Subscription token is expired")]
+    SubscriptionTokenExpired,
+
+    #[error("Subscription token is already used")]
+    SubscriptionTokenAlreadyUsed,
+
+    #[error("Subscription token is revoked by a newer one")]
+    SubscriptionTokenRevoked,
+
+    #[error("Subscription token's purpose is invalid")]
+    InvalidSubscriptionTokenPurpose,
 
     #[error("Failed to operate on repository")]
     RepositoryOperationFailed(#[source] anyhow::Error),
@@ -1,11 +1,23 @@
 use uuid::Uuid;
 
 use crate::subscription_token::error::SubscriptionTokenError;
-use crate::subscription_token::model::SubscriptionToken;
+use crate::subscription_token::model::{
+    SubscriptionToken,
+    SubscriptionTokenPurpose,
+};
 use crate::subscription_token::repository::SubscriptionTokenRepository;
 
 pub enum SubscriptionTokenCommand {
-    IssueSubscriptionToken { token: String, subscriber_id: Uuid },
+    IssueSubscriptionToken {
+        token: String,
+        subscriber_id: Uuid,
+        purpose: SubscriptionTokenPurpose,
+    },
+    // Consume the token so that it can't be used again
+    UseSubscriptionToken {
+        token: String,
+        purpose: SubscriptionTokenPurpose,
+    },
 }
 
 #[derive(Clone)]
@@ -32,9 +44,38 @@ where
             SubscriptionTokenCommand::IssueSubscriptionToken {
                 token,
                 subscriber_id,
+                purpose,
             } => {
-                let subscription_token = SubscriptionToken::new(token, subscriber_id);
-                self.repository.save(&subscription_token).await
+                // only the latest token for the purpose is valid
+                let older_subscription_tokens: Vec<SubscriptionToken> = self
+                    .repository
+                    .find_by_subscriber_id(subscriber_id)
+                    .await?
+                    .into_iter()
+                    .filter(|subscription_token| {
+                        subscription_token.purpose == purpose && subscription_token.is_active()
+                    })
+                    .map(|mut older_subscription_token| {
+                        older_subscription_token.revoke();
+                        older_subscription_token
+                    })
+                    .collect();
+
+                let subscription_token = SubscriptionToken::new(token, subscriber_id, purpose);
+                self.repository
+                    .reissue(&older_subscription_tokens, &subscription_token)
+                    .await
+            }
+            SubscriptionTokenCommand::UseSubscriptionToken { token, purpose } => {
+                let mut subscription_token = self
+                    .repository
+                    .find_by_token(&token)
+                    .await?
+                    .ok_or(SubscriptionTokenError::SubscriptionTokenNotFound)?;
+
+                subscription_token.verify(&purpose)?;
+                subscription_token.use_up();
+                self.repository.use_up(&subscription_token).await
             }
         }
     }
@@ -1,35 +1,267 @@
 use std::ops::Add;
 
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use chrono::{
     DateTime,
     Duration,
     Utc,
 };
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{
+    Digest,
+    Sha256,
+};
+use subtle::ConstantTimeEq;
 use uuid::Uuid;
 
+use crate::subscription_token::error::SubscriptionTokenError;
+
+// Only the digest of the token is kept, the token itself is known only to the subscriber
 #[derive(Debug)]
 pub struct SubscriptionToken {
-    pub token: String,
+    pub token_hash: String,
     pub subscriber_id: Uuid,
+    pub purpose: SubscriptionTokenPurpose,
     pub issued_at: DateTime<Utc>,
     pub expired_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
 }
 
 impl SubscriptionToken {
-    const EXPIRATION_DURATION_IN_HOURS: i64 = 1;
+    const TOKEN_LENGTH_IN_BYTES: usize = 32;
 
-    pub fn new(token: String, subscriber_id: Uuid) -> Self {
-        let expiration_duration = Duration::hours(Self::EXPIRATION_DURATION_IN_HOURS);
+    pub fn new(token: String, subscriber_id: Uuid, purpose: SubscriptionTokenPurpose) -> Self {
+        let issued_at = Utc::now();
 
         Self {
-            token,
+            token_hash: Self::hash(&token),
             subscriber_id,
-            issued_at: Utc::now(),
-            expired_at: Utc::now().add(expiration_duration),
+            expired_at: issued_at.add(purpose.expiration_duration()),
+            purpose,
+            issued_at,
+            used_at: None,
+            revoked_at: None,
         }
     }
 
+    // Neither used nor revoked yet, regardless of the expiration
+    pub fn is_active(&self) -> bool {
+        self.used_at.is_none() && self.revoked_at.is_none()
+    }
+
+    pub fn use_up(&mut self) {
+        self.used_at = Some(Utc::now());
+    }
+
+    pub fn revoke(&mut self) {
+        self.revoked_at = Some(Utc::now());
+    }
+
+    // Check the token is used for the purpose it was issued for and is still usable
+    // Token issued for another purpose is handled as not existing one
+    pub fn verify(&self, purpose: &SubscriptionTokenPurpose) -> Result<(), SubscriptionTokenError> {
+        if &self.purpose != purpose {
+            return Err(SubscriptionTokenError::SubscriptionTokenNotFound);
+        }
+        if self.used_at.is_some() {
+            return Err(SubscriptionTokenError::SubscriptionTokenAlreadyUsed);
+        }
+        if self.revoked_at.is_some() {
+            return Err(SubscriptionTokenError::SubscriptionTokenRevoked);
+        }
+        if self.expired_at <= Utc::now() {
+            return Err(SubscriptionTokenError::SubscriptionTokenExpired);
+        }
+
+        Ok(())
+    }
+
+    // Compare in constant time not to leak how much of the digest matched
+    pub fn matches(&self, token: &str) -> bool {
+        self.token_hash
+            .as_bytes()
+            .ct_eq(Self::hash(token).as_bytes())
+            .into()
+    }
+
+    // Hex encoded SHA-256 digest, which is what gets persisted and searched
+    pub fn hash(token: &str) -> String {
+        Sha256::digest(token.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    // 256 bits from the OS CSPRNG, encoded to be safe in URLs
     pub async fn generate_token() -> String {
-        Uuid::new_v4().to_string()
+        let mut bytes = [0u8; Self::TOKEN_LENGTH_IN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubscriptionTokenPurpose {
+    // confirming a subscription from the welcome message
+    Confirmation,
+    // viewing and updating a subscriber's profile from the magic link
+    ProfileManagement,
+}
+
+impl SubscriptionTokenPurpose {
+    const CONFIRMATION_EXPIRATION_DURATION_IN_MINUTES: i64 = 60;
+    const PROFILE_MANAGEMENT_EXPIRATION_DURATION_IN_MINUTES: i64 = 15;
+
+    pub fn expiration_duration(&self) -> Duration {
+        match self {
+            SubscriptionTokenPurpose::Confirmation => {
+                Duration::minutes(Self::CONFIRMATION_EXPIRATION_DURATION_IN_MINUTES)
+            }
+            SubscriptionTokenPurpose::ProfileManagement => {
+                Duration::minutes(Self::PROFILE_MANAGEMENT_EXPIRATION_DURATION_IN_MINUTES)
+            }
+        }
+    }
+
+    pub fn parse(s: String) -> Result<Self, SubscriptionTokenError> {
+        match s.as_str() {
+            "Confirmation" => Ok(Self::Confirmation),
+            "ProfileManagement" => Ok(Self::ProfileManagement),
+            _ => Err(SubscriptionTokenError::InvalidSubscriptionTokenPurpose),
+        }
+    }
+}
+
+impl AsRef<str> for SubscriptionTokenPurpose {
+    fn as_ref(&self) -> &str {
+        match self {
+            SubscriptionTokenPurpose::Confirmation => "Confirmation",
+            SubscriptionTokenPurpose::ProfileManagement => "ProfileManagement",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::{
+        assert_err,
+        assert_ok,
+    };
+
+    use super::*;
+
+    #[test]
+    fn profile_management_token_expires_earlier_than_confirmation_token() {
+        let confirmation_token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::Confirmation,
+        );
+        let profile_management_token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::ProfileManagement,
+        );
+
+        assert!(profile_management_token.expired_at < confirmation_token.expired_at);
+    }
+
+    #[test]
+    fn verifying_token_with_the_issued_purpose_succeeds() {
+        let token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::ProfileManagement,
+        );
+        assert_ok!(token.verify(&SubscriptionTokenPurpose::ProfileManagement));
+    }
+
+    #[test]
+    fn verifying_token_with_other_purpose_fails_as_not_found() {
+        let token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::ProfileManagement,
+        );
+
+        assert!(matches!(
+            token.verify(&SubscriptionTokenPurpose::Confirmation),
+            Err(SubscriptionTokenError::SubscriptionTokenNotFound)
+        ));
+    }
+
+    #[test]
+    fn verifying_expired_token_fails() {
+        let mut token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::ProfileManagement,
+        );
+        token.expired_at = Utc::now();
+
+        assert_err!(token.verify(&SubscriptionTokenPurpose::ProfileManagement));
+    }
+
+    #[test]
+    fn token_is_kept_only_as_its_digest() {
+        let token = SubscriptionToken::new(
+            "token".to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::Confirmation,
+        );
+
+        assert_eq!(
+            token.token_hash,
+            "3c469e9d6c5875d37a43f353d4f88e61fcf812c66eee3457465a40b0da4153e0"
+        );
+        assert!(token.matches("token"));
+        assert!(!token.matches("other-token"));
+    }
+
+    #[tokio::test]
+    async fn generated_token_has_256_bits_encoded_in_base64url() {
+        let token = SubscriptionToken::generate_token().await;
+
+        assert_eq!(token.len(), 43);
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_ne!(token, SubscriptionToken::generate_token().await);
+    }
+
+    #[test]
+    fn verifying_used_token_fails_as_already_used() {
+        let mut token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::Confirmation,
+        );
+        token.use_up();
+
+        assert!(!token.is_active());
+        assert!(matches!(
+            token.verify(&SubscriptionTokenPurpose::Confirmation),
+            Err(SubscriptionTokenError::SubscriptionTokenAlreadyUsed)
+        ));
+    }
+
+    #[test]
+    fn verifying_revoked_token_fails_as_revoked() {
+        let mut token = SubscriptionToken::new(
+            Uuid::new_v4().to_string(),
+            Uuid::new_v4(),
+            SubscriptionTokenPurpose::Confirmation,
+        );
+        token.revoke();
+
+        assert!(!token.is_active());
+        assert!(matches!(
+            token.verify(&SubscriptionTokenPurpose::Confirmation),
+            Err(SubscriptionTokenError::SubscriptionTokenRevoked)
+        ));
     }
 }
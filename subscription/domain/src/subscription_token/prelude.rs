@@ -3,7 +3,10 @@ pub use crate::subscription_token::executor::{
     SubscriptionTokenCommand,
     SubscriptionTokenCommandExecutor,
 };
-pub use crate::subscription_token::model::SubscriptionToken;
+pub use crate::subscription_token::model::{
+    SubscriptionToken,
+    SubscriptionTokenPurpose,
+};
 pub use crate::subscription_token::reader::{
     SubscriptionTokenQuery,
     SubscriptionTokenQueryReader,
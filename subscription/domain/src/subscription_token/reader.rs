@@ -1,9 +1,19 @@
 use crate::subscription_token::error::SubscriptionTokenError;
-use crate::subscription_token::model::SubscriptionToken;
+use crate::subscription_token::model::{
+    SubscriptionToken,
+    SubscriptionTokenPurpose,
+};
 use crate::subscription_token::repository::SubscriptionTokenRepository;
 
 pub enum SubscriptionTokenQuery {
-    InquireSubscriptionTokenByToken { token: String },
+    InquireSubscriptionTokenByToken {
+        token: String,
+    },
+    // Only token issued for the purpose and not expired yet is returned
+    InquireValidSubscriptionToken {
+        token: String,
+        purpose: SubscriptionTokenPurpose,
+    },
 }
 
 #[derive(Clone)]
@@ -31,7 +41,17 @@ where
                 .repository
                 .find_by_token(&token)
                 .await?
-                .ok_or(SubscriptionTokenError::SubscriptionTokenNotFound(token)),
+                .ok_or(SubscriptionTokenError::SubscriptionTokenNotFound),
+            SubscriptionTokenQuery::InquireValidSubscriptionToken { token, purpose } => {
+                let subscription_token = self
+                    .repository
+                    .find_by_token(&token)
+                    .await?
+                    .ok_or(SubscriptionTokenError::SubscriptionTokenNotFound)?;
+
+                subscription_token.verify(&purpose)?;
+                Ok(subscription_token)
+            }
         }
     }
 }
@@ -1,3 +1,7 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use uuid::Uuid;
 
 use crate::subscription_token::error::SubscriptionTokenError;
@@ -10,6 +14,20 @@ pub trait SubscriptionTokenRepository: Send + Sync {
         &self,
         subscription_token: &SubscriptionToken,
     ) -> Result<(), SubscriptionTokenError>;
+    // Persist used_at of the used up token only if it is still active, fails with
+    // SubscriptionTokenAlreadyUsed when another request used it first
+    // or with SubscriptionTokenRevoked when a newer token replaced it in the meantime
+    async fn use_up(
+        &self,
+        subscription_token: &SubscriptionToken,
+    ) -> Result<(), SubscriptionTokenError>;
+    // Persist revoked_at of the revoked tokens along with the new token, or none of them,
+    // already used ones are kept as they are
+    async fn reissue(
+        &self,
+        revoked_subscription_tokens: &[SubscriptionToken],
+        subscription_token: &SubscriptionToken,
+    ) -> Result<(), SubscriptionTokenError>;
     async fn find_by_token(
         &self,
         token: &str,
@@ -17,5 +35,14 @@ pub trait SubscriptionTokenRepository: Send + Sync {
     async fn find_by_subscriber_id(
         &self,
         subscriber_id: Uuid,
-    ) -> Result<Option<SubscriptionToken>, SubscriptionTokenError>;
+    ) -> Result<Vec<SubscriptionToken>, SubscriptionTokenError>;
+    async fn count_expired_before(
+        &self,
+        expired_before: DateTime<Utc>,
+    ) -> Result<u64, SubscriptionTokenError>;
+    // Returns the number of deleted tokens
+    async fn delete_expired_before(
+        &self,
+        expired_before: DateTime<Utc>,
+    ) -> Result<u64, SubscriptionTokenError>;
 }
@@ -0,0 +1,23 @@
+#[derive(thiserror::Error, Debug)]
+pub enum TopicError {
+    #[error("Topic's slug is invalid")]
+    InvalidTopicSlug,
+
+    #[error("Topic's name is invalid")]
+    InvalidTopicName,
+
+    #[error("Topic's visibility is invalid")]
+    InvalidTopicVisibility,
+
+    #[error("Topic ({0}) doesn't exist")]
+    TopicNotFound(String),
+
+    #[error("Topic ({0}) already exists")]
+    DuplicateTopicSlug(String),
+
+    #[error("Failed to operate on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+
+    #[error("Failed unexpectedly")]
+    Unexpected(#[source] anyhow::Error),
+}
@@ -0,0 +1,56 @@
+use uuid::Uuid;
+
+use crate::topic::error::TopicError;
+use crate::topic::model::{
+    Topic,
+    TopicName,
+    TopicSlug,
+    TopicVisibility,
+};
+use crate::topic::repository::TopicRepository;
+
+pub enum TopicCommand {
+    CreateTopic {
+        id: Uuid,
+        slug: String,
+        name: String,
+        description: String,
+        visibility: String,
+    },
+}
+
+#[derive(Clone)]
+pub struct TopicCommandExecutor<R>
+where
+    R: TopicRepository,
+{
+    repository: R,
+}
+
+impl<R> TopicCommandExecutor<R>
+where
+    R: TopicRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, command: TopicCommand) -> Result<(), TopicError> {
+        match command {
+            TopicCommand::CreateTopic {
+                id,
+                slug,
+                name,
+                description,
+                visibility,
+            } => {
+                let slug = TopicSlug::parse(slug)?;
+                let name = TopicName::parse(name)?;
+                let visibility = TopicVisibility::parse(visibility)?;
+                let topic = Topic::new(id, slug, name, description, visibility);
+
+                self.repository.save(&topic).await
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod error;
+mod executor;
+mod model;
+pub mod prelude;
+mod reader;
+mod repository;
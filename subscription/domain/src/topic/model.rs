@@ -0,0 +1,172 @@
+use uuid::Uuid;
+
+use crate::topic::error::TopicError;
+
+// A newsletter which subscribers can join separately
+#[derive(Debug)]
+pub struct Topic {
+    pub id: Uuid,
+    pub slug: TopicSlug,
+    pub name: TopicName,
+    pub description: String,
+    pub visibility: TopicVisibility,
+}
+
+impl Topic {
+    pub fn new(
+        id: Uuid,
+        slug: TopicSlug,
+        name: TopicName,
+        description: String,
+        visibility: TopicVisibility,
+    ) -> Self {
+        Self {
+            id,
+            slug,
+            name,
+            description,
+            visibility,
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.visibility == TopicVisibility::Public
+    }
+}
+
+// Identifies a topic in URLs and forms, e.g. rust-weekly
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicSlug(String);
+
+impl TopicSlug {
+    const SLUG_MAX_LENGTH: usize = 64;
+
+    pub fn parse(s: String) -> Result<Self, TopicError> {
+        let is_empty = s.is_empty();
+        let is_too_long = s.len() > Self::SLUG_MAX_LENGTH;
+        let contains_invalid_characters = s
+            .chars()
+            .any(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'));
+        let is_hyphen_at_edge = s.starts_with('-') || s.ends_with('-');
+
+        if is_empty || is_too_long || contains_invalid_characters || is_hyphen_at_edge {
+            Err(TopicError::InvalidTopicSlug)
+        } else {
+            Ok(Self(s))
+        }
+    }
+}
+
+impl AsRef<str> for TopicSlug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicName(String);
+
+impl TopicName {
+    const NAME_MAX_LENGTH: usize = 256;
+
+    pub fn parse(s: String) -> Result<Self, TopicError> {
+        let is_empty_or_whitespace_only = s.trim().is_empty();
+        let is_too_long = s.len() > Self::NAME_MAX_LENGTH;
+
+        if is_empty_or_whitespace_only || is_too_long {
+            Err(TopicError::InvalidTopicName)
+        } else {
+            Ok(Self(s))
+        }
+    }
+}
+
+impl AsRef<str> for TopicName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+// Private topics are hidden from the public listing and can't be joined on subscription
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicVisibility {
+    Public,
+    Private,
+}
+
+impl TopicVisibility {
+    pub fn parse(s: String) -> Result<Self, TopicError> {
+        match s.as_str() {
+            "Public" => Ok(Self::Public),
+            "Private" => Ok(Self::Private),
+            _ => Err(TopicError::InvalidTopicVisibility),
+        }
+    }
+}
+
+impl AsRef<str> for TopicVisibility {
+    fn as_ref(&self) -> &str {
+        match self {
+            TopicVisibility::Public => "Public",
+            TopicVisibility::Private => "Private",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use claims::{
+        assert_err,
+        assert_ok,
+    };
+
+    use super::*;
+
+    #[test]
+    fn lowercase_alphanumeric_slug_with_hyphens_is_valid() {
+        assert_ok!(TopicSlug::parse("rust-weekly-2024".to_string()));
+    }
+
+    #[test]
+    fn empty_slug_is_rejected() {
+        assert_err!(TopicSlug::parse("".to_string()));
+    }
+
+    #[test]
+    fn slug_with_uppercase_or_spaces_is_rejected() {
+        assert_err!(TopicSlug::parse("Rust".to_string()));
+        assert_err!(TopicSlug::parse("rust weekly".to_string()));
+    }
+
+    #[test]
+    fn slug_starting_or_ending_with_hyphen_is_rejected() {
+        assert_err!(TopicSlug::parse("-rust".to_string()));
+        assert_err!(TopicSlug::parse("rust-".to_string()));
+    }
+
+    #[test]
+    fn too_long_slug_is_rejected() {
+        assert_err!(TopicSlug::parse("a".repeat(65)));
+    }
+
+    #[test]
+    fn whitespace_only_name_is_rejected() {
+        assert_err!(TopicName::parse("  ".to_string()));
+    }
+
+    #[test]
+    fn only_public_topic_is_public() {
+        let topic = |visibility| {
+            Topic::new(
+                Uuid::new_v4(),
+                TopicSlug::parse("rust".to_string()).unwrap(),
+                TopicName::parse("Rust".to_string()).unwrap(),
+                "".to_string(),
+                visibility,
+            )
+        };
+
+        assert!(topic(TopicVisibility::Public).is_public());
+        assert!(!topic(TopicVisibility::Private).is_public());
+    }
+}
@@ -0,0 +1,19 @@
+pub use crate::topic::error::TopicError;
+pub use crate::topic::executor::{
+    TopicCommand,
+    TopicCommandExecutor,
+};
+pub use crate::topic::model::{
+    Topic,
+    TopicName,
+    TopicSlug,
+    TopicVisibility,
+};
+pub use crate::topic::reader::{
+    TopicQuery,
+    TopicQueryReader,
+};
+pub use crate::topic::repository::{
+    MockTopicRepository,
+    TopicRepository,
+};
@@ -0,0 +1,58 @@
+use crate::topic::error::TopicError;
+use crate::topic::model::Topic;
+use crate::topic::repository::TopicRepository;
+
+pub enum TopicQuery {
+    InquirePublicTopics,
+    // Single topic query returns one topic or TopicNotFound error
+    InquireTopicBySlug { slug: String },
+    // Topics joinable on subscription, fails with TopicNotFound if any of them isn't public
+    InquirePublicTopicsBySlugs { slugs: Vec<String> },
+}
+
+#[derive(Clone)]
+pub struct TopicQueryReader<R>
+where
+    R: TopicRepository,
+{
+    repository: R,
+}
+
+impl<R> TopicQueryReader<R>
+where
+    R: TopicRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn read(&self, query: TopicQuery) -> Result<Vec<Topic>, TopicError> {
+        match query {
+            TopicQuery::InquirePublicTopics => Ok(self
+                .repository
+                .find_all()
+                .await?
+                .into_iter()
+                .filter(Topic::is_public)
+                .collect()),
+            TopicQuery::InquireTopicBySlug { slug } => self
+                .repository
+                .find_by_slug(&slug)
+                .await?
+                .map(|topic| vec![topic])
+                .ok_or(TopicError::TopicNotFound(slug)),
+            TopicQuery::InquirePublicTopicsBySlugs { slugs } => {
+                let mut topics = Vec::with_capacity(slugs.len());
+                for slug in slugs {
+                    // private topic is handled as not existing one not to expose it
+                    match self.repository.find_by_slug(&slug).await? {
+                        Some(topic) if topic.is_public() => topics.push(topic),
+                        _ => return Err(TopicError::TopicNotFound(slug)),
+                    }
+                }
+
+                Ok(topics)
+            }
+        }
+    }
+}
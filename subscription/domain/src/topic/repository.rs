@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use crate::topic::error::TopicError;
+use crate::topic::model::Topic;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TopicRepository: Send + Sync {
+    async fn save(&self, topic: &Topic) -> Result<(), TopicError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Topic>, TopicError>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<Topic>, TopicError>;
+    async fn find_all(&self) -> Result<Vec<Topic>, TopicError>;
+}
@@ -0,0 +1,11 @@
+#[derive(thiserror::Error, Debug)]
+pub enum TopicMembershipError {
+    #[error("Topic membership's status is invalid")]
+    InvalidTopicMembershipStatus,
+
+    #[error("Failed to operate on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+
+    #[error("Failed unexpectedly")]
+    Unexpected(#[source] anyhow::Error),
+}
@@ -0,0 +1,80 @@
+use uuid::Uuid;
+
+use crate::topic_membership::error::TopicMembershipError;
+use crate::topic_membership::model::{
+    TopicMembership,
+    TopicMembershipStatus,
+};
+use crate::topic_membership::repository::TopicMembershipRepository;
+
+pub enum TopicMembershipCommand {
+    // Topics are expected to be checked to be joinable in advance
+    JoinTopics {
+        subscriber_id: Uuid,
+        topic_ids: Vec<Uuid>,
+    },
+    ConfirmTopicMemberships {
+        subscriber_id: Uuid,
+    },
+}
+
+#[derive(Clone)]
+pub struct TopicMembershipCommandExecutor<R>
+where
+    R: TopicMembershipRepository,
+{
+    repository: R,
+}
+
+impl<R> TopicMembershipCommandExecutor<R>
+where
+    R: TopicMembershipRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: TopicMembershipCommand,
+    ) -> Result<(), TopicMembershipError> {
+        match command {
+            TopicMembershipCommand::JoinTopics {
+                subscriber_id,
+                topic_ids,
+            } => {
+                let joined_topic_ids: Vec<Uuid> = self
+                    .repository
+                    .find_by_subscriber_id(subscriber_id)
+                    .await?
+                    .into_iter()
+                    .map(|topic_membership| topic_membership.topic_id)
+                    .collect();
+
+                for topic_id in topic_ids {
+                    if joined_topic_ids.contains(&topic_id) {
+                        continue;
+                    }
+                    let topic_membership = TopicMembership::new(topic_id, subscriber_id);
+                    self.repository.save(&topic_membership).await?;
+                }
+
+                Ok(())
+            }
+            TopicMembershipCommand::ConfirmTopicMemberships { subscriber_id } => {
+                let topic_memberships =
+                    self.repository.find_by_subscriber_id(subscriber_id).await?;
+
+                for mut topic_membership in topic_memberships {
+                    if topic_membership.status != TopicMembershipStatus::Pending {
+                        continue;
+                    }
+                    topic_membership.confirm();
+                    self.repository.save(&topic_membership).await?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use uuid::Uuid;
+
+use crate::topic_membership::error::TopicMembershipError;
+
+// A subscriber's membership of a topic, which has its own status per topic
+#[derive(Debug)]
+pub struct TopicMembership {
+    pub topic_id: Uuid,
+    pub subscriber_id: Uuid,
+    pub status: TopicMembershipStatus,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl TopicMembership {
+    pub fn new(topic_id: Uuid, subscriber_id: Uuid) -> Self {
+        Self {
+            topic_id,
+            subscriber_id,
+            status: TopicMembershipStatus::Pending,
+            joined_at: Utc::now(),
+        }
+    }
+
+    pub fn confirm(&mut self) {
+        self.status = TopicMembershipStatus::Confirmed;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopicMembershipStatus {
+    // joined on subscription, waiting for the subscriber to confirm it
+    Pending,
+    Confirmed,
+}
+
+impl TopicMembershipStatus {
+    pub fn parse(s: String) -> Result<Self, TopicMembershipError> {
+        match s.as_str() {
+            "Pending" => Ok(Self::Pending),
+            "Confirmed" => Ok(Self::Confirmed),
+            _ => Err(TopicMembershipError::InvalidTopicMembershipStatus),
+        }
+    }
+}
+
+impl AsRef<str> for TopicMembershipStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            TopicMembershipStatus::Pending => "Pending",
+            TopicMembershipStatus::Confirmed => "Confirmed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_membership_is_pending_until_confirmed() {
+        let mut membership = TopicMembership::new(Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(membership.status, TopicMembershipStatus::Pending);
+
+        membership.confirm();
+        assert_eq!(membership.status, TopicMembershipStatus::Confirmed);
+    }
+}
@@ -0,0 +1,17 @@
+pub use crate::topic_membership::error::TopicMembershipError;
+pub use crate::topic_membership::executor::{
+    TopicMembershipCommand,
+    TopicMembershipCommandExecutor,
+};
+pub use crate::topic_membership::model::{
+    TopicMembership,
+    TopicMembershipStatus,
+};
+pub use crate::topic_membership::reader::{
+    TopicMembershipQuery,
+    TopicMembershipQueryReader,
+};
+pub use crate::topic_membership::repository::{
+    MockTopicMembershipRepository,
+    TopicMembershipRepository,
+};
@@ -0,0 +1,40 @@
+use uuid::Uuid;
+
+use crate::subscriber::prelude::Subscriber;
+use crate::topic_membership::error::TopicMembershipError;
+use crate::topic_membership::model::TopicMembershipStatus;
+use crate::topic_membership::repository::TopicMembershipRepository;
+
+pub enum TopicMembershipQuery {
+    InquireConfirmedSubscribersOfTopic { topic_id: Uuid },
+}
+
+#[derive(Clone)]
+pub struct TopicMembershipQueryReader<R>
+where
+    R: TopicMembershipRepository,
+{
+    repository: R,
+}
+
+impl<R> TopicMembershipQueryReader<R>
+where
+    R: TopicMembershipRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn read(
+        &self,
+        query: TopicMembershipQuery,
+    ) -> Result<Vec<Subscriber>, TopicMembershipError> {
+        match query {
+            TopicMembershipQuery::InquireConfirmedSubscribersOfTopic { topic_id } => {
+                self.repository
+                    .find_subscribers_by_topic_id(topic_id, TopicMembershipStatus::Confirmed)
+                    .await
+            }
+        }
+    }
+}
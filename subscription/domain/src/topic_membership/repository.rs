@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+use crate::subscriber::prelude::Subscriber;
+use crate::topic_membership::error::TopicMembershipError;
+use crate::topic_membership::model::{
+    TopicMembership,
+    TopicMembershipStatus,
+};
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TopicMembershipRepository: Send + Sync {
+    async fn save(&self, topic_membership: &TopicMembership) -> Result<(), TopicMembershipError>;
+    async fn find_by_subscriber_id(
+        &self,
+        subscriber_id: Uuid,
+    ) -> Result<Vec<TopicMembership>, TopicMembershipError>;
+    async fn find_subscribers_by_topic_id(
+        &self,
+        topic_id: Uuid,
+        status: TopicMembershipStatus,
+    ) -> Result<Vec<Subscriber>, TopicMembershipError>;
+}
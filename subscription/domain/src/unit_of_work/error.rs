@@ -0,0 +1,8 @@
+#[derive(thiserror::Error, Debug)]
+pub enum UnitOfWorkError {
+    #[error("Failed to operate on repository")]
+    RepositoryOperationFailed(#[source] anyhow::Error),
+
+    #[error("Failed unexpectedly")]
+    Unexpected(#[source] anyhow::Error),
+}
@@ -0,0 +1,7 @@
+pub use crate::unit_of_work::error::UnitOfWorkError;
+pub use crate::unit_of_work::repository::{
+    MockUnitOfWork,
+    MockUnitOfWorkFactory,
+    UnitOfWork,
+    UnitOfWorkFactory,
+};
@@ -0,0 +1,49 @@
+use crate::subscriber::prelude::{
+    MockSubscriberRepository,
+    SubscriberRepository,
+};
+use crate::subscription_token::prelude::{
+    MockSubscriptionTokenRepository,
+    SubscriptionTokenRepository,
+};
+use crate::topic_membership::prelude::{
+    MockTopicMembershipRepository,
+    TopicMembershipRepository,
+};
+use crate::unit_of_work::error::UnitOfWorkError;
+
+// Repositories handed out by a unit of work share its transaction,
+// so the changes made through them are persisted all together or not at all
+#[mockall::automock(
+    type SubscriberRepository<'a> = MockSubscriberRepository;
+    type SubscriptionTokenRepository<'a> = MockSubscriptionTokenRepository;
+    type TopicMembershipRepository<'a> = MockTopicMembershipRepository;
+)]
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    type SubscriberRepository<'a>: SubscriberRepository
+    where
+        Self: 'a;
+    type SubscriptionTokenRepository<'a>: SubscriptionTokenRepository
+    where
+        Self: 'a;
+    type TopicMembershipRepository<'a>: TopicMembershipRepository
+    where
+        Self: 'a;
+
+    // Repositories handed out borrow the unit of work, so it can't be committed while in use
+    fn subscriber_repository(&self) -> Self::SubscriberRepository<'_>;
+    fn subscription_token_repository(&self) -> Self::SubscriptionTokenRepository<'_>;
+    fn topic_membership_repository(&self) -> Self::TopicMembershipRepository<'_>;
+    async fn commit(self) -> Result<(), UnitOfWorkError>;
+    // Dropping a unit of work without committing rolls it back as well
+    async fn rollback(self) -> Result<(), UnitOfWorkError>;
+}
+
+#[mockall::automock(type UnitOfWork = MockUnitOfWork;)]
+#[async_trait::async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    type UnitOfWork: UnitOfWork;
+
+    async fn begin(&self) -> Result<Self::UnitOfWork, UnitOfWorkError>;
+}
@@ -0,0 +1,3 @@
+pub mod prelude;
+mod subscriber_event_in_memory_publisher;
+mod subscriber_event_postgres_publisher;
@@ -0,0 +1,2 @@
+pub use crate::subscriber_event_in_memory_publisher::SubscriberEventInMemoryPublisher;
+pub use crate::subscriber_event_postgres_publisher::SubscriberEventPostgresPublisher;
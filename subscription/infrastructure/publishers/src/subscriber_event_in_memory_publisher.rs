@@ -0,0 +1,41 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use domain::prelude::{
+    SubscriberError,
+    SubscriberEventEnvelope,
+    SubscriberEventPublisher,
+};
+
+// Keeps published events in memory, clones share them so that tests can check what is published
+#[derive(Clone, Default)]
+pub struct SubscriberEventInMemoryPublisher {
+    events: Arc<Mutex<Vec<SubscriberEventEnvelope>>>,
+}
+
+impl SubscriberEventInMemoryPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn published_events(&self) -> Vec<SubscriberEventEnvelope> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriberEventPublisher for SubscriberEventInMemoryPublisher {
+    #[tracing::instrument(name = "Publishing subscriber event in memory", skip(self))]
+    async fn publish(&self, event: &SubscriberEventEnvelope) -> Result<(), SubscriberError> {
+        let mut events = self.events.lock().unwrap();
+
+        // the same event is published only once, like the other publishers
+        if !events.iter().any(|published| published.id == event.id) {
+            events.push(event.clone());
+        }
+
+        Ok(())
+    }
+}
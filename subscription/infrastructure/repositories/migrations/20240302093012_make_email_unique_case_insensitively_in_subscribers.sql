@@ -0,0 +1,32 @@
+-- Report all subscribers sharing an email case-insensitively at once,
+-- because they block creating the case-insensitive unique index below
+DO $$
+DECLARE
+    conflict RECORD;
+    conflict_count INTEGER := 0;
+BEGIN
+    FOR conflict IN
+        SELECT LOWER(TRIM(email)) AS email, ARRAY_AGG(id ORDER BY id) AS ids
+        FROM subscribers
+        GROUP BY LOWER(TRIM(email))
+        HAVING COUNT(*) > 1
+    LOOP
+        conflict_count := conflict_count + 1;
+        RAISE WARNING 'Email % is shared by subscribers %', conflict.email, conflict.ids;
+    END LOOP;
+
+    IF conflict_count > 0 THEN
+        RAISE EXCEPTION '% emails are duplicated case-insensitively, merge or remove the subscribers reported above', conflict_count;
+    END IF;
+END $$;
+
+-- Canonicalize existing emails in the same way as SubscriberEmail::parse, which splits on the last '@'
+-- since a quoted local part can contain '@' as well
+-- Internationalized domains can't be converted to punycode here, but they are still unique by the index
+UPDATE subscribers
+SET email = REGEXP_REPLACE(TRIM(email), '@[^@]*$', '') || '@' || LOWER(SUBSTRING(TRIM(email) FROM '@([^@]*)$'))
+WHERE email LIKE '%@%'
+    AND email <> REGEXP_REPLACE(TRIM(email), '@[^@]*$', '') || '@' || LOWER(SUBSTRING(TRIM(email) FROM '@([^@]*)$'));
+
+ALTER TABLE subscribers DROP CONSTRAINT subscribers_email_key;
+CREATE UNIQUE INDEX subscribers_email_lower_key ON subscribers (LOWER(email));
@@ -0,0 +1 @@
+ALTER TABLE subscribers ADD COLUMN preferences JSONB NOT NULL DEFAULT '{}';
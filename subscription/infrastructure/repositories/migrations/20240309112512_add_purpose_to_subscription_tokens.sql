@@ -0,0 +1,2 @@
+-- All tokens issued so far are for confirming subscriptions
+ALTER TABLE subscription_tokens ADD COLUMN purpose TEXT NOT NULL DEFAULT 'Confirmation';
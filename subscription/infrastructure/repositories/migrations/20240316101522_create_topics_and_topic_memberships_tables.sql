@@ -0,0 +1,18 @@
+CREATE TABLE topics (
+    id UUID PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    visibility TEXT NOT NULL
+);
+
+CREATE TABLE topic_memberships (
+    topic_id UUID NOT NULL REFERENCES topics (id) ON DELETE CASCADE,
+    subscriber_id UUID NOT NULL REFERENCES subscribers (id) ON DELETE CASCADE,
+    status TEXT NOT NULL,
+    joined_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (topic_id, subscriber_id)
+);
+
+-- the primary key covers lookups by topic, but not by subscriber
+CREATE INDEX topic_memberships_subscriber_id_idx ON topic_memberships (subscriber_id);
@@ -0,0 +1,9 @@
+-- Only SHA-256 digests of tokens are stored from now on
+-- Existing plaintext tokens are hashed in place, so links already sent keep working
+ALTER TABLE subscription_tokens ADD COLUMN token_hash TEXT;
+
+UPDATE subscription_tokens SET token_hash = encode(sha256(convert_to(token, 'UTF8')), 'hex');
+
+ALTER TABLE subscription_tokens ALTER COLUMN token_hash SET NOT NULL;
+ALTER TABLE subscription_tokens ADD CONSTRAINT subscription_tokens_token_hash_key UNIQUE (token_hash);
+ALTER TABLE subscription_tokens DROP COLUMN token;
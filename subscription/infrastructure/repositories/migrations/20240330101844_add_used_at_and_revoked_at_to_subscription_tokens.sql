@@ -0,0 +1,2 @@
+ALTER TABLE subscription_tokens ADD COLUMN used_at TIMESTAMP WITH TIME ZONE;
+ALTER TABLE subscription_tokens ADD COLUMN revoked_at TIMESTAMP WITH TIME ZONE;
@@ -0,0 +1,7 @@
+-- Existing subscribers are treated as subscribed now, so none of them is purged right away
+ALTER TABLE subscribers ADD COLUMN subscribed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now();
+
+-- Support purging expired tokens and stale unconfirmed subscribers, and lookups by subscriber
+CREATE INDEX subscription_tokens_expired_at_idx ON subscription_tokens (expired_at);
+CREATE INDEX subscription_tokens_subscriber_id_idx ON subscription_tokens (subscriber_id);
+CREATE INDEX subscribers_unconfirmed_subscribed_at_idx ON subscribers (subscribed_at) WHERE status = 'Unconfirmed';
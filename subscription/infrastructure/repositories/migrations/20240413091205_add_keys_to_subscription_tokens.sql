@@ -0,0 +1,36 @@
+-- Tokens whose subscriber no longer exists block the foreign key below
+-- They are kept aside for inspection and reported before being removed
+CREATE TABLE orphaned_subscription_tokens AS
+SELECT subscription_tokens.*, now() AS orphaned_at
+FROM subscription_tokens
+WHERE NOT EXISTS (
+    SELECT 1 FROM subscribers WHERE subscribers.id = subscription_tokens.subscriber_id
+);
+
+DO $$
+DECLARE
+    orphan RECORD;
+BEGIN
+    FOR orphan IN
+        SELECT subscriber_id, count(*) AS tokens
+        FROM orphaned_subscription_tokens
+        GROUP BY subscriber_id
+    LOOP
+        RAISE WARNING 'Removing % orphaned subscription tokens of missing subscriber %',
+            orphan.tokens, orphan.subscriber_id;
+    END LOOP;
+END $$;
+
+DELETE FROM subscription_tokens
+WHERE NOT EXISTS (
+    SELECT 1 FROM subscribers WHERE subscribers.id = subscription_tokens.subscriber_id
+);
+
+-- The digest already identifies a token, so it becomes the primary key
+ALTER TABLE subscription_tokens DROP CONSTRAINT subscription_tokens_token_hash_key;
+ALTER TABLE subscription_tokens ADD CONSTRAINT subscription_tokens_pkey PRIMARY KEY (token_hash);
+
+-- Tokens go away along with their subscriber, subscription_tokens_subscriber_id_idx covers the lookup
+ALTER TABLE subscription_tokens
+    ADD CONSTRAINT subscription_tokens_subscriber_id_fkey
+    FOREIGN KEY (subscriber_id) REFERENCES subscribers (id) ON DELETE CASCADE;
@@ -0,0 +1,2 @@
+-- Incremented on every update, so concurrent modifications can detect each other
+ALTER TABLE subscribers ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,14 @@
+-- Events recorded in the same transaction as subscriber changes, waiting to be published
+-- Not referencing subscribers, since events of erased subscribers still have to be published
+CREATE TABLE subscriber_event_outbox (
+    id UUID PRIMARY KEY,
+    sequence BIGSERIAL NOT NULL UNIQUE,
+    subscriber_id UUID NOT NULL,
+    event_type TEXT NOT NULL,
+    schema_version INTEGER NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    published_at TIMESTAMP WITH TIME ZONE NULL
+);
+
+CREATE INDEX subscriber_event_outbox_unpublished_idx ON subscriber_event_outbox (sequence) WHERE published_at IS NULL;
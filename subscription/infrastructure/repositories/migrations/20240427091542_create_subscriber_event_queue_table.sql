@@ -0,0 +1,17 @@
+-- Queue of published subscriber events, which is the broker of the Postgres queue publisher
+-- Consumers lease a message by pushing visible_at forward, and acknowledge or dead-letter it
+CREATE TABLE subscriber_event_queue (
+    id UUID PRIMARY KEY,
+    sequence BIGSERIAL NOT NULL UNIQUE,
+    event_type TEXT NOT NULL,
+    schema_version INTEGER NOT NULL,
+    payload JSONB NOT NULL,
+    enqueued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    visible_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT NULL,
+    acknowledged_at TIMESTAMP WITH TIME ZONE NULL,
+    dead_lettered_at TIMESTAMP WITH TIME ZONE NULL
+);
+
+CREATE INDEX subscriber_event_queue_pending_idx ON subscriber_event_queue (sequence) WHERE acknowledged_at IS NULL AND dead_lettered_at IS NULL;
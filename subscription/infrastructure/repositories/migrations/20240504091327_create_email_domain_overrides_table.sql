@@ -0,0 +1,6 @@
+-- Runtime overrides of the email domain policy, re-read by every replica on reloading the policy
+CREATE TABLE email_domain_overrides (
+    domain TEXT PRIMARY KEY,
+    rule TEXT NOT NULL,
+    overridden_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
@@ -0,0 +1,7 @@
+-- Messages with the same ordering key, the ID of the subscriber, are received one at a time in
+-- their sequence, so that consumers don't apply events of a subscriber out of order
+ALTER TABLE subscriber_event_queue ADD COLUMN ordering_key TEXT NULL;
+
+UPDATE subscriber_event_queue SET ordering_key = payload -> 'Data' ->> 'SubscriberId';
+
+CREATE INDEX subscriber_event_queue_ordering_idx ON subscriber_event_queue (ordering_key, sequence) WHERE acknowledged_at IS NULL AND dead_lettered_at IS NULL;
@@ -1,3 +1,8 @@
 pub mod prelude;
+mod sea_orm_unit_of_work;
+mod subscriber_email_domain_override_sea_orm_repository;
+mod subscriber_event_outbox_sea_orm_repository;
 mod subscriber_sea_orm_repository;
 mod subscription_token_sea_orm_repository;
+mod topic_membership_sea_orm_repository;
+mod topic_sea_orm_repository;
@@ -1,7 +1,16 @@
 pub use sea_orm::{
     ConnectOptions as DatabaseConnectionOptions,
     Database as DatabaseConnection,
+    DatabaseConnection as DatabaseConnectionPool,
 };
 
+pub use crate::sea_orm_unit_of_work::{
+    SeaOrmUnitOfWork,
+    SeaOrmUnitOfWorkFactory,
+};
+pub use crate::subscriber_email_domain_override_sea_orm_repository::SubscriberEmailDomainOverrideSeaOrmRepository;
+pub use crate::subscriber_event_outbox_sea_orm_repository::SubscriberEventOutboxSeaOrmRepository;
 pub use crate::subscriber_sea_orm_repository::SubscriberSeaOrmRepository;
 pub use crate::subscription_token_sea_orm_repository::SubscriptionTokenSeaOrmRepository;
+pub use crate::topic_membership_sea_orm_repository::TopicMembershipSeaOrmRepository;
+pub use crate::topic_sea_orm_repository::TopicSeaOrmRepository;
@@ -1,97 +1,295 @@
+use std::sync::Arc;
+
 use axum::extract::FromRef;
 
 use domain::prelude::{
     SubscriberCommandExecutor,
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+    SubscriberEmailDomainPolicy,
     SubscriberMessenger,
     SubscriberQueryReader,
     SubscriberRepository,
     SubscriptionTokenCommandExecutor,
     SubscriptionTokenQueryReader,
     SubscriptionTokenRepository,
+    TopicCommandExecutor,
+    TopicMembershipCommandExecutor,
+    TopicMembershipQueryReader,
+    TopicMembershipRepository,
+    TopicQueryReader,
+    TopicRepository,
+    UnitOfWorkFactory,
 };
 
+use crate::guards::admin_token::AdminToken;
+use crate::guards::unsubscribe_signature::UnsubscribeSignature;
+
 #[derive(Clone)]
-pub struct Container<R, M, T>
+pub struct Container<R, M, T, P, S, U, O>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
     subscriber_command_executor: SubscriberCommandExecutor<R, M>,
     subscriber_query_reader: SubscriberQueryReader<R>,
+    subscriber_email_domain_overrides: SubscriberEmailDomainOverrides<O>,
     subscription_token_command_executor: SubscriptionTokenCommandExecutor<T>,
     subscription_token_query_reader: SubscriptionTokenQueryReader<T>,
+    topic_command_executor: TopicCommandExecutor<P>,
+    topic_query_reader: TopicQueryReader<P>,
+    topic_membership_command_executor: TopicMembershipCommandExecutor<S>,
+    topic_membership_query_reader: TopicMembershipQueryReader<S>,
+    unit_of_work_factory: Arc<U>,
+    admin_token: AdminToken,
+    unsubscribe_signature: UnsubscribeSignature,
 }
 
-impl<R, M, T> Container<R, M, T>
+impl<R, M, T, P, S, U, O> Container<R, M, T, P, S, U, O>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         subscriber_repository: R,
         subscriber_messenger: M,
         subscription_token_repository: T,
+        topic_repository: P,
+        topic_membership_repository: S,
+        unit_of_work_factory: U,
+        subscriber_email_domain_override_repository: O,
+        subscriber_email_domain_policy: SubscriberEmailDomainPolicy,
         exposing_address: String,
+        // sent by operators calling admin routes
+        admin_token: String,
+        // shared with the publication service signing unsubscribe links in issues
+        unsubscribe_secret: String,
     ) -> Self {
         Self {
             subscriber_command_executor: SubscriberCommandExecutor::new(
                 subscriber_repository.clone(),
                 subscriber_messenger.clone(),
+                subscriber_email_domain_policy.clone(),
                 exposing_address,
             ),
             subscriber_query_reader: SubscriberQueryReader::new(subscriber_repository.clone()),
+            subscriber_email_domain_overrides: SubscriberEmailDomainOverrides::new(
+                subscriber_email_domain_policy,
+                subscriber_email_domain_override_repository,
+            ),
             subscription_token_command_executor: SubscriptionTokenCommandExecutor::new(
                 subscription_token_repository.clone(),
             ),
             subscription_token_query_reader: SubscriptionTokenQueryReader::new(
                 subscription_token_repository.clone(),
             ),
+            topic_command_executor: TopicCommandExecutor::new(topic_repository.clone()),
+            topic_query_reader: TopicQueryReader::new(topic_repository.clone()),
+            topic_membership_command_executor: TopicMembershipCommandExecutor::new(
+                topic_membership_repository.clone(),
+            ),
+            topic_membership_query_reader: TopicMembershipQueryReader::new(
+                topic_membership_repository.clone(),
+            ),
+            unit_of_work_factory: Arc::new(unit_of_work_factory),
+            admin_token: AdminToken::new(admin_token),
+            unsubscribe_signature: UnsubscribeSignature::new(unsubscribe_secret),
         }
     }
 }
 
-impl<R, M, T> FromRef<Container<R, M, T>> for SubscriberQueryReader<R>
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for SubscriberQueryReader<R>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R, M, T>) -> Self {
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
         container.subscriber_query_reader.clone()
     }
 }
 
-impl<R, M, T> FromRef<Container<R, M, T>> for SubscriberCommandExecutor<R, M>
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>>
+    for SubscriberCommandExecutor<R, M>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R, M, T>) -> Self {
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
         container.subscriber_command_executor.clone()
     }
 }
 
-impl<R, M, T> FromRef<Container<R, M, T>> for SubscriptionTokenCommandExecutor<T>
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>>
+    for SubscriptionTokenCommandExecutor<T>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R, M, T>) -> Self {
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
         container.subscription_token_command_executor.clone()
     }
 }
 
-impl<R, M, T> FromRef<Container<R, M, T>> for SubscriptionTokenQueryReader<T>
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>>
+    for SubscriptionTokenQueryReader<T>
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
-    fn from_ref(container: &Container<R, M, T>) -> Self {
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
         container.subscription_token_query_reader.clone()
     }
 }
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for TopicCommandExecutor<P>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.topic_command_executor.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for TopicQueryReader<P>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.topic_query_reader.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>>
+    for TopicMembershipCommandExecutor<S>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.topic_membership_command_executor.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for TopicMembershipQueryReader<S>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.topic_membership_query_reader.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for Arc<U>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.unit_of_work_factory.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>>
+    for SubscriberEmailDomainOverrides<O>
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.subscriber_email_domain_overrides.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for AdminToken
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.admin_token.clone()
+    }
+}
+
+impl<R, M, T, P, S, U, O> FromRef<Container<R, M, T, P, S, U, O>> for UnsubscribeSignature
+where
+    R: SubscriberRepository + Clone + Send + Sync + 'static,
+    M: SubscriberMessenger + Clone + Send + Sync + 'static,
+    T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
+{
+    fn from_ref(container: &Container<R, M, T, P, S, U, O>) -> Self {
+        container.unsubscribe_signature.clone()
+    }
+}
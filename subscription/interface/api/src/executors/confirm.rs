@@ -10,10 +10,16 @@ use domain::prelude::{
     SubscriberError,
     SubscriberMessenger,
     SubscriberRepository,
+    SubscriptionTokenCommand,
+    SubscriptionTokenCommandExecutor,
     SubscriptionTokenError,
+    SubscriptionTokenPurpose,
     SubscriptionTokenQuery,
     SubscriptionTokenQueryReader,
     SubscriptionTokenRepository,
+    TopicMembershipCommand,
+    TopicMembershipCommandExecutor,
+    TopicMembershipRepository,
 };
 
 use crate::error::ApiError;
@@ -26,29 +32,53 @@ pub struct Request {
 
 #[tracing::instrument(
     name = "Confirming a subscription",
-    skip(subscriber_command_executor, subscription_token_query_reader,)
+    skip(
+        subscriber_command_executor,
+        subscription_token_command_executor,
+        subscription_token_query_reader,
+        topic_membership_command_executor
+    )
 )]
 pub async fn execute(
     State(subscriber_command_executor): State<
         SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
     >,
+    State(subscription_token_command_executor): State<
+        SubscriptionTokenCommandExecutor<impl SubscriptionTokenRepository>,
+    >,
     State(subscription_token_query_reader): State<
         SubscriptionTokenQueryReader<impl SubscriptionTokenRepository>,
     >,
+    State(topic_membership_command_executor): State<
+        TopicMembershipCommandExecutor<impl TopicMembershipRepository>,
+    >,
     Query(request): Query<Request>,
 ) -> Result<StatusCode, ApiError> {
-    let inquire_subscription_token_by_token_query =
-        SubscriptionTokenQuery::InquireSubscriptionTokenByToken {
-            token: request.token,
+    let inquire_valid_subscription_token_query =
+        SubscriptionTokenQuery::InquireValidSubscriptionToken {
+            token: request.token.clone(),
+            purpose: SubscriptionTokenPurpose::Confirmation,
         };
     let subscription_token = subscription_token_query_reader
-        .read(inquire_subscription_token_by_token_query)
+        .read(inquire_valid_subscription_token_query)
         .await
         .map_err(|error| match error {
-            SubscriptionTokenError::SubscriptionTokenNotFound(_) => ApiError::new(
+            SubscriptionTokenError::SubscriptionTokenNotFound => ApiError::new(
                 StatusCode::NOT_FOUND,
                 anyhow::anyhow!("The given token doesn't exist"),
             ),
+            SubscriptionTokenError::SubscriptionTokenExpired => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is expired"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenRevoked => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is replaced by a newer one"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenAlreadyUsed => ApiError::new(
+                StatusCode::CONFLICT,
+                anyhow::anyhow!("The given token is already used"),
+            ),
             _ => ApiError::new(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 anyhow::anyhow!("Failed to get subscription token"),
@@ -65,6 +95,37 @@ pub async fn execute(
                 StatusCode::NOT_FOUND,
                 anyhow::anyhow!("No subscriber found for the given subscription token"),
             ),
+            SubscriberError::ConcurrentModification(_) => {
+                ApiError::new(StatusCode::CONFLICT, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    let confirm_topic_memberships_command =
+        TopicMembershipCommand::ConfirmTopicMemberships { subscriber_id };
+    topic_membership_command_executor
+        .execute(confirm_topic_memberships_command)
+        .await
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()))?;
+
+    // confirming is idempotent, so the token is consumed at last
+    // and only one of concurrent confirmations with the same token succeeds
+    let use_subscription_token_command = SubscriptionTokenCommand::UseSubscriptionToken {
+        token: request.token,
+        purpose: SubscriptionTokenPurpose::Confirmation,
+    };
+    subscription_token_command_executor
+        .execute(use_subscription_token_command)
+        .await
+        .map_err(|error| match error {
+            SubscriptionTokenError::SubscriptionTokenAlreadyUsed => ApiError::new(
+                StatusCode::CONFLICT,
+                anyhow::anyhow!("The given token is already used"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenRevoked => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is replaced by a newer one"),
+            ),
             _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
         })?;
 
@@ -77,12 +138,15 @@ mod tests {
         MockSubscriberMessenger,
         MockSubscriberRepository,
         MockSubscriptionTokenRepository,
-        // Subscriber, SubscriberEmail, SubscriberName, SubscriptionToken,
+        MockTopicMembershipRepository,
+        SubscriberEmailDomainPolicy,
+        SubscriptionToken,
+        // Subscriber, SubscriberEmail, SubscriberName,
     };
     // use fake::faker::internet::en::SafeEmail;
     // use fake::faker::name::en::FirstName;
     // use fake::Fake;
-    // use uuid::Uuid;
+    use uuid::Uuid;
 
     use super::*;
 
@@ -102,10 +166,15 @@ mod tests {
         let subscriber_command_executor = SubscriberCommandExecutor::new(
             subscriber_repository,
             subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
             exposing_address,
         );
+        let subscription_token_command_executor =
+            SubscriptionTokenCommandExecutor::new(MockSubscriptionTokenRepository::new());
         let subscription_token_query_reader =
             SubscriptionTokenQueryReader::new(subscription_token_repository);
+        let topic_membership_command_executor =
+            TopicMembershipCommandExecutor::new(MockTopicMembershipRepository::new());
 
         // when
         let request = Request {
@@ -113,7 +182,59 @@ mod tests {
         };
         let response = execute(
             State(subscriber_command_executor),
+            State(subscription_token_command_executor),
+            State(subscription_token_query_reader),
+            State(topic_membership_command_executor),
+            Query(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn confirmation_with_profile_management_token_returns_not_found() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+        let exposing_address = "http://localhost:3000".to_string();
+
+        subscription_token_repository
+            .expect_find_by_token()
+            .once()
+            .returning(|token| {
+                Ok(Some(SubscriptionToken::new(
+                    token.to_string(),
+                    Uuid::new_v4(),
+                    SubscriptionTokenPurpose::ProfileManagement,
+                )))
+            });
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            exposing_address,
+        );
+        let subscription_token_command_executor =
+            SubscriptionTokenCommandExecutor::new(MockSubscriptionTokenRepository::new());
+        let subscription_token_query_reader =
+            SubscriptionTokenQueryReader::new(subscription_token_repository);
+        let topic_membership_command_executor =
+            TopicMembershipCommandExecutor::new(MockTopicMembershipRepository::new());
+
+        // when
+        let request = Request {
+            token: "profile-management-token".to_string(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(subscription_token_command_executor),
             State(subscription_token_query_reader),
+            State(topic_membership_command_executor),
             Query(request),
         )
         .await;
@@ -144,16 +265,22 @@ mod tests {
     //             Ok(Option::Some(SubscriptionToken::new(
     //                 Uuid::new_v4().to_string(),
     //                 Uuid::new_v4(),
+    //                 SubscriptionTokenPurpose::Confirmation,
     //             )))
     //         });
 
     //     let subscriber_command_executor = SubscriberCommandExecutor::new(
     //         subscriber_repository,
     //         subscriber_messenger,
+    //         SubscriberEmailDomainPolicy::default(),
     //         exposing_address,
     //     );
+    //     let subscription_token_command_executor =
+    //         SubscriptionTokenCommandExecutor::new(MockSubscriptionTokenRepository::new());
     //     let subscription_token_query_reader =
     //         SubscriptionTokenQueryReader::new(subscription_token_repository);
+    //     let topic_membership_command_executor =
+    //         TopicMembershipCommandExecutor::new(MockTopicMembershipRepository::new());
 
     //     // when
     //     let request = Request {
@@ -161,7 +288,9 @@ mod tests {
     //     };
     //     let response = execute(
     //         State(subscriber_command_executor),
+    //         State(subscription_token_command_executor),
     //         State(subscription_token_query_reader),
+    //         State(topic_membership_command_executor),
     //         Query(request),
     //     )
     //     .await;
@@ -201,16 +330,22 @@ mod tests {
     //             Ok(Option::Some(SubscriptionToken::new(
     //                 Uuid::new_v4().to_string(),
     //                 subscriber_id,
+    //                 SubscriptionTokenPurpose::Confirmation,
     //             )))
     //         });
 
     //     let subscriber_command_executor = SubscriberCommandExecutor::new(
     //         subscriber_repository,
     //         subscriber_messenger,
+    //         SubscriberEmailDomainPolicy::default(),
     //         exposing_address,
     //     );
+    //     let subscription_token_command_executor =
+    //         SubscriptionTokenCommandExecutor::new(MockSubscriptionTokenRepository::new());
     //     let subscription_token_query_reader =
     //         SubscriptionTokenQueryReader::new(subscription_token_repository);
+    //     let topic_membership_command_executor =
+    //         TopicMembershipCommandExecutor::new(MockTopicMembershipRepository::new());
 
     //     // when
     //     let request = Request {
@@ -218,7 +353,9 @@ mod tests {
     //     };
     //     let response = execute(
     //         State(subscriber_command_executor),
+    //         State(subscription_token_command_executor),
     //         State(subscription_token_query_reader),
+    //         State(topic_membership_command_executor),
     //         Query(request),
     //     )
     //     .await;
@@ -0,0 +1,102 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    TopicCommand,
+    TopicCommandExecutor,
+    TopicError,
+    TopicRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Request {
+    slug: String,
+    name: String,
+    #[serde(default)]
+    description: String,
+    visibility: String,
+}
+
+#[tracing::instrument(name = "Creating a new topic", skip(topic_command_executor))]
+pub async fn execute(
+    State(topic_command_executor): State<TopicCommandExecutor<impl TopicRepository>>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError> {
+    let create_topic_command = TopicCommand::CreateTopic {
+        id: Uuid::new_v4(),
+        slug: request.slug,
+        name: request.name,
+        description: request.description,
+        visibility: request.visibility,
+    };
+    topic_command_executor
+        .execute(create_topic_command)
+        .await
+        .map_err(|error| match error {
+            TopicError::InvalidTopicSlug
+            | TopicError::InvalidTopicName
+            | TopicError::InvalidTopicVisibility => {
+                ApiError::new(StatusCode::BAD_REQUEST, error.into())
+            }
+            TopicError::DuplicateTopicSlug(_) => ApiError::new(StatusCode::CONFLICT, error.into()),
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    Ok(StatusCode::CREATED)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::MockTopicRepository;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn creating_topic_with_invalid_slug_returns_bad_request() {
+        // given
+        let topic_command_executor = TopicCommandExecutor::new(MockTopicRepository::new());
+
+        // when
+        let request = Request {
+            slug: "Rust Weekly".to_string(),
+            name: "Rust Weekly".to_string(),
+            description: "".to_string(),
+            visibility: "Public".to_string(),
+        };
+        let response = execute(State(topic_command_executor), Json(request)).await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn creating_topic_with_existing_slug_returns_conflict() {
+        // given
+        let mut topic_repository = MockTopicRepository::new();
+        topic_repository
+            .expect_save()
+            .once()
+            .returning(|topic| Err(TopicError::DuplicateTopicSlug(topic.slug.as_ref().into())));
+        let topic_command_executor = TopicCommandExecutor::new(topic_repository);
+
+        // when
+        let request = Request {
+            slug: "rust-weekly".to_string(),
+            name: "Rust Weekly".to_string(),
+            description: "".to_string(),
+            visibility: "Public".to_string(),
+        };
+        let response = execute(State(topic_command_executor), Json(request)).await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,81 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberError,
+    SubscriberMessenger,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Request {
+    subscriber_id: Uuid,
+}
+
+#[tracing::instrument(name = "Erasing a subscriber", skip(subscriber_command_executor))]
+pub async fn execute(
+    State(subscriber_command_executor): State<
+        SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
+    >,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError> {
+    let erase_subscriber_command = SubscriberCommand::EraseSubscriber {
+        id: request.subscriber_id,
+    };
+    subscriber_command_executor
+        .execute(erase_subscriber_command)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::SubscriberNotFound(_) => {
+                ApiError::new(StatusCode::NOT_FOUND, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberMessenger,
+        MockSubscriberRepository,
+        SubscriberEmailDomainPolicy,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn erasing_not_existing_subscriber_returns_not_found() {
+        // given
+        let mut subscriber_repository = MockSubscriberRepository::new();
+        subscriber_repository
+            .expect_find_by_id()
+            .once()
+            .returning(|_| Ok(None));
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            MockSubscriberMessenger::new(),
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+
+        // when
+        let request = Request {
+            subscriber_id: Uuid::new_v4(),
+        };
+        let response = execute(State(subscriber_command_executor), Json(request)).await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::NOT_FOUND);
+    }
+}
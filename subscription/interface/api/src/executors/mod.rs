@@ -1,2 +1,9 @@
 pub mod confirm;
+pub mod create_topic;
+pub mod erase_subscriber;
+pub mod override_email_domain;
+pub mod remove_email_domain_override;
+pub mod request_profile_link;
 pub mod subscribe;
+pub mod unsubscribe;
+pub mod update_profile;
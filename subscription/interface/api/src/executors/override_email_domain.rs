@@ -0,0 +1,111 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+    SubscriberEmailDomainRule,
+    SubscriberError,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Request {
+    domain: String,
+    rule: SubscriberEmailDomainRule,
+}
+
+#[tracing::instrument(
+    name = "Overriding an email domain rule",
+    skip(subscriber_email_domain_overrides)
+)]
+pub async fn execute(
+    State(subscriber_email_domain_overrides): State<
+        SubscriberEmailDomainOverrides<impl SubscriberEmailDomainOverrideRepository>,
+    >,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError> {
+    subscriber_email_domain_overrides
+        .override_domain(&request.domain, request.rule)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::InvalidSubscriberEmailDomain(_) => {
+                ApiError::new(StatusCode::BAD_REQUEST, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberEmailDomainOverrideRepository,
+        SubscriberEmailDomainPolicy,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn overriding_invalid_domain_returns_bad_request() {
+        // given
+        let mut subscriber_email_domain_override_repository =
+            MockSubscriberEmailDomainOverrideRepository::new();
+        subscriber_email_domain_override_repository
+            .expect_save()
+            .never();
+        let subscriber_email_domain_overrides = SubscriberEmailDomainOverrides::new(
+            SubscriberEmailDomainPolicy::default(),
+            subscriber_email_domain_override_repository,
+        );
+
+        // when
+        let request = Request {
+            domain: " ".to_string(),
+            rule: SubscriberEmailDomainRule::Allowed,
+        };
+        let response = execute(State(subscriber_email_domain_overrides), Json(request)).await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn overriding_domain_stores_it_in_canonical_form() {
+        // given
+        let mut subscriber_email_domain_override_repository =
+            MockSubscriberEmailDomainOverrideRepository::new();
+        subscriber_email_domain_override_repository
+            .expect_save()
+            .withf(|domain, rule| {
+                domain == "example.com" && *rule == SubscriberEmailDomainRule::Denied
+            })
+            .once()
+            .returning(|_, _| Ok(()));
+        let subscriber_email_domain_policy = SubscriberEmailDomainPolicy::default();
+        let subscriber_email_domain_overrides = SubscriberEmailDomainOverrides::new(
+            subscriber_email_domain_policy.clone(),
+            subscriber_email_domain_override_repository,
+        );
+
+        // when
+        let request = Request {
+            domain: " Example.COM ".to_string(),
+            rule: SubscriberEmailDomainRule::Denied,
+        };
+        let response = execute(State(subscriber_email_domain_overrides), Json(request)).await;
+
+        // then
+        assert_eq!(response.unwrap(), StatusCode::OK);
+        assert_eq!(
+            subscriber_email_domain_policy.overrides().unwrap(),
+            vec![("example.com".to_string(), SubscriberEmailDomainRule::Denied)]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+    SubscriberError,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Request {
+    domain: String,
+}
+
+#[tracing::instrument(
+    name = "Removing an email domain override",
+    skip(subscriber_email_domain_overrides)
+)]
+pub async fn execute(
+    State(subscriber_email_domain_overrides): State<
+        SubscriberEmailDomainOverrides<impl SubscriberEmailDomainOverrideRepository>,
+    >,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError> {
+    let removed = subscriber_email_domain_overrides
+        .remove_override(&request.domain)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::InvalidSubscriberEmailDomain(_) => {
+                ApiError::new(StatusCode::BAD_REQUEST, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    if !removed {
+        return Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            anyhow::anyhow!("The given domain isn't overridden"),
+        ));
+    }
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberEmailDomainOverrideRepository,
+        SubscriberEmailDomainPolicy,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn removing_not_overridden_domain_returns_not_found() {
+        // given
+        let mut subscriber_email_domain_override_repository =
+            MockSubscriberEmailDomainOverrideRepository::new();
+        subscriber_email_domain_override_repository
+            .expect_remove()
+            .returning(|_| Ok(false));
+        let subscriber_email_domain_overrides = SubscriberEmailDomainOverrides::new(
+            SubscriberEmailDomainPolicy::default(),
+            subscriber_email_domain_override_repository,
+        );
+
+        // when
+        let request = Request {
+            domain: "example.com".to_string(),
+        };
+        let response = execute(State(subscriber_email_domain_overrides), Json(request)).await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::NOT_FOUND);
+    }
+}
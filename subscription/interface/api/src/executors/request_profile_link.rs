@@ -0,0 +1,231 @@
+use anyhow::Context;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Form;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberError,
+    SubscriberMessenger,
+    SubscriberQuery,
+    SubscriberQueryReader,
+    SubscriberRepository,
+    SubscriptionToken,
+    SubscriptionTokenCommand,
+    SubscriptionTokenCommandExecutor,
+    SubscriptionTokenPurpose,
+    SubscriptionTokenRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Request {
+    email: String,
+}
+
+// Always accepted regardless of the email existence not to expose who subscribes
+#[tracing::instrument(
+    name = "Requesting a profile management link",
+    skip(
+        subscriber_command_executor,
+        subscriber_query_reader,
+        subscription_token_command_executor
+    )
+)]
+pub async fn execute(
+    State(subscriber_command_executor): State<
+        SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
+    >,
+    State(subscriber_query_reader): State<SubscriberQueryReader<impl SubscriberRepository>>,
+    State(subscription_token_command_executor): State<
+        SubscriptionTokenCommandExecutor<impl SubscriptionTokenRepository>,
+    >,
+    Form(request): Form<Request>,
+) -> Result<StatusCode, ApiError> {
+    let inquire_subscriber_by_email_query = SubscriberQuery::InquireSubscriberByEmail {
+        email: request.email,
+    };
+    let subscriber = match subscriber_query_reader
+        .read(inquire_subscriber_by_email_query)
+        .await
+    {
+        Ok(mut subscribers) => subscribers.remove(0),
+        Err(SubscriberError::SubscriberEmailNotFound) => return Ok(StatusCode::ACCEPTED),
+        Err(error) => {
+            return Err(ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                error.into(),
+            ))
+        }
+    };
+
+    let token = SubscriptionToken::generate_token().await;
+    let issue_subscription_token_command = SubscriptionTokenCommand::IssueSubscriptionToken {
+        token: token.clone(),
+        subscriber_id: subscriber.id,
+        purpose: SubscriptionTokenPurpose::ProfileManagement,
+    };
+    subscription_token_command_executor
+        .execute(issue_subscription_token_command)
+        .await
+        .context("Failed to issue a profile management token")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
+    let send_profile_management_message_command = SubscriberCommand::SendProfileManagementMessage {
+        id: subscriber.id,
+        token,
+    };
+    subscriber_command_executor
+        .execute(send_profile_management_message_command)
+        .await
+        .context("Failed to send a profile management email")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberMessenger,
+        MockSubscriberRepository,
+        MockSubscriptionTokenRepository,
+        Subscriber,
+        SubscriberEmail,
+        SubscriberEmailDomainPolicy,
+        SubscriberName,
+    };
+    use fake::faker::internet::en::SafeEmail;
+    use fake::faker::name::en::FirstName;
+    use fake::Fake;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn requesting_link_for_unknown_email_is_accepted_without_sending_message() {
+        // given
+        let mut subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let subscription_token_repository = MockSubscriptionTokenRepository::new();
+
+        subscriber_repository
+            .expect_find_by_email()
+            .once()
+            .returning(|_| Ok(None));
+
+        let subscriber_query_reader = SubscriberQueryReader::new(subscriber_repository);
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            MockSubscriberRepository::new(),
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let subscription_token_command_executor =
+            SubscriptionTokenCommandExecutor::new(subscription_token_repository);
+
+        // when
+        let request = Request {
+            email: SafeEmail().fake(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(subscriber_query_reader),
+            State(subscription_token_command_executor),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert_eq!(response.unwrap(), StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn requesting_link_for_existing_email_issues_token_and_sends_message() {
+        // given
+        let subscriber_id = Uuid::new_v4();
+        let subscriber = move || {
+            Subscriber::new(
+                subscriber_id,
+                SubscriberEmail::parse(SafeEmail().fake()).unwrap(),
+                SubscriberName::parse(FirstName().fake()).unwrap(),
+            )
+        };
+
+        let mut subscriber_query_repository = MockSubscriberRepository::new();
+        let mut subscriber_command_repository = MockSubscriberRepository::new();
+        let mut subscriber_messenger = MockSubscriberMessenger::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+
+        subscriber_query_repository
+            .expect_find_by_email()
+            .once()
+            .returning(move |_| Ok(Some(subscriber())));
+        subscriber_command_repository
+            .expect_find_by_id()
+            .once()
+            .returning(move |_| Ok(Some(subscriber())));
+        subscriber_messenger
+            .expect_send()
+            .withf(|_, _, content| content.contains("/subscriptions/profile?token="))
+            .once()
+            .returning(|_, _, _| Ok(()));
+        // only the older token for the same purpose is revoked
+        subscription_token_repository
+            .expect_find_by_subscriber_id()
+            .once()
+            .returning(|subscriber_id| {
+                Ok(vec![
+                    SubscriptionToken::new(
+                        "older-confirmation-token".to_string(),
+                        subscriber_id,
+                        SubscriptionTokenPurpose::Confirmation,
+                    ),
+                    SubscriptionToken::new(
+                        "older-profile-management-token".to_string(),
+                        subscriber_id,
+                        SubscriptionTokenPurpose::ProfileManagement,
+                    ),
+                ])
+            });
+        subscription_token_repository
+            .expect_reissue()
+            .withf(move |revoked_tokens, token| {
+                revoked_tokens.len() == 1
+                    && revoked_tokens[0].matches("older-profile-management-token")
+                    && revoked_tokens[0].revoked_at.is_some()
+                    && token.subscriber_id == subscriber_id
+                    && token.purpose == SubscriptionTokenPurpose::ProfileManagement
+            })
+            .once()
+            .returning(|_, _| Ok(()));
+
+        let subscriber_query_reader = SubscriberQueryReader::new(subscriber_query_repository);
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_command_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let subscription_token_command_executor =
+            SubscriptionTokenCommandExecutor::new(subscription_token_repository);
+
+        // when
+        let request = Request {
+            email: SafeEmail().fake(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(subscriber_query_reader),
+            State(subscription_token_command_executor),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert_eq!(response.unwrap(), StatusCode::ACCEPTED);
+    }
+}
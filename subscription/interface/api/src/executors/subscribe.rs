@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use anyhow::Context;
 use axum::extract::State;
 use axum::http::StatusCode;
@@ -13,7 +15,15 @@ use domain::prelude::{
     SubscriptionToken,
     SubscriptionTokenCommand,
     SubscriptionTokenCommandExecutor,
-    SubscriptionTokenRepository,
+    SubscriptionTokenPurpose,
+    TopicError,
+    TopicMembershipCommand,
+    TopicMembershipCommandExecutor,
+    TopicQuery,
+    TopicQueryReader,
+    TopicRepository,
+    UnitOfWork,
+    UnitOfWorkFactory,
 };
 
 use crate::error::ApiError;
@@ -23,21 +33,54 @@ use crate::error::ApiError;
 pub struct Request {
     email: String,
     name: String,
+    // comma separated slugs of public topics to join, e.g. rust,go
+    topics: Option<String>,
 }
 
 #[tracing::instrument(
     name = "Adding a new subscriber",
-    skip(subscriber_command_executor, subscription_token_command_executor)
+    skip(subscriber_command_executor, unit_of_work_factory, topic_query_reader)
 )]
 pub async fn execute(
     State(subscriber_command_executor): State<
         SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
     >,
-    State(subscription_token_command_executor): State<
-        SubscriptionTokenCommandExecutor<impl SubscriptionTokenRepository>,
-    >,
+    State(unit_of_work_factory): State<Arc<impl UnitOfWorkFactory>>,
+    State(topic_query_reader): State<TopicQueryReader<impl TopicRepository>>,
     Form(request): Form<Request>,
 ) -> Result<StatusCode, ApiError> {
+    // check topics before registering not to leave a subscriber without the requested topics
+    let slugs: Vec<String> = request
+        .topics
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|slug| !slug.is_empty())
+        .map(str::to_string)
+        .collect();
+    let topics = if slugs.is_empty() {
+        vec![]
+    } else {
+        let inquire_public_topics_by_slugs_query = TopicQuery::InquirePublicTopicsBySlugs { slugs };
+        topic_query_reader
+            .read(inquire_public_topics_by_slugs_query)
+            .await
+            .map_err(|error| match error {
+                TopicError::TopicNotFound(_) => {
+                    ApiError::new(StatusCode::BAD_REQUEST, error.into())
+                }
+                _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+            })?
+    };
+
+    // register the subscriber along with the confirmation token and the topics, or none of them
+    let unit_of_work = unit_of_work_factory
+        .begin()
+        .await
+        .context("Failed to begin a unit of work")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
     let subscriber_id = Uuid::new_v4();
     let register_subscriber_command = SubscriberCommand::RegisterSubscriber {
         id: subscriber_id,
@@ -45,17 +88,25 @@ pub async fn execute(
         name: request.name,
     };
     subscriber_command_executor
+        .with_repository(unit_of_work.subscriber_repository())
         .execute(register_subscriber_command)
         .await
         .map_err(|error| match error {
             SubscriberError::InvalidSubscriberName
             | SubscriberError::InvalidSubscriberEmail
+            | SubscriberError::BlockedSubscriberEmailDomain(_)
+            | SubscriberError::InvalidSubscriberEmailDomain(_)
             | SubscriberError::InvalidSubscriberStatus
-            | SubscriberError::SubscriberNotFound(_) => {
+            | SubscriberError::SubscriberNotFound(_)
+            | SubscriberError::SubscriberEmailNotFound => {
                 ApiError::new(StatusCode::BAD_REQUEST, error.into())
             }
+            SubscriberError::ConcurrentModification(_) => {
+                ApiError::new(StatusCode::CONFLICT, error.into())
+            }
             SubscriberError::RepositoryOperationFailed(_)
             | SubscriberError::MessengerOperationFailed(_)
+            | SubscriberError::PublisherOperationFailed(_)
             | SubscriberError::Unexpected(_) => {
                 ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into())
             }
@@ -65,13 +116,32 @@ pub async fn execute(
     let issue_subscription_token_command = SubscriptionTokenCommand::IssueSubscriptionToken {
         token: token.clone(),
         subscriber_id,
+        purpose: SubscriptionTokenPurpose::Confirmation,
     };
-    subscription_token_command_executor
+    SubscriptionTokenCommandExecutor::new(unit_of_work.subscription_token_repository())
         .execute(issue_subscription_token_command)
         .await
         .context("Failed to issue a subscription token")
         .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
 
+    if !topics.is_empty() {
+        let join_topics_command = TopicMembershipCommand::JoinTopics {
+            subscriber_id,
+            topic_ids: topics.iter().map(|topic| topic.id).collect(),
+        };
+        TopicMembershipCommandExecutor::new(unit_of_work.topic_membership_repository())
+            .execute(join_topics_command)
+            .await
+            .context("Failed to join topics")
+            .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+    }
+
+    unit_of_work
+        .commit()
+        .await
+        .context("Failed to commit a unit of work")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
     let send_confirmation_message_command = SubscriberCommand::SendConfirmationMessage {
         id: subscriber_id,
         token,
@@ -91,9 +161,20 @@ mod tests {
         MockSubscriberMessenger,
         MockSubscriberRepository,
         MockSubscriptionTokenRepository,
+        MockTopicMembershipRepository,
+        MockTopicRepository,
+        MockUnitOfWork,
+        MockUnitOfWorkFactory,
         Subscriber,
         SubscriberEmail,
+        SubscriberEmailDomainPolicy,
         SubscriberName,
+        SubscriptionTokenError,
+        Topic,
+        TopicMembershipError,
+        TopicName,
+        TopicSlug,
+        TopicVisibility,
     };
     use fake::faker::internet::en::SafeEmail;
     use fake::faker::name::en::FirstName;
@@ -101,6 +182,36 @@ mod tests {
 
     use super::*;
 
+    // unit of work handing out the given repositories
+    fn get_unit_of_work_factory(
+        subscriber_repository: MockSubscriberRepository,
+        subscription_token_repository: MockSubscriptionTokenRepository,
+        topic_membership_repository: MockTopicMembershipRepository,
+        committed: bool,
+    ) -> Arc<MockUnitOfWorkFactory> {
+        let mut unit_of_work = MockUnitOfWork::new();
+        unit_of_work
+            .expect_subscriber_repository()
+            .return_once(move || subscriber_repository);
+        unit_of_work
+            .expect_subscription_token_repository()
+            .return_once(move || subscription_token_repository);
+        unit_of_work
+            .expect_topic_membership_repository()
+            .return_once(move || topic_membership_repository);
+        unit_of_work
+            .expect_commit()
+            .times(usize::from(committed))
+            .returning(|| Ok(()));
+
+        let mut unit_of_work_factory = MockUnitOfWorkFactory::new();
+        unit_of_work_factory
+            .expect_begin()
+            .return_once(move || Ok(unit_of_work));
+
+        Arc::new(unit_of_work_factory)
+    }
+
     #[tokio::test]
     async fn subscription_with_invalid_email_returns_bad_request() {
         // given
@@ -112,19 +223,68 @@ mod tests {
         let subscriber_command_executor = SubscriberCommandExecutor::new(
             subscriber_repository,
             subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
             exposing_address,
         );
-        let subscription_token_command_executor =
-            SubscriptionTokenCommandExecutor::new(subscription_token_repository);
+        let unit_of_work_factory = get_unit_of_work_factory(
+            MockSubscriberRepository::new(),
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(MockTopicRepository::new());
 
         // when
         let request = Request {
             email: "not-an-email".to_string(),
             name: FirstName().fake(),
+            topics: None,
         };
         let response = execute(
             State(subscriber_command_executor),
-            State(subscription_token_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn subscription_with_blocked_email_domain_returns_bad_request() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let exposing_address = "http://localhost:3000".to_string();
+        let subscription_token_repository = MockSubscriptionTokenRepository::new();
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            exposing_address,
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            MockSubscriberRepository::new(),
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(MockTopicRepository::new());
+
+        // when
+        let request = Request {
+            email: "arine@mailinator.com".to_string(),
+            name: FirstName().fake(),
+            topics: None,
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
             Form(request),
         )
         .await;
@@ -137,12 +297,13 @@ mod tests {
     #[tokio::test]
     async fn subscription_with_duplicate_email_returns_bad_request() {
         // given
-        let mut subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_repository = MockSubscriberRepository::new();
+        let mut unit_of_work_subscriber_repository = MockSubscriberRepository::new();
         let subscriber_messenger = MockSubscriberMessenger::new();
         let subscription_token_repository = MockSubscriptionTokenRepository::new();
         let exposing_address = "http://localhost:3000".to_string();
 
-        subscriber_repository
+        unit_of_work_subscriber_repository
             .expect_save()
             .once()
             .returning(|_| Err(SubscriberError::InvalidSubscriberEmail));
@@ -150,19 +311,27 @@ mod tests {
         let subscriber_command_executor = SubscriberCommandExecutor::new(
             subscriber_repository,
             subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
             exposing_address,
         );
-        let subscription_token_command_executor =
-            SubscriptionTokenCommandExecutor::new(subscription_token_repository);
+        let unit_of_work_factory = get_unit_of_work_factory(
+            unit_of_work_subscriber_repository,
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(MockTopicRepository::new());
 
         // when
         let request = Request {
             email: SafeEmail().fake(),
             name: FirstName().fake(),
+            topics: None,
         };
         let response = execute(
             State(subscriber_command_executor),
-            State(subscription_token_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
             Form(request),
         )
         .await;
@@ -176,11 +345,12 @@ mod tests {
     async fn subscription_returns_200_when_infrastructure_succeed() {
         // given
         let mut subscriber_repository = MockSubscriberRepository::new();
+        let mut unit_of_work_subscriber_repository = MockSubscriberRepository::new();
         let mut subscriber_messenger = MockSubscriberMessenger::new();
         let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
         let exposing_address = "http://localhost:3000".to_string();
 
-        subscriber_repository
+        unit_of_work_subscriber_repository
             .expect_save()
             .once()
             .returning(|_| Ok(()));
@@ -199,26 +369,38 @@ mod tests {
             .once()
             .returning(|_, _, _| Ok(()));
         subscription_token_repository
-            .expect_save()
+            .expect_find_by_subscriber_id()
             .once()
-            .returning(|_| Ok(()));
+            .returning(|_| Ok(vec![]));
+        subscription_token_repository
+            .expect_reissue()
+            .once()
+            .returning(|_, _| Ok(()));
 
         let subscriber_command_executor = SubscriberCommandExecutor::new(
             subscriber_repository,
             subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
             exposing_address,
         );
-        let subscription_token_command_executor =
-            SubscriptionTokenCommandExecutor::new(subscription_token_repository);
+        let unit_of_work_factory = get_unit_of_work_factory(
+            unit_of_work_subscriber_repository,
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            true,
+        );
+        let topic_query_reader = TopicQueryReader::new(MockTopicRepository::new());
 
         // when
         let request = Request {
             email: SafeEmail().fake(),
             name: FirstName().fake(),
+            topics: None,
         };
         let response = execute(
             State(subscriber_command_executor),
-            State(subscription_token_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
             Form(request),
         )
         .await;
@@ -227,4 +409,197 @@ mod tests {
         assert!(response.is_ok());
         assert_eq!(response.unwrap(), StatusCode::CREATED);
     }
+
+    #[tokio::test]
+    async fn subscription_is_not_committed_when_issuing_token_fails() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let mut unit_of_work_subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+        let exposing_address = "http://localhost:3000".to_string();
+
+        unit_of_work_subscriber_repository
+            .expect_save()
+            .once()
+            .returning(|_| Ok(()));
+        subscription_token_repository
+            .expect_find_by_subscriber_id()
+            .once()
+            .returning(|_| Ok(vec![]));
+        subscription_token_repository
+            .expect_reissue()
+            .once()
+            .returning(|_, _| {
+                Err(SubscriptionTokenError::RepositoryOperationFailed(
+                    anyhow::anyhow!("Some errors"),
+                ))
+            });
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            exposing_address,
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            unit_of_work_subscriber_repository,
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(MockTopicRepository::new());
+
+        // when
+        let request = Request {
+            email: SafeEmail().fake(),
+            name: FirstName().fake(),
+            topics: None,
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(
+            response.unwrap_err().code,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[tokio::test]
+    async fn subscription_with_unknown_topic_returns_bad_request_without_registering() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let subscription_token_repository = MockSubscriptionTokenRepository::new();
+        let mut topic_repository = MockTopicRepository::new();
+        let exposing_address = "http://localhost:3000".to_string();
+
+        topic_repository
+            .expect_find_by_slug()
+            .once()
+            .returning(|_| Ok(None));
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            exposing_address,
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            MockSubscriberRepository::new(),
+            subscription_token_repository,
+            MockTopicMembershipRepository::new(),
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(topic_repository);
+
+        // when
+        let request = Request {
+            email: SafeEmail().fake(),
+            name: FirstName().fake(),
+            topics: Some("not-existing-topic".to_string()),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn subscription_is_not_committed_when_joining_topics_fails() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let mut unit_of_work_subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+        let mut topic_repository = MockTopicRepository::new();
+        let mut topic_membership_repository = MockTopicMembershipRepository::new();
+        let exposing_address = "http://localhost:3000".to_string();
+
+        topic_repository
+            .expect_find_by_slug()
+            .once()
+            .returning(|slug| {
+                Ok(Some(Topic::new(
+                    Uuid::new_v4(),
+                    TopicSlug::parse(slug.to_string()).unwrap(),
+                    TopicName::parse(slug.to_string()).unwrap(),
+                    "".to_string(),
+                    TopicVisibility::Public,
+                )))
+            });
+        unit_of_work_subscriber_repository
+            .expect_save()
+            .once()
+            .returning(|_| Ok(()));
+        subscription_token_repository
+            .expect_find_by_subscriber_id()
+            .once()
+            .returning(|_| Ok(vec![]));
+        subscription_token_repository
+            .expect_reissue()
+            .once()
+            .returning(|_, _| Ok(()));
+        topic_membership_repository
+            .expect_find_by_subscriber_id()
+            .once()
+            .returning(|_| Ok(vec![]));
+        topic_membership_repository
+            .expect_save()
+            .once()
+            .returning(|_| {
+                Err(TopicMembershipError::RepositoryOperationFailed(
+                    anyhow::anyhow!("Some errors"),
+                ))
+            });
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            exposing_address,
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            unit_of_work_subscriber_repository,
+            subscription_token_repository,
+            topic_membership_repository,
+            false,
+        );
+        let topic_query_reader = TopicQueryReader::new(topic_repository);
+
+        // when
+        let request = Request {
+            email: SafeEmail().fake(),
+            name: FirstName().fake(),
+            topics: Some("rust".to_string()),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(topic_query_reader),
+            Form(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(
+            response.unwrap_err().code,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
 }
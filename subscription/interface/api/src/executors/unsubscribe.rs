@@ -0,0 +1,101 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::StatusCode;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberError,
+    SubscriberMessenger,
+    SubscriberRepository,
+};
+
+use crate::error::ApiError;
+use crate::guards::unsubscribe_signature::UnsubscribeSignature;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Request {
+    token: String,
+}
+
+#[tracing::instrument(
+    name = "Unsubscribing a subscriber",
+    skip(subscriber_command_executor, unsubscribe_signature)
+)]
+pub async fn execute(
+    State(subscriber_command_executor): State<
+        SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
+    >,
+    State(unsubscribe_signature): State<UnsubscribeSignature>,
+    Query(request): Query<Request>,
+) -> Result<StatusCode, ApiError> {
+    // the link never expires, as it's in every issue sent, but only the signed subscriber leaves
+    let subscriber_id = unsubscribe_signature
+        .verify(&request.token)
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("The given token doesn't exist"),
+            )
+        })?;
+
+    let unsubscribe_command = SubscriberCommand::Unsubscribe { id: subscriber_id };
+    subscriber_command_executor
+        .execute(unsubscribe_command)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::SubscriberNotFound(_) => ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("No subscriber found for the given token"),
+            ),
+            SubscriberError::ConcurrentModification(_) => {
+                ApiError::new(StatusCode::CONFLICT, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberMessenger,
+        MockSubscriberRepository,
+        SubscriberEmailDomainPolicy,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn unsubscribing_with_token_signed_by_another_secret_returns_not_found() {
+        // given
+        // the subscriber repository panics if the subscriber is modified
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            MockSubscriberRepository::new(),
+            MockSubscriberMessenger::new(),
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let unsubscribe_signature = UnsubscribeSignature::new("welcome".to_string());
+
+        // when
+        let request = Request {
+            token: UnsubscribeSignature::new("guess".to_string()).sign(Uuid::new_v4()),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unsubscribe_signature),
+            Query(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::NOT_FOUND);
+    }
+}
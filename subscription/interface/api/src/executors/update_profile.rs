@@ -0,0 +1,350 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    SubscriberCommand,
+    SubscriberCommandExecutor,
+    SubscriberError,
+    SubscriberMessenger,
+    SubscriberPreferences,
+    SubscriberRepository,
+    SubscriptionTokenCommand,
+    SubscriptionTokenCommandExecutor,
+    SubscriptionTokenError,
+    SubscriptionTokenPurpose,
+    SubscriptionTokenQuery,
+    SubscriptionTokenQueryReader,
+    SubscriptionTokenRepository,
+    UnitOfWork,
+    UnitOfWorkFactory,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Parameters {
+    token: String,
+}
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct Request {
+    name: String,
+    #[serde(default)]
+    preferences: SubscriberPreferences,
+}
+
+#[tracing::instrument(
+    name = "Updating a subscriber's profile",
+    skip(
+        subscriber_command_executor,
+        unit_of_work_factory,
+        subscription_token_query_reader
+    )
+)]
+pub async fn execute(
+    State(subscriber_command_executor): State<
+        SubscriberCommandExecutor<impl SubscriberRepository, impl SubscriberMessenger>,
+    >,
+    State(unit_of_work_factory): State<Arc<impl UnitOfWorkFactory>>,
+    State(subscription_token_query_reader): State<
+        SubscriptionTokenQueryReader<impl SubscriptionTokenRepository>,
+    >,
+    Query(parameters): Query<Parameters>,
+    Json(request): Json<Request>,
+) -> Result<StatusCode, ApiError> {
+    let inquire_valid_subscription_token_query =
+        SubscriptionTokenQuery::InquireValidSubscriptionToken {
+            token: parameters.token.clone(),
+            purpose: SubscriptionTokenPurpose::ProfileManagement,
+        };
+    let subscription_token = subscription_token_query_reader
+        .read(inquire_valid_subscription_token_query)
+        .await
+        .map_err(|error| match error {
+            SubscriptionTokenError::SubscriptionTokenNotFound => ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("The given token doesn't exist"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenExpired => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is expired"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenRevoked => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is replaced by a newer one"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenAlreadyUsed => ApiError::new(
+                StatusCode::CONFLICT,
+                anyhow::anyhow!("The given token is already used"),
+            ),
+            _ => ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                anyhow::anyhow!("Failed to get subscription token"),
+            ),
+        })?;
+
+    // the magic link works only once, so the token is used up first in the same unit of work
+    // as the update, and concurrent requests with the same link wait for it and stop there
+    let unit_of_work = unit_of_work_factory
+        .begin()
+        .await
+        .context("Failed to begin a unit of work")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
+    let use_subscription_token_command = SubscriptionTokenCommand::UseSubscriptionToken {
+        token: parameters.token,
+        purpose: SubscriptionTokenPurpose::ProfileManagement,
+    };
+    SubscriptionTokenCommandExecutor::new(unit_of_work.subscription_token_repository())
+        .execute(use_subscription_token_command)
+        .await
+        .map_err(|error| match error {
+            SubscriptionTokenError::SubscriptionTokenAlreadyUsed => ApiError::new(
+                StatusCode::CONFLICT,
+                anyhow::anyhow!("The given token is already used"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenRevoked => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is replaced by a newer one"),
+            ),
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    let update_profile_command = SubscriberCommand::UpdateProfile {
+        id: subscription_token.subscriber_id,
+        name: request.name,
+        preferences: request.preferences,
+    };
+    subscriber_command_executor
+        .with_repository(unit_of_work.subscriber_repository())
+        .execute(update_profile_command)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::InvalidSubscriberName => {
+                ApiError::new(StatusCode::BAD_REQUEST, error.into())
+            }
+            SubscriberError::SubscriberNotFound(_) => ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("No subscriber found for the given token"),
+            ),
+            SubscriberError::ConcurrentModification(_) => {
+                ApiError::new(StatusCode::CONFLICT, error.into())
+            }
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?;
+
+    unit_of_work
+        .commit()
+        .await
+        .context("Failed to commit a unit of work")
+        .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?;
+
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberMessenger,
+        MockSubscriberRepository,
+        MockSubscriptionTokenRepository,
+        MockUnitOfWork,
+        MockUnitOfWorkFactory,
+        SubscriberEmailDomainPolicy,
+        SubscriptionToken,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    // unit of work handing out the given repositories, never committed by the tests below
+    fn get_unit_of_work_factory(
+        subscriber_repository: MockSubscriberRepository,
+        subscription_token_repository: MockSubscriptionTokenRepository,
+    ) -> Arc<MockUnitOfWorkFactory> {
+        let mut unit_of_work = MockUnitOfWork::new();
+        unit_of_work
+            .expect_subscriber_repository()
+            .return_once(move || subscriber_repository);
+        unit_of_work
+            .expect_subscription_token_repository()
+            .return_once(move || subscription_token_repository);
+        unit_of_work.expect_commit().never();
+
+        let mut unit_of_work_factory = MockUnitOfWorkFactory::new();
+        unit_of_work_factory
+            .expect_begin()
+            .return_once(move || Ok(unit_of_work));
+
+        Arc::new(unit_of_work_factory)
+    }
+
+    fn get_profile_management_token_repository() -> MockSubscriptionTokenRepository {
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+        subscription_token_repository
+            .expect_find_by_token()
+            .returning(|token| {
+                Ok(Some(SubscriptionToken::new(
+                    token.to_string(),
+                    Uuid::new_v4(),
+                    SubscriptionTokenPurpose::ProfileManagement,
+                )))
+            });
+        subscription_token_repository
+    }
+
+    #[tokio::test]
+    async fn updating_profile_with_invalid_name_returns_bad_request() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut unit_of_work_subscription_token_repository =
+            get_profile_management_token_repository();
+        unit_of_work_subscription_token_repository
+            .expect_use_up()
+            .once()
+            .returning(|_| Ok(()));
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            MockSubscriberRepository::new(),
+            unit_of_work_subscription_token_repository,
+        );
+        let subscription_token_query_reader =
+            SubscriptionTokenQueryReader::new(get_profile_management_token_repository());
+
+        // when
+        let parameters = Parameters {
+            token: "profile-management-token".to_string(),
+        };
+        let request = Request {
+            name: "<script>".to_string(),
+            preferences: SubscriberPreferences::default(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(subscription_token_query_reader),
+            Query(parameters),
+            Json(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn updating_profile_with_confirmation_token_returns_not_found() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+
+        subscription_token_repository
+            .expect_find_by_token()
+            .once()
+            .returning(|token| {
+                Ok(Some(SubscriptionToken::new(
+                    token.to_string(),
+                    Uuid::new_v4(),
+                    SubscriptionTokenPurpose::Confirmation,
+                )))
+            });
+
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            subscriber_repository,
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let mut unit_of_work_factory = MockUnitOfWorkFactory::new();
+        unit_of_work_factory.expect_begin().never();
+        let subscription_token_query_reader =
+            SubscriptionTokenQueryReader::new(subscription_token_repository);
+
+        // when
+        let parameters = Parameters {
+            token: "confirmation-token".to_string(),
+        };
+        let request = Request {
+            name: "Arine You".to_string(),
+            preferences: SubscriberPreferences::default(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(Arc::new(unit_of_work_factory)),
+            State(subscription_token_query_reader),
+            Query(parameters),
+            Json(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn updating_profile_with_token_used_up_meanwhile_returns_conflict_without_updating() {
+        // given
+        let subscriber_messenger = MockSubscriberMessenger::new();
+        let mut unit_of_work_subscription_token_repository =
+            get_profile_management_token_repository();
+        unit_of_work_subscription_token_repository
+            .expect_use_up()
+            .once()
+            .returning(|_| Err(SubscriptionTokenError::SubscriptionTokenAlreadyUsed));
+
+        // the subscriber repository panics if the profile is modified
+        let subscriber_command_executor = SubscriberCommandExecutor::new(
+            MockSubscriberRepository::new(),
+            subscriber_messenger,
+            SubscriberEmailDomainPolicy::default(),
+            "http://localhost:3000".to_string(),
+        );
+        let unit_of_work_factory = get_unit_of_work_factory(
+            MockSubscriberRepository::new(),
+            unit_of_work_subscription_token_repository,
+        );
+        let subscription_token_query_reader =
+            SubscriptionTokenQueryReader::new(get_profile_management_token_repository());
+
+        // when
+        let parameters = Parameters {
+            token: "profile-management-token".to_string(),
+        };
+        let request = Request {
+            name: "Arine You".to_string(),
+            preferences: SubscriberPreferences::default(),
+        };
+        let response = execute(
+            State(subscriber_command_executor),
+            State(unit_of_work_factory),
+            State(subscription_token_query_reader),
+            Query(parameters),
+            Json(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,122 @@
+use axum::extract::{
+    Request,
+    State,
+};
+use axum::http::header::AUTHORIZATION;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::Response;
+use subtle::ConstantTimeEq;
+
+use crate::error::ApiError;
+
+// Admin routes are called by operators, who send the token configured for them as a bearer token
+#[derive(Clone)]
+pub struct AdminToken {
+    token: Vec<u8>,
+}
+
+impl AdminToken {
+    pub fn new(token: String) -> Self {
+        Self {
+            token: token.into_bytes(),
+        }
+    }
+
+    pub fn verify(&self, authorization: &str) -> bool {
+        // nothing is authorized by an empty token, so a missing configuration doesn't let anyone in
+        if self.token.is_empty() {
+            return false;
+        }
+
+        match authorization.strip_prefix("Bearer ") {
+            // compared in constant time, so the token can't be guessed byte by byte
+            Some(token) => token.trim().as_bytes().ct_eq(&self.token).into(),
+            None => false,
+        }
+    }
+}
+
+pub async fn authorize(
+    State(admin_token): State<AdminToken>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let authorization = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|authorization| authorization.to_str().ok())
+        .unwrap_or_default();
+    if !admin_token.verify(authorization) {
+        return Err(ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            anyhow::anyhow!("Admin token is missing or doesn't match"),
+        ));
+    }
+
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::routing::post;
+    use axum::{
+        middleware,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn call_admin_route(authorization: Option<&str>) -> StatusCode {
+        let admin_token = AdminToken::new("welcome".to_string());
+        let router = Router::new()
+            .route("/admin/command/execute", post(|| async { "executed" }))
+            .route_layer(middleware::from_fn_with_state(
+                admin_token.clone(),
+                authorize,
+            ))
+            .with_state(admin_token);
+
+        let mut request = axum::http::Request::post("/admin/command/execute");
+        if let Some(authorization) = authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn admin_route_is_called_only_with_the_token() {
+        // when
+        let authorized = call_admin_route(Some("Bearer welcome")).await;
+        let guessed = call_admin_route(Some("Bearer guess")).await;
+        let anonymous = call_admin_route(None).await;
+
+        // then
+        assert_eq!(authorized, StatusCode::OK);
+        assert_eq!(guessed, StatusCode::UNAUTHORIZED);
+        assert_eq!(anonymous, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn only_bearer_of_same_token_is_verified() {
+        // given
+        let admin_token = AdminToken::new("welcome".to_string());
+
+        // when
+        let verified = admin_token.verify("Bearer welcome");
+        let guessed = admin_token.verify("Bearer guess");
+        let not_bearer = admin_token.verify("welcome");
+
+        // then
+        assert!(verified);
+        assert!(!guessed);
+        assert!(!not_bearer);
+        assert!(!AdminToken::new(String::new()).verify("Bearer "));
+    }
+}
@@ -0,0 +1,94 @@
+use hmac::{
+    Hmac,
+    Mac,
+};
+use sha2::Sha256;
+use uuid::Uuid;
+
+// Unsubscribe links are sent in issues by the publication service, whose token is the ID of the
+// subscriber and its HMAC-SHA256 in hex by the secret shared with it, joined by a period
+#[derive(Clone)]
+pub struct UnsubscribeSignature {
+    secret: Vec<u8>,
+}
+
+impl UnsubscribeSignature {
+    pub fn new(secret: String) -> Self {
+        Self {
+            secret: secret.into_bytes(),
+        }
+    }
+
+    // ID of the subscriber the token is signed for, if it is
+    pub fn verify(&self, token: &str) -> Option<Uuid> {
+        // nothing is signed by an empty secret, so a missing configuration doesn't let anyone in
+        if self.secret.is_empty() {
+            return None;
+        }
+        let (subscriber_id, signature) = token.trim().split_once('.')?;
+        let subscriber_id = Uuid::parse_str(subscriber_id).ok()?;
+        let signature = hex::decode(signature).ok()?;
+
+        // compared in constant time, so the signature can't be guessed byte by byte
+        self.mac(subscriber_id)
+            .verify_slice(&signature)
+            .ok()
+            .map(|_| subscriber_id)
+    }
+
+    #[cfg(test)]
+    pub fn sign(&self, subscriber_id: Uuid) -> String {
+        format!(
+            "{}.{}",
+            subscriber_id,
+            hex::encode(self.mac(subscriber_id).finalize().into_bytes())
+        )
+    }
+
+    fn mac(&self, subscriber_id: Uuid) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC takes a key of any size");
+        mac.update(subscriber_id.to_string().as_bytes());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_token_signed_for_the_subscriber_by_same_secret_is_verified() {
+        // given
+        let unsubscribe_signature = UnsubscribeSignature::new("welcome".to_string());
+        let subscriber_id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let token = unsubscribe_signature.sign(subscriber_id);
+        let (_, signature) = token.split_once('.').unwrap();
+
+        // when
+        let verified = unsubscribe_signature.verify(&token);
+        let tampered = unsubscribe_signature.verify(&format!("{}.{}", Uuid::new_v4(), signature));
+        let forged = unsubscribe_signature
+            .verify(&UnsubscribeSignature::new("guess".to_string()).sign(subscriber_id));
+
+        // then
+        // the same token as the publication service signs
+        assert_eq!(
+            token,
+            "67e55044-10b1-426f-9247-bb680e5fe0c8.\
+            19d3b3506fe2ffa99601dbf4feaa8169f657906b275c9ae1ab44d43823465e01"
+        );
+        assert_eq!(verified, Some(subscriber_id));
+        assert_eq!(tampered, None);
+        assert_eq!(forged, None);
+        assert_eq!(
+            unsubscribe_signature.verify(&subscriber_id.to_string()),
+            None
+        );
+        assert_eq!(
+            UnsubscribeSignature::new(String::new())
+                .verify(&UnsubscribeSignature::new(String::new()).sign(subscriber_id)),
+            None
+        );
+    }
+}
@@ -2,6 +2,7 @@ mod checkers;
 pub mod container;
 mod error;
 mod executors;
+mod guards;
 mod readers;
 mod router;
 pub mod runner;
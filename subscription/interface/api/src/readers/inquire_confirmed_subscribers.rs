@@ -1,7 +1,11 @@
 use anyhow::Context;
-use axum::extract::State;
+use axum::extract::{
+    Query,
+    State,
+};
 use axum::http::StatusCode;
 use axum::Json;
+use uuid::Uuid;
 
 use domain::prelude::{
     Subscriber,
@@ -12,10 +16,19 @@ use domain::prelude::{
 
 use crate::error::ApiError;
 
+// Subscribers are paged by their IDs only if the limit is given, otherwise all are returned
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Request {
+    after: Option<Uuid>,
+    limit: Option<u64>,
+}
+
 #[readonly::make]
 #[derive(serde::Serialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Response {
+    id: Uuid,
     name: String,
     email: String,
 }
@@ -23,6 +36,7 @@ pub struct Response {
 impl From<Subscriber> for Response {
     fn from(subscriber: Subscriber) -> Self {
         Response {
+            id: subscriber.id,
             name: subscriber.name.as_ref().to_owned(),
             email: subscriber.email.as_ref().to_owned(),
         }
@@ -35,8 +49,15 @@ impl From<Subscriber> for Response {
 )]
 pub async fn read(
     State(subscriber_query_reader): State<SubscriberQueryReader<impl SubscriberRepository>>,
+    Query(request): Query<Request>,
 ) -> Result<Json<Vec<Response>>, ApiError> {
-    let inquire_confirmed_subscribers_query = SubscriberQuery::InquireConfirmedSubscribers;
+    let inquire_confirmed_subscribers_query = match request.limit {
+        Some(limit) => SubscriberQuery::InquireConfirmedSubscribersPage {
+            after: request.after,
+            limit,
+        },
+        None => SubscriberQuery::InquireConfirmedSubscribers,
+    };
     Ok(Json(
         subscriber_query_reader
             .read(inquire_confirmed_subscribers_query)
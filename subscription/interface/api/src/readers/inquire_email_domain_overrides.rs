@@ -0,0 +1,82 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+    SubscriberEmailDomainRule,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    domain: String,
+    rule: SubscriberEmailDomainRule,
+}
+
+#[tracing::instrument(
+    name = "Inquiring email domain overrides",
+    skip(subscriber_email_domain_overrides)
+)]
+pub async fn read(
+    State(subscriber_email_domain_overrides): State<
+        SubscriberEmailDomainOverrides<impl SubscriberEmailDomainOverrideRepository>,
+    >,
+) -> Result<Json<Vec<Response>>, ApiError> {
+    Ok(Json(
+        subscriber_email_domain_overrides
+            .overrides()
+            .await
+            .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()))?
+            .into_iter()
+            .map(|(domain, rule)| Response { domain, rule })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberEmailDomainOverrideRepository,
+        SubscriberEmailDomainPolicy,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inquiring_overrides_returns_all_overridden_domains() {
+        // given
+        let mut subscriber_email_domain_override_repository =
+            MockSubscriberEmailDomainOverrideRepository::new();
+        subscriber_email_domain_override_repository
+            .expect_find_all()
+            .returning(|| {
+                Ok(vec![(
+                    "example.com".to_string(),
+                    SubscriberEmailDomainRule::Denied,
+                )])
+            });
+        let subscriber_email_domain_overrides = SubscriberEmailDomainOverrides::new(
+            SubscriberEmailDomainPolicy::default(),
+            subscriber_email_domain_override_repository,
+        );
+
+        // when
+        let response = read(State(subscriber_email_domain_overrides))
+            .await
+            .unwrap();
+
+        // then
+        assert_eq!(
+            response.0,
+            vec![Response {
+                domain: "example.com".to_string(),
+                rule: SubscriberEmailDomainRule::Denied,
+            }]
+        );
+    }
+}
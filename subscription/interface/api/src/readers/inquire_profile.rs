@@ -0,0 +1,158 @@
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    Subscriber,
+    SubscriberError,
+    SubscriberPreferences,
+    SubscriberQuery,
+    SubscriberQueryReader,
+    SubscriberRepository,
+    SubscriptionTokenError,
+    SubscriptionTokenPurpose,
+    SubscriptionTokenQuery,
+    SubscriptionTokenQueryReader,
+    SubscriptionTokenRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Request {
+    token: String,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    email: String,
+    name: String,
+    status: String,
+    preferences: SubscriberPreferences,
+}
+
+impl From<Subscriber> for Response {
+    fn from(subscriber: Subscriber) -> Self {
+        Response {
+            email: subscriber.email.as_ref().to_owned(),
+            name: subscriber.name.as_ref().to_owned(),
+            status: subscriber.status.as_ref().to_owned(),
+            preferences: subscriber.preferences,
+        }
+    }
+}
+
+#[tracing::instrument(
+    name = "Inquiring a subscriber's profile",
+    skip(subscriber_query_reader, subscription_token_query_reader)
+)]
+pub async fn read(
+    State(subscriber_query_reader): State<SubscriberQueryReader<impl SubscriberRepository>>,
+    State(subscription_token_query_reader): State<
+        SubscriptionTokenQueryReader<impl SubscriptionTokenRepository>,
+    >,
+    Query(request): Query<Request>,
+) -> Result<Json<Response>, ApiError> {
+    let inquire_valid_subscription_token_query =
+        SubscriptionTokenQuery::InquireValidSubscriptionToken {
+            token: request.token,
+            purpose: SubscriptionTokenPurpose::ProfileManagement,
+        };
+    let subscription_token = subscription_token_query_reader
+        .read(inquire_valid_subscription_token_query)
+        .await
+        .map_err(|error| match error {
+            SubscriptionTokenError::SubscriptionTokenNotFound => ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("The given token doesn't exist"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenExpired => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is expired"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenRevoked => ApiError::new(
+                StatusCode::GONE,
+                anyhow::anyhow!("The given token is replaced by a newer one"),
+            ),
+            SubscriptionTokenError::SubscriptionTokenAlreadyUsed => ApiError::new(
+                StatusCode::CONFLICT,
+                anyhow::anyhow!("The given token is already used"),
+            ),
+            _ => ApiError::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                anyhow::anyhow!("Failed to get subscription token"),
+            ),
+        })?;
+
+    let inquire_subscriber_query = SubscriberQuery::InquireSubscriber {
+        id: subscription_token.subscriber_id,
+    };
+    let subscriber = subscriber_query_reader
+        .read(inquire_subscriber_query)
+        .await
+        .map_err(|error| match error {
+            SubscriberError::SubscriberNotFound(_) => ApiError::new(
+                StatusCode::NOT_FOUND,
+                anyhow::anyhow!("No subscriber found for the given token"),
+            ),
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?
+        .remove(0);
+
+    Ok(Json(Response::from(subscriber)))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::prelude::{
+        MockSubscriberRepository,
+        MockSubscriptionTokenRepository,
+        SubscriptionToken,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inquiring_profile_with_expired_token_returns_gone() {
+        // given
+        let subscriber_repository = MockSubscriberRepository::new();
+        let mut subscription_token_repository = MockSubscriptionTokenRepository::new();
+
+        subscription_token_repository
+            .expect_find_by_token()
+            .once()
+            .returning(|token| {
+                let mut subscription_token = SubscriptionToken::new(
+                    token.to_string(),
+                    Uuid::new_v4(),
+                    SubscriptionTokenPurpose::ProfileManagement,
+                );
+                subscription_token.expired_at = subscription_token.issued_at;
+                Ok(Some(subscription_token))
+            });
+
+        // when
+        let request = Request {
+            token: "expired-token".to_string(),
+        };
+        let response = read(
+            State(SubscriberQueryReader::new(subscriber_repository)),
+            State(SubscriptionTokenQueryReader::new(
+                subscription_token_repository,
+            )),
+            Query(request),
+        )
+        .await;
+
+        // then
+        assert!(response.is_err());
+        assert_eq!(response.unwrap_err().code, StatusCode::GONE);
+    }
+}
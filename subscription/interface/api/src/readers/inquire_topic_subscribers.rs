@@ -0,0 +1,78 @@
+use anyhow::Context;
+use axum::extract::{
+    Query,
+    State,
+};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    Subscriber,
+    TopicError,
+    TopicMembershipQuery,
+    TopicMembershipQueryReader,
+    TopicMembershipRepository,
+    TopicQuery,
+    TopicQueryReader,
+    TopicRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Deserialize, Debug)]
+pub struct Request {
+    slug: String,
+}
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    name: String,
+    email: String,
+}
+
+impl From<Subscriber> for Response {
+    fn from(subscriber: Subscriber) -> Self {
+        Response {
+            name: subscriber.name.as_ref().to_owned(),
+            email: subscriber.email.as_ref().to_owned(),
+        }
+    }
+}
+
+#[tracing::instrument(
+    name = "Inquiring confirmed subscribers of a topic",
+    skip(topic_query_reader, topic_membership_query_reader)
+)]
+pub async fn read(
+    State(topic_query_reader): State<TopicQueryReader<impl TopicRepository>>,
+    State(topic_membership_query_reader): State<
+        TopicMembershipQueryReader<impl TopicMembershipRepository>,
+    >,
+    Query(request): Query<Request>,
+) -> Result<Json<Vec<Response>>, ApiError> {
+    let inquire_topic_by_slug_query = TopicQuery::InquireTopicBySlug { slug: request.slug };
+    let topic = topic_query_reader
+        .read(inquire_topic_by_slug_query)
+        .await
+        .map_err(|error| match error {
+            TopicError::TopicNotFound(_) => ApiError::new(StatusCode::NOT_FOUND, error.into()),
+            _ => ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error.into()),
+        })?
+        .remove(0);
+
+    let inquire_confirmed_subscribers_of_topic_query =
+        TopicMembershipQuery::InquireConfirmedSubscribersOfTopic { topic_id: topic.id };
+    Ok(Json(
+        topic_membership_query_reader
+            .read(inquire_confirmed_subscribers_of_topic_query)
+            .await
+            .context("Failed to get subscribers of the topic")
+            .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?
+            .into_iter()
+            .map(Response::from)
+            .collect(),
+    ))
+}
@@ -0,0 +1,49 @@
+use anyhow::Context;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::prelude::{
+    Topic,
+    TopicQuery,
+    TopicQueryReader,
+    TopicRepository,
+};
+
+use crate::error::ApiError;
+
+#[readonly::make]
+#[derive(serde::Serialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    slug: String,
+    name: String,
+    description: String,
+}
+
+impl From<Topic> for Response {
+    fn from(topic: Topic) -> Self {
+        Response {
+            slug: topic.slug.as_ref().to_owned(),
+            name: topic.name.as_ref().to_owned(),
+            description: topic.description,
+        }
+    }
+}
+
+#[tracing::instrument(name = "Inquiring public topics", skip(topic_query_reader))]
+pub async fn read(
+    State(topic_query_reader): State<TopicQueryReader<impl TopicRepository>>,
+) -> Result<Json<Vec<Response>>, ApiError> {
+    let inquire_public_topics_query = TopicQuery::InquirePublicTopics;
+    Ok(Json(
+        topic_query_reader
+            .read(inquire_public_topics_query)
+            .await
+            .context("Failed to get topics")
+            .map_err(|error| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, error))?
+            .into_iter()
+            .map(Response::from)
+            .collect(),
+    ))
+}
@@ -1 +1,5 @@
 pub mod inquire_confirmed_subscribers;
+pub mod inquire_email_domain_overrides;
+pub mod inquire_profile;
+pub mod inquire_topic_subscribers;
+pub mod inquire_topics;
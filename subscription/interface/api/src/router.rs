@@ -4,42 +4,109 @@ use axum::routing::{
     get,
     post,
 };
-use axum::Router;
+use axum::{
+    middleware,
+    Router,
+};
 use tower_http::trace::TraceLayer;
 use uuid::Uuid;
 
 use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
     SubscriberMessenger,
     SubscriberRepository,
     SubscriptionTokenRepository,
+    TopicMembershipRepository,
+    TopicRepository,
+    UnitOfWorkFactory,
 };
 
 use crate::{
     checkers,
     container,
     executors,
+    guards,
     readers,
 };
 
-pub async fn get_router<R, M, T>(container: container::Container<R, M, T>) -> Router
+pub async fn get_router<R, M, T, P, S, U, O>(
+    container: container::Container<R, M, T, P, S, U, O>,
+) -> Router
 where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
+    // admin routes change the email domain policy or expose and erase subscribers, so only operators
+    // call them
+    let admin_router = Router::new()
+        .route(
+            "/admin/query/inquire-email-domain-overrides/read",
+            get(readers::inquire_email_domain_overrides::read),
+        )
+        .route(
+            "/admin/command/override-email-domain/execute",
+            post(executors::override_email_domain::execute),
+        )
+        .route(
+            "/admin/command/remove-email-domain-override/execute",
+            post(executors::remove_email_domain_override::execute),
+        )
+        .route(
+            "/admin/query/inquire-topic-subscribers/read",
+            get(readers::inquire_topic_subscribers::read),
+        )
+        .route(
+            "/admin/command/create-topic/execute",
+            post(executors::create_topic::execute),
+        )
+        .route(
+            "/admin/command/erase-subscriber/execute",
+            post(executors::erase_subscriber::execute),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            container.clone(),
+            guards::admin_token::authorize,
+        ));
+
     Router::new()
         .route(
             "/subscription/query/inquire-confirmed-subscribers/read",
             get(readers::inquire_confirmed_subscribers::read),
         )
+        .route(
+            "/subscription/query/inquire-profile/read",
+            get(readers::inquire_profile::read),
+        )
+        .route(
+            "/subscription/query/inquire-topics/read",
+            get(readers::inquire_topics::read),
+        )
         .route(
             "/subscription/command/confirm/execute",
             post(executors::confirm::execute),
         )
+        .route(
+            "/subscription/command/request-profile-link/execute",
+            post(executors::request_profile_link::execute),
+        )
+        .route(
+            "/subscription/command/update-profile/execute",
+            post(executors::update_profile::execute),
+        )
         .route(
             "/subscription/command/subscribe/execute",
             post(executors::subscribe::execute),
         )
+        .route(
+            "/subscription/command/unsubscribe/execute",
+            post(executors::unsubscribe::execute),
+        )
+        .merge(admin_router)
         .route("/health/readiness", get(checkers::readiness::handle))
         .with_state(container)
         .route("/health/liveness", get(checkers::liveness::handle))
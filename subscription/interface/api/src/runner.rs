@@ -1,9 +1,13 @@
 use tokio::net::TcpListener;
 
 use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
     SubscriberMessenger,
     SubscriberRepository,
     SubscriptionTokenRepository,
+    TopicMembershipRepository,
+    TopicRepository,
+    UnitOfWorkFactory,
 };
 
 use crate::{
@@ -11,11 +15,17 @@ use crate::{
     router,
 };
 
-pub async fn run<R, M, T>(listener: TcpListener, container: container::Container<R, M, T>)
-where
+pub async fn run<R, M, T, P, S, U, O>(
+    listener: TcpListener,
+    container: container::Container<R, M, T, P, S, U, O>,
+) where
     R: SubscriberRepository + Clone + Send + Sync + 'static,
     M: SubscriberMessenger + Clone + Send + Sync + 'static,
     T: SubscriptionTokenRepository + Clone + Send + Sync + 'static,
+    P: TopicRepository + Clone + Send + Sync + 'static,
+    S: TopicMembershipRepository + Clone + Send + Sync + 'static,
+    U: UnitOfWorkFactory + Clone + Send + Sync + 'static,
+    O: SubscriberEmailDomainOverrideRepository + Clone + Send + Sync + 'static,
 {
     let app = router::get_router(container).await;
 
@@ -1,38 +1,19 @@
-use std::time::Duration;
-
+use anyhow::Context;
 use secrecy::ExposeSecret;
 
-use crate::configuration;
+use crate::{
+    configuration,
+    database,
+    policy,
+};
 
-pub async fn run(configuration: configuration::Configuration) {
+pub async fn run(configuration: configuration::Configuration) -> anyhow::Result<()> {
     // configure application listener
     let listener = configuration::bind_listener(&configuration).await;
 
     // configure database connection pool
-    let mut database_connection_options = repositories::prelude::DatabaseConnectionOptions::new(
-        configuration
-            .database
-            .connection_string_with_database()
-            .expose_secret(),
-    );
-
-    database_connection_options
-        .min_connections(configuration.database.pool_options.min_connections)
-        .max_connections(configuration.database.pool_options.max_connections)
-        .connect_timeout(Duration::from_secs(
-            configuration.database.pool_options.connect_timeout,
-        ))
-        .sqlx_logging(true)
-        .sqlx_logging_level(tracing_log::log::LevelFilter::Debug)
-        .sqlx_slow_statements_logging_settings(
-            tracing_log::log::LevelFilter::Warn,
-            Duration::from_secs(1),
-        );
-
     let database_connection_pool =
-        repositories::prelude::DatabaseConnection::connect(database_connection_options)
-            .await
-            .expect("Failed to create repository connection pool");
+        database::get_database_connection_pool(&configuration.database).await;
 
     let subscriber_repository =
         repositories::prelude::SubscriberSeaOrmRepository::new(database_connection_pool.clone());
@@ -40,6 +21,17 @@ pub async fn run(configuration: configuration::Configuration) {
         repositories::prelude::SubscriptionTokenSeaOrmRepository::new(
             database_connection_pool.clone(),
         );
+    let topic_repository =
+        repositories::prelude::TopicSeaOrmRepository::new(database_connection_pool.clone());
+    let topic_membership_repository = repositories::prelude::TopicMembershipSeaOrmRepository::new(
+        database_connection_pool.clone(),
+    );
+    let unit_of_work_factory =
+        repositories::prelude::SeaOrmUnitOfWorkFactory::new(database_connection_pool.clone());
+    let subscriber_email_domain_override_repository =
+        repositories::prelude::SubscriberEmailDomainOverrideSeaOrmRepository::new(
+            database_connection_pool.clone(),
+        );
 
     // configure email service client for messenger
     let mut headers = messengers::prelude::http_header::HeaderMap::new();
@@ -70,14 +62,46 @@ pub async fn run(configuration: configuration::Configuration) {
     // );
     let subscriber_messenger = messengers::prelude::SubscriberFakeMessenger::new();
 
+    // configure email domain policy reloaded when the policy file or the stored overrides change
+    let subscriber_email_domain_policy = policy::get_email_domain_policy(
+        &configuration.policy.email_domain,
+        subscriber_email_domain_override_repository.clone(),
+    )
+    .await
+    .context("Failed to configure email domain policy")?;
+    tokio::spawn(policy::watch_email_domain_policy(
+        subscriber_email_domain_policy.clone(),
+        subscriber_email_domain_override_repository.clone(),
+        configuration.policy.email_domain.clone(),
+    ));
+
     // configure container which of the application context
     let container = api::container::Container::new(
         subscriber_repository,
         subscriber_messenger,
         subscription_token_repository,
+        topic_repository,
+        topic_membership_repository,
+        unit_of_work_factory,
+        subscriber_email_domain_override_repository,
+        subscriber_email_domain_policy,
         configuration.application.exposing_address.url,
+        configuration
+            .application
+            .admin
+            .token
+            .expose_secret()
+            .clone(),
+        configuration
+            .application
+            .unsubscribe
+            .secret
+            .expose_secret()
+            .clone(),
     );
 
     // run the application api
     api::runner::run(listener, container).await;
+
+    Ok(())
 }
@@ -18,6 +18,15 @@ pub struct Configuration {
     #[config(nested)]
     pub messenger: MessengerConfiguration,
 
+    #[config(nested)]
+    pub policy: PolicyConfiguration,
+
+    #[config(nested)]
+    pub maintenance: MaintenanceConfiguration,
+
+    #[config(nested)]
+    pub outbox: OutboxConfiguration,
+
     #[config(nested)]
     pub logging: LoggingConfiguration,
 }
@@ -29,6 +38,12 @@ pub struct ApplicationConfiguration {
 
     #[config(nested)]
     pub exposing_address: ApplicationExposingAddress,
+
+    #[config(nested)]
+    pub admin: ApplicationAdmin,
+
+    #[config(nested)]
+    pub unsubscribe: ApplicationUnsubscribe,
 }
 
 #[derive(Debug, Config, Clone)]
@@ -46,6 +61,19 @@ pub struct ApplicationExposingAddress {
     pub url: String,
 }
 
+#[derive(Debug, Config, Clone)]
+pub struct ApplicationAdmin {
+    #[config(env = "APP_APPLICATION_ADMIN_TOKEN")]
+    pub token: Secret<String>,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct ApplicationUnsubscribe {
+    // shared with the publication service signing unsubscribe links in issues
+    #[config(env = "APP_APPLICATION_UNSUBSCRIBE_SECRET")]
+    pub secret: Secret<String>,
+}
+
 #[derive(Debug, Config, Clone)]
 pub struct DatabaseConfiguration {
     #[config(nested)]
@@ -121,6 +149,45 @@ pub struct EmailClientPoolOptions {
     pub request_timeout: u64,
 }
 
+#[derive(Debug, Config, Clone)]
+pub struct PolicyConfiguration {
+    #[config(nested)]
+    pub email_domain: EmailDomainPolicyConfiguration,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct EmailDomainPolicyConfiguration {
+    // default rules are used if the file is not given
+    #[config(env = "APP_POLICY_EMAIL_DOMAIN_FILE")]
+    pub file: Option<String>,
+    pub reload_interval: u64,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct MaintenanceConfiguration {
+    #[config(nested)]
+    pub purge: PurgeConfiguration,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct PurgeConfiguration {
+    pub interval: u64,
+    // days to keep tokens after their expiration
+    pub subscription_token_retention: u32,
+    // days to wait for subscribers to confirm
+    pub unconfirmed_subscriber_retention: u32,
+    // only report what would be purged
+    #[config(env = "APP_MAINTENANCE_PURGE_DRY_RUN", default = false)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Config, Clone)]
+pub struct OutboxConfiguration {
+    pub relay_interval: u64,
+    // maximum number of events relayed at once
+    pub relay_batch_size: u64,
+}
+
 #[derive(Debug, Config, Clone)]
 pub struct LoggingConfiguration {
     #[config(env = "APP_LOGGING_GLOBAL")]
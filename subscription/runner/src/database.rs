@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use secrecy::ExposeSecret;
+
+use crate::configuration::DatabaseConfiguration;
+
+pub async fn get_database_connection_pool(
+    configuration: &DatabaseConfiguration,
+) -> repositories::prelude::DatabaseConnectionPool {
+    let mut database_connection_options = repositories::prelude::DatabaseConnectionOptions::new(
+        configuration
+            .connection_string_with_database()
+            .expose_secret(),
+    );
+
+    database_connection_options
+        .min_connections(configuration.pool_options.min_connections)
+        .max_connections(configuration.pool_options.max_connections)
+        .connect_timeout(Duration::from_secs(
+            configuration.pool_options.connect_timeout,
+        ))
+        .sqlx_logging(true)
+        .sqlx_logging_level(tracing_log::log::LevelFilter::Debug)
+        .sqlx_slow_statements_logging_settings(
+            tracing_log::log::LevelFilter::Warn,
+            Duration::from_secs(1),
+        );
+
+    repositories::prelude::DatabaseConnection::connect(database_connection_options)
+        .await
+        .expect("Failed to create repository connection pool")
+}
@@ -1,3 +1,7 @@
 pub mod api;
 pub mod configuration;
+pub mod database;
+pub mod maintenance;
+pub mod outbox;
+pub mod policy;
 pub mod telemetry;
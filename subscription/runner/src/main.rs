@@ -9,6 +9,42 @@ async fn main() {
     );
     runner::telemetry::initialize_subscriber(subscriber);
 
-    // run api
-    runner::api::run(configuration).await;
+    let mut arguments = std::env::args().skip(1);
+    match arguments.next().as_deref() {
+        // run api along with scheduled maintenance and event relay
+        None => {
+            tokio::spawn(runner::maintenance::run(configuration.clone()));
+            tokio::spawn(runner::outbox::run(configuration.clone()));
+            // invalid configuration fails startup instead of panicking in the middle of it
+            if let Err(error) = runner::api::run(configuration).await {
+                eprintln!("Failed to start up the application: {:?}", error);
+                std::process::exit(1);
+            }
+        }
+        // purge stale data once, e.g. `app purge --dry-run`
+        Some("purge") => {
+            let dry_run = arguments.any(|argument| argument == "--dry-run");
+            let report = runner::maintenance::run_once(configuration, dry_run)
+                .await
+                .expect("Failed to purge stale subscription data");
+
+            println!(
+                "{} {} expired subscription tokens and {} unconfirmed subscribers",
+                if report.dry_run {
+                    "Would purge"
+                } else {
+                    "Purged"
+                },
+                report.expired_subscription_tokens,
+                report.unconfirmed_subscribers,
+            );
+        }
+        Some(command) => {
+            eprintln!(
+                "Unknown command: {}, available commands: purge [--dry-run]",
+                command
+            );
+            std::process::exit(2);
+        }
+    }
 }
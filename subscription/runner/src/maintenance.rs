@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::Utc;
+
+use domain::prelude::{
+    SubscriberRepository,
+    SubscriptionTokenRepository,
+};
+
+use crate::configuration::{
+    Configuration,
+    PurgeConfiguration,
+};
+use crate::database;
+
+#[derive(Debug, PartialEq)]
+pub struct PurgeReport {
+    pub dry_run: bool,
+    pub expired_subscription_tokens: u64,
+    pub unconfirmed_subscribers: u64,
+}
+
+// Delete tokens expired before the retention window and subscribers who haven't confirmed
+// within the retention window, or only count them in dry run
+pub async fn purge(
+    subscriber_repository: &impl SubscriberRepository,
+    subscription_token_repository: &impl SubscriptionTokenRepository,
+    configuration: &PurgeConfiguration,
+    dry_run: bool,
+) -> anyhow::Result<PurgeReport> {
+    let now = Utc::now();
+    let expired_before =
+        now - chrono::Duration::days(configuration.subscription_token_retention.into());
+    let subscribed_before =
+        now - chrono::Duration::days(configuration.unconfirmed_subscriber_retention.into());
+
+    let (expired_subscription_tokens, unconfirmed_subscribers) = if dry_run {
+        (
+            subscription_token_repository
+                .count_expired_before(expired_before)
+                .await
+                .context("Failed to count expired subscription tokens")?,
+            subscriber_repository
+                .count_unconfirmed_subscribed_before(subscribed_before)
+                .await
+                .context("Failed to count unconfirmed subscribers")?,
+        )
+    } else {
+        (
+            subscription_token_repository
+                .delete_expired_before(expired_before)
+                .await
+                .context("Failed to delete expired subscription tokens")?,
+            subscriber_repository
+                .delete_unconfirmed_subscribed_before(subscribed_before)
+                .await
+                .context("Failed to delete unconfirmed subscribers")?,
+        )
+    };
+
+    Ok(PurgeReport {
+        dry_run,
+        expired_subscription_tokens,
+        unconfirmed_subscribers,
+    })
+}
+
+// Purge periodically along with the application, failures are reported and retried next time
+pub async fn run(configuration: Configuration) {
+    let database_connection_pool =
+        database::get_database_connection_pool(&configuration.database).await;
+    let subscriber_repository =
+        repositories::prelude::SubscriberSeaOrmRepository::new(database_connection_pool.clone());
+    let subscription_token_repository =
+        repositories::prelude::SubscriptionTokenSeaOrmRepository::new(database_connection_pool);
+
+    let purge_configuration = configuration.maintenance.purge;
+    let mut interval = tokio::time::interval(Duration::from_secs(purge_configuration.interval));
+
+    loop {
+        interval.tick().await;
+
+        match purge(
+            &subscriber_repository,
+            &subscription_token_repository,
+            &purge_configuration,
+            purge_configuration.dry_run,
+        )
+        .await
+        {
+            Ok(report) => tracing::info!(?report, "Purged stale subscription data"),
+            Err(error) => tracing::error!("Failed to purge stale subscription data: {:?}", error),
+        }
+    }
+}
+
+// Purge once from the command line
+pub async fn run_once(configuration: Configuration, dry_run: bool) -> anyhow::Result<PurgeReport> {
+    let database_connection_pool =
+        database::get_database_connection_pool(&configuration.database).await;
+    let subscriber_repository =
+        repositories::prelude::SubscriberSeaOrmRepository::new(database_connection_pool.clone());
+    let subscription_token_repository =
+        repositories::prelude::SubscriptionTokenSeaOrmRepository::new(database_connection_pool);
+
+    purge(
+        &subscriber_repository,
+        &subscription_token_repository,
+        &configuration.maintenance.purge,
+        dry_run || configuration.maintenance.purge.dry_run,
+    )
+    .await
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use domain::prelude::SubscriberEventRelay;
+use publishers::prelude::SubscriberEventPostgresPublisher;
+use repositories::prelude::SubscriberEventOutboxSeaOrmRepository;
+
+use crate::configuration::Configuration;
+use crate::database;
+
+// Relay recorded events to the publisher periodically, failures are reported and retried next time
+pub async fn run(configuration: Configuration) {
+    let database_connection_pool =
+        database::get_database_connection_pool(&configuration.database).await;
+    let relay = SubscriberEventRelay::new(
+        SubscriberEventOutboxSeaOrmRepository::new(database_connection_pool.clone()),
+        SubscriberEventPostgresPublisher::new(database_connection_pool),
+    );
+
+    let outbox_configuration = configuration.outbox;
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(outbox_configuration.relay_interval));
+
+    loop {
+        interval.tick().await;
+
+        // keep relaying while full batches are relayed, so that a backlog is drained at once
+        loop {
+            match relay.relay(outbox_configuration.relay_batch_size).await {
+                Ok(relayed) if relayed as u64 == outbox_configuration.relay_batch_size => {
+                    tracing::info!(relayed, "Relayed subscriber events");
+                }
+                Ok(relayed) => {
+                    if relayed > 0 {
+                        tracing::info!(relayed, "Relayed subscriber events");
+                    }
+                    break;
+                }
+                Err(error) => {
+                    tracing::error!("Failed to relay subscriber events: {:?}", error);
+                    break;
+                }
+            }
+        }
+    }
+}
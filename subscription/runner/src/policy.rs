@@ -0,0 +1,104 @@
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+use anyhow::Context;
+
+use domain::prelude::{
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrides,
+    SubscriberEmailDomainPolicy,
+    SubscriberEmailDomainRules,
+};
+
+use crate::configuration::EmailDomainPolicyConfiguration;
+
+pub async fn get_email_domain_policy(
+    configuration: &EmailDomainPolicyConfiguration,
+    override_repository: impl SubscriberEmailDomainOverrideRepository,
+) -> anyhow::Result<SubscriberEmailDomainPolicy> {
+    // zero would make the watcher poll without pausing
+    anyhow::ensure!(
+        configuration.reload_interval > 0,
+        "Email domain policy reload interval must be at least 1 second"
+    );
+
+    let rules = match &configuration.file {
+        Some(file) => load_email_domain_rules(file).await?,
+        None => SubscriberEmailDomainRules::default(),
+    };
+
+    let policy = SubscriberEmailDomainPolicy::new(rules);
+    SubscriberEmailDomainOverrides::new(policy.clone(), override_repository)
+        .reload()
+        .await
+        .context("Failed to load email domain overrides")?;
+
+    Ok(policy)
+}
+
+// Poll the stored overrides and the policy file, and reload rules when the file is modified,
+// invalid files are reported and the previous rules are kept
+// The configuration is expected to be validated by getting the policy first
+pub async fn watch_email_domain_policy(
+    policy: SubscriberEmailDomainPolicy,
+    override_repository: impl SubscriberEmailDomainOverrideRepository,
+    configuration: EmailDomainPolicyConfiguration,
+) {
+    let overrides = SubscriberEmailDomainOverrides::new(policy.clone(), override_repository);
+    let mut interval = tokio::time::interval(Duration::from_secs(configuration.reload_interval));
+    let mut last_modified_at = match &configuration.file {
+        Some(file) => modified_at(file).await.ok(),
+        None => None,
+    };
+
+    loop {
+        interval.tick().await;
+
+        // re-read every time, as the overrides may be changed through other replicas
+        if let Err(error) = overrides.reload().await {
+            tracing::warn!("Failed to reload email domain overrides: {:?}", error);
+        }
+
+        let Some(file) = &configuration.file else {
+            continue;
+        };
+
+        let modified = match modified_at(file).await {
+            Ok(modified) => modified,
+            Err(error) => {
+                tracing::warn!("Failed to check email domain policy file: {:?}", error);
+                continue;
+            }
+        };
+        if last_modified_at == Some(modified) {
+            continue;
+        }
+
+        let reloaded = load_email_domain_rules(file)
+            .await
+            .and_then(|rules| policy.reload(rules).map_err(anyhow::Error::from));
+        match reloaded {
+            Ok(()) => {
+                tracing::info!("Reloaded email domain policy from {}", file);
+                last_modified_at = Some(modified);
+            }
+            Err(error) => {
+                tracing::warn!("Failed to reload email domain policy: {:?}", error);
+            }
+        }
+    }
+}
+
+async fn load_email_domain_rules(file: &str) -> anyhow::Result<SubscriberEmailDomainRules> {
+    let content = tokio::fs::read_to_string(file)
+        .await
+        .with_context(|| format!("Failed to read {}", file))?;
+
+    serde_yaml::from_str(&content).with_context(|| format!("Failed to parse {}", file))
+}
+
+async fn modified_at(file: &str) -> anyhow::Result<SystemTime> {
+    Ok(tokio::fs::metadata(file).await?.modified()?)
+}
@@ -8,19 +8,31 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use fake::Fake;
+use hmac::{
+    Hmac,
+    Mac,
+};
 use once_cell::sync::Lazy;
 use sea_orm::ConnectionTrait;
 use secrecy::ExposeSecret;
+use sha2::Sha256;
 use tokio::net::TcpListener;
+use uuid::Uuid;
 use wiremock::MockServer;
 
 use messengers::prelude::SubscriberEmailMessenger;
 use repositories::prelude::{
+    SeaOrmUnitOfWorkFactory,
+    SubscriberEmailDomainOverrideSeaOrmRepository,
+    SubscriberEventOutboxSeaOrmRepository,
     SubscriberSeaOrmRepository,
     SubscriptionTokenSeaOrmRepository,
+    TopicMembershipSeaOrmRepository,
+    TopicSeaOrmRepository,
 };
 use runner::{
     configuration,
+    policy,
     telemetry,
 };
 
@@ -29,12 +41,25 @@ pub struct App {
     pub address: SocketAddr,
     // reqwest client for checking API calls from external client
     pub client: reqwest::Client,
+    // token sent by operators calling admin routes
+    pub admin_token: String,
+    // secret the publication service signs unsubscribe links with
+    pub unsubscribe_secret: String,
     // mock server for checking email calls from application
     pub email_server: Arc<MockServer>,
     // subscriber repository for checking data in the database
-    pub subscriber_repository: Arc<SubscriberSeaOrmRepository>,
+    pub subscriber_repository: Arc<SubscriberSeaOrmRepository<'static>>,
     // subscription token repository for checking data in the database
-    pub subscription_token_repository: Arc<SubscriptionTokenSeaOrmRepository>,
+    pub subscription_token_repository: Arc<SubscriptionTokenSeaOrmRepository<'static>>,
+    // topic repository for checking data in the database
+    pub topic_repository: Arc<TopicSeaOrmRepository>,
+    // topic membership repository for checking data in the database
+    pub topic_membership_repository: Arc<TopicMembershipSeaOrmRepository<'static>>,
+    // outbox for checking and relaying recorded events
+    pub subscriber_event_outbox_repository: Arc<SubscriberEventOutboxSeaOrmRepository>,
+    // email domain override repository for checking data in the database
+    pub subscriber_email_domain_override_repository:
+        Arc<SubscriberEmailDomainOverrideSeaOrmRepository>,
 }
 
 static TRACING: Lazy<()> = Lazy::new(|| {
@@ -122,6 +147,13 @@ impl App {
         // create repository
         let subscriber_repository = SubscriberSeaOrmRepository::new(pool.clone());
         let subscription_token_repository = SubscriptionTokenSeaOrmRepository::new(pool.clone());
+        let topic_repository = TopicSeaOrmRepository::new(pool.clone());
+        let topic_membership_repository = TopicMembershipSeaOrmRepository::new(pool.clone());
+        let unit_of_work_factory = SeaOrmUnitOfWorkFactory::new(pool.clone());
+        let subscriber_event_outbox_repository =
+            SubscriberEventOutboxSeaOrmRepository::new(pool.clone());
+        let subscriber_email_domain_override_repository =
+            SubscriberEmailDomainOverrideSeaOrmRepository::new(pool.clone());
 
         // create email messenger
         let subscriber_messenger = SubscriberEmailMessenger::new(
@@ -138,12 +170,39 @@ impl App {
             configuration.messenger.email.sender,
         );
 
+        // create email domain policy
+        let subscriber_email_domain_policy = policy::get_email_domain_policy(
+            &configuration.policy.email_domain,
+            subscriber_email_domain_override_repository.clone(),
+        )
+        .await
+        .unwrap();
+        let admin_token = configuration
+            .application
+            .admin
+            .token
+            .expose_secret()
+            .clone();
+        let unsubscribe_secret = configuration
+            .application
+            .unsubscribe
+            .secret
+            .expose_secret()
+            .clone();
+
         // create container for application context
         let container = api::container::Container::new(
             subscriber_repository.clone(),
             subscriber_messenger.clone(),
             subscription_token_repository.clone(),
+            topic_repository.clone(),
+            topic_membership_repository.clone(),
+            unit_of_work_factory,
+            subscriber_email_domain_override_repository.clone(),
+            subscriber_email_domain_policy,
             configuration.application.exposing_address.url,
+            admin_token.clone(),
+            unsubscribe_secret.clone(),
         );
 
         // create http client for accessing application APIs
@@ -154,9 +213,30 @@ impl App {
         App {
             address,
             client,
+            admin_token,
+            unsubscribe_secret,
             email_server: Arc::new(email_server),
             subscriber_repository: Arc::new(subscriber_repository),
             subscription_token_repository: Arc::new(subscription_token_repository),
+            topic_repository: Arc::new(topic_repository),
+            topic_membership_repository: Arc::new(topic_membership_repository),
+            subscriber_event_outbox_repository: Arc::new(subscriber_event_outbox_repository),
+            subscriber_email_domain_override_repository: Arc::new(
+                subscriber_email_domain_override_repository,
+            ),
         }
     }
+
+    // token of the unsubscribe link, signed as the publication service does
+    pub fn sign_unsubscribe_token(&self, subscriber_id: Uuid) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.unsubscribe_secret.as_bytes())
+            .expect("HMAC takes a key of any size");
+        mac.update(subscriber_id.to_string().as_bytes());
+
+        format!(
+            "{}.{}",
+            subscriber_id,
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
 }
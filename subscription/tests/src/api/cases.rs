@@ -13,12 +13,12 @@ use wiremock::{
 use domain::prelude::{
     Subscriber,
     SubscriberRepository,
-    SubscriptionTokenRepository,
 };
 
 use crate::api::app::App;
 
-pub async fn create_unconfirmed_subscriber(app: &App) -> Subscriber {
+// only the digest of the token is persisted, so the token is taken from the sent email
+async fn subscribe(app: &App) -> (Subscriber, String) {
     let email: String = SafeEmail().fake();
     let name: String = FirstName().fake();
     let parameters = [("email", email.as_str()), ("name", name.as_str())];
@@ -36,30 +36,41 @@ pub async fn create_unconfirmed_subscriber(app: &App) -> Subscriber {
         .error_for_status()
         .unwrap();
 
-    let _request_in_email_server = &app
+    let request_in_email_server = &app
         .email_server
         .received_requests()
         .await
         .unwrap()
         .pop()
         .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&request_in_email_server.body).unwrap();
+    let token = body["Content"]
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/confirm?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string();
 
-    app.subscriber_repository
+    let subscriber = app
+        .subscriber_repository
         .find_by_email(&email)
         .await
         .unwrap()
-        .unwrap()
+        .unwrap();
+
+    (subscriber, token)
+}
+
+pub async fn create_unconfirmed_subscriber(app: &App) -> Subscriber {
+    let (subscriber, _) = subscribe(app).await;
+    subscriber
 }
 
 pub async fn create_confirmed_subscriber(app: &App) -> Subscriber {
-    let subscriber = create_unconfirmed_subscriber(app).await;
-    let subscription_token = app
-        .subscription_token_repository
-        .find_by_subscriber_id(subscriber.id)
-        .await
-        .unwrap()
-        .unwrap();
-    let parameters = [("token", subscription_token.token)];
+    let (subscriber, token) = subscribe(app).await;
+    let parameters = [("token", token)];
 
     app.post_subscription_confirm(&parameters).await;
 
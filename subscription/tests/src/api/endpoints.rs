@@ -35,4 +35,187 @@ impl App {
             .await
             .unwrap()
     }
+
+    // POST /subscription/request-profile-link
+    pub async fn post_subscription_request_profile_link<T: serde::Serialize + ?Sized>(
+        &self,
+        parameters: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/subscription/command/request-profile-link/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .form(&parameters)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /subscription/command/unsubscribe/execute
+    pub async fn post_subscription_unsubscribe<T: serde::Serialize + ?Sized>(
+        &self,
+        parameters: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/subscription/command/unsubscribe/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .query(&parameters)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // GET /subscription/inquire-profile
+    pub async fn get_subscription_inquire_profile<T: serde::Serialize + ?Sized>(
+        &self,
+        parameters: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/subscription/query/inquire-profile/read",
+            self.address
+        );
+        self.client
+            .get(url)
+            .query(&parameters)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /subscription/update-profile
+    pub async fn post_subscription_update_profile<
+        T: serde::Serialize + ?Sized,
+        U: serde::Serialize + ?Sized,
+    >(
+        &self,
+        parameters: &T,
+        body: &U,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/subscription/command/update-profile/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .query(&parameters)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // GET /subscription/inquire-topics
+    pub async fn get_subscription_inquire_topics(&self) -> reqwest::Response {
+        let url = format!(
+            "http://{}/subscription/query/inquire-topics/read",
+            self.address
+        );
+        self.client.get(url).send().await.unwrap()
+    }
+
+    // GET /admin/query/inquire-topic-subscribers/read
+    pub async fn get_admin_inquire_topic_subscribers<T: serde::Serialize + ?Sized>(
+        &self,
+        parameters: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/admin/query/inquire-topic-subscribers/read",
+            self.address
+        );
+        self.client
+            .get(url)
+            .bearer_auth(&self.admin_token)
+            .query(&parameters)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /admin/command/create-topic/execute
+    pub async fn post_admin_create_topic<T: serde::Serialize + ?Sized>(
+        &self,
+        body: &T,
+    ) -> reqwest::Response {
+        let url = format!("http://{}/admin/command/create-topic/execute", self.address);
+        self.client
+            .post(url)
+            .bearer_auth(&self.admin_token)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // GET /admin/query/inquire-email-domain-overrides/read
+    pub async fn get_admin_inquire_email_domain_overrides(&self) -> reqwest::Response {
+        let url = format!(
+            "http://{}/admin/query/inquire-email-domain-overrides/read",
+            self.address
+        );
+        self.client
+            .get(url)
+            .bearer_auth(&self.admin_token)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /admin/command/override-email-domain/execute
+    pub async fn post_admin_override_email_domain<T: serde::Serialize + ?Sized>(
+        &self,
+        body: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/admin/command/override-email-domain/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .bearer_auth(&self.admin_token)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /admin/command/remove-email-domain-override/execute
+    pub async fn post_admin_remove_email_domain_override<T: serde::Serialize + ?Sized>(
+        &self,
+        body: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/admin/command/remove-email-domain-override/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .bearer_auth(&self.admin_token)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    // POST /admin/command/erase-subscriber/execute
+    pub async fn post_admin_erase_subscriber<T: serde::Serialize + ?Sized>(
+        &self,
+        body: &T,
+    ) -> reqwest::Response {
+        let url = format!(
+            "http://{}/admin/command/erase-subscriber/execute",
+            self.address
+        );
+        self.client
+            .post(url)
+            .bearer_auth(&self.admin_token)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
 }
@@ -0,0 +1,208 @@
+use domain::prelude::{
+    MockSubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainOverrideRepository,
+    SubscriberEmailDomainRule,
+};
+use fake::faker::name::en::FirstName;
+use fake::Fake;
+use reqwest::StatusCode;
+use runner::configuration::EmailDomainPolicyConfiguration;
+use runner::policy;
+use tests::api::app::App;
+use wiremock::matchers::{
+    method,
+    path,
+};
+use wiremock::{
+    Mock,
+    ResponseTemplate,
+};
+
+#[tokio::test]
+async fn subscription_with_disposable_email_domain_returns_400() {
+    // given
+    let app = App::new().await;
+
+    let name: String = FirstName().fake();
+    let parameters = [("email", "arine@mailinator.com"), ("name", name.as_str())];
+
+    // when
+    let response = app.post_subscription_subscribe(&parameters).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.text().await.unwrap(),
+        r#"{"error":"Subscriber's email domain (mailinator.com) is not allowed to subscribe"}"#,
+    );
+}
+
+#[tokio::test]
+async fn subscription_with_disposable_email_domain_succeeds_after_allowing_it_at_runtime() {
+    // given
+    let app = App::new().await;
+
+    let name: String = FirstName().fake();
+    let parameters = [("email", "arine@mailinator.com"), ("name", name.as_str())];
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    // when
+    let override_response = app
+        .post_admin_override_email_domain(&serde_json::json!({
+            "Domain": "Mailinator.com",
+            "Rule": "Allowed",
+        }))
+        .await;
+    let response = app.post_subscription_subscribe(&parameters).await;
+
+    // then
+    assert_eq!(override_response.status(), StatusCode::OK);
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let overrides: serde_json::Value = app
+        .get_admin_inquire_email_domain_overrides()
+        .await
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        overrides,
+        serde_json::json!([{ "Domain": "mailinator.com", "Rule": "Allowed" }]),
+    );
+}
+
+#[tokio::test]
+async fn removing_email_domain_override_restores_the_policy() {
+    // given
+    let app = App::new().await;
+
+    let name: String = FirstName().fake();
+    let parameters = [("email", "arine@example.com"), ("name", name.as_str())];
+    let domain = serde_json::json!({ "Domain": "example.com" });
+
+    app.post_admin_override_email_domain(&serde_json::json!({
+        "Domain": "example.com",
+        "Rule": "Denied",
+    }))
+    .await;
+    let denied_response = app.post_subscription_subscribe(&parameters).await;
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    // when
+    let remove_response = app.post_admin_remove_email_domain_override(&domain).await;
+    let response = app.post_subscription_subscribe(&parameters).await;
+
+    // then
+    assert_eq!(denied_response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(remove_response.status(), StatusCode::OK);
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn overriding_email_domain_without_admin_token_returns_401() {
+    // given
+    let app = App::new().await;
+    let url = format!(
+        "http://{}/admin/command/override-email-domain/execute",
+        app.address
+    );
+
+    // when
+    let response = app
+        .client
+        .post(url)
+        .json(&serde_json::json!({
+            "Domain": "mailinator.com",
+            "Rule": "Allowed",
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(app
+        .subscriber_email_domain_override_repository
+        .find_all()
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn email_domain_override_is_stored_for_other_replicas_and_restarts() {
+    // given
+    let app = App::new().await;
+
+    // when
+    let response = app
+        .post_admin_override_email_domain(&serde_json::json!({
+            "Domain": "Mailinator.com",
+            "Rule": "Allowed",
+        }))
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        app.subscriber_email_domain_override_repository
+            .find_all()
+            .await
+            .unwrap(),
+        vec![(
+            "mailinator.com".to_string(),
+            SubscriberEmailDomainRule::Allowed
+        )],
+    );
+}
+
+#[tokio::test]
+async fn email_domain_policy_with_zero_reload_interval_is_rejected() {
+    // given
+    let configuration = EmailDomainPolicyConfiguration {
+        file: None,
+        reload_interval: 0,
+    };
+
+    // when
+    let policy = policy::get_email_domain_policy(
+        &configuration,
+        MockSubscriberEmailDomainOverrideRepository::new(),
+    )
+    .await;
+
+    // then
+    assert!(policy.is_err());
+}
+
+#[tokio::test]
+async fn malformed_email_domain_policy_file_is_reported_instead_of_panicking() {
+    // given
+    let file = std::env::temp_dir().join(format!("{}.yaml", 10.fake::<String>()));
+    tokio::fs::write(&file, "allowed_domains: [").await.unwrap();
+    let configuration = EmailDomainPolicyConfiguration {
+        file: Some(file.to_string_lossy().to_string()),
+        reload_interval: 30,
+    };
+
+    // when
+    let policy = policy::get_email_domain_policy(
+        &configuration,
+        MockSubscriberEmailDomainOverrideRepository::new(),
+    )
+    .await;
+
+    // then
+    tokio::fs::remove_file(&file).await.unwrap();
+    assert!(policy.is_err());
+}
@@ -0,0 +1,152 @@
+use domain::prelude::{
+    Subscriber,
+    SubscriberEmail,
+    SubscriberName,
+    SubscriberRepository,
+    SubscriptionToken,
+    SubscriptionTokenPurpose,
+    SubscriptionTokenRepository,
+};
+use fake::faker::internet::en::SafeEmail;
+use fake::Fake;
+use runner::configuration::PurgeConfiguration;
+use runner::maintenance::{
+    self,
+    PurgeReport,
+};
+use tests::api::app::App;
+use uuid::Uuid;
+
+const PURGE_CONFIGURATION: PurgeConfiguration = PurgeConfiguration {
+    interval: 3600,
+    subscription_token_retention: 7,
+    unconfirmed_subscriber_retention: 30,
+    dry_run: false,
+};
+
+// save a stale unconfirmed subscriber, a recent unconfirmed subscriber and an expired token
+async fn save_stale_data(app: &App) -> (Subscriber, Subscriber, SubscriptionToken) {
+    let mut stale_subscriber = Subscriber::new(
+        Uuid::new_v4(),
+        SubscriberEmail::parse(SafeEmail().fake()).unwrap(),
+        SubscriberName::parse("stale".to_string()).unwrap(),
+    );
+    stale_subscriber.subscribed_at = chrono::Utc::now() - chrono::Duration::days(31);
+    let recent_subscriber = Subscriber::new(
+        Uuid::new_v4(),
+        SubscriberEmail::parse(SafeEmail().fake()).unwrap(),
+        SubscriberName::parse("recent".to_string()).unwrap(),
+    );
+
+    let mut expired_subscription_token = SubscriptionToken::new(
+        SubscriptionToken::generate_token().await,
+        recent_subscriber.id,
+        SubscriptionTokenPurpose::Confirmation,
+    );
+    expired_subscription_token.expired_at = chrono::Utc::now() - chrono::Duration::days(8);
+
+    app.subscriber_repository
+        .save(&stale_subscriber)
+        .await
+        .unwrap();
+    app.subscriber_repository
+        .save(&recent_subscriber)
+        .await
+        .unwrap();
+    app.subscription_token_repository
+        .save(&expired_subscription_token)
+        .await
+        .unwrap();
+
+    (
+        stale_subscriber,
+        recent_subscriber,
+        expired_subscription_token,
+    )
+}
+
+#[tokio::test]
+async fn purge_in_dry_run_reports_stale_data_without_deleting_it() {
+    // given
+    let app = App::new().await;
+    let (stale_subscriber, _, expired_subscription_token) = save_stale_data(&app).await;
+
+    // when
+    let report = maintenance::purge(
+        app.subscriber_repository.as_ref(),
+        app.subscription_token_repository.as_ref(),
+        &PURGE_CONFIGURATION,
+        true,
+    )
+    .await
+    .unwrap();
+
+    // then
+    assert_eq!(
+        report,
+        PurgeReport {
+            dry_run: true,
+            expired_subscription_tokens: 1,
+            unconfirmed_subscribers: 1,
+        }
+    );
+    assert!(app
+        .subscriber_repository
+        .find_by_id(stale_subscriber.id)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        app.subscription_token_repository
+            .count_expired_before(expired_subscription_token.expired_at + chrono::Duration::days(1))
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn purge_deletes_only_stale_data() {
+    // given
+    let app = App::new().await;
+    let (stale_subscriber, recent_subscriber, expired_subscription_token) =
+        save_stale_data(&app).await;
+
+    // when
+    let report = maintenance::purge(
+        app.subscriber_repository.as_ref(),
+        app.subscription_token_repository.as_ref(),
+        &PURGE_CONFIGURATION,
+        false,
+    )
+    .await
+    .unwrap();
+
+    // then
+    assert_eq!(
+        report,
+        PurgeReport {
+            dry_run: false,
+            expired_subscription_tokens: 1,
+            unconfirmed_subscribers: 1,
+        }
+    );
+    assert!(app
+        .subscriber_repository
+        .find_by_id(stale_subscriber.id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(app
+        .subscriber_repository
+        .find_by_id(recent_subscriber.id)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(app
+        .subscription_token_repository
+        .find_by_subscriber_id(expired_subscription_token.subscriber_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
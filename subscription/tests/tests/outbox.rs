@@ -0,0 +1,173 @@
+use domain::prelude::{
+    SubscriberEvent,
+    SubscriberEventOutbox,
+    SubscriberEventRelay,
+    SubscriberRepository,
+};
+use fake::faker::internet::en::SafeEmail;
+use fake::Fake;
+use publishers::prelude::SubscriberEventInMemoryPublisher;
+use reqwest::StatusCode;
+use tests::api::app::App;
+use wiremock::matchers::{
+    method,
+    path,
+};
+use wiremock::{
+    Mock,
+    ResponseTemplate,
+};
+
+// subscribe and confirm the subscription through the API
+async fn subscribe_and_confirm(app: &App, email: &str) {
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    app.post_subscription_subscribe(&[("email", email), ("name", "Arine You")])
+        .await;
+
+    let request = &app.email_server.received_requests().await.unwrap()[0];
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    let token = body["Content"]
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/confirm?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string();
+
+    app.post_subscription_confirm(&[("token", token.as_str())])
+        .await;
+}
+
+#[tokio::test]
+async fn relaying_publishes_events_of_subscription_in_order() {
+    // given
+    let app = App::new().await;
+    let email: String = SafeEmail().fake();
+    subscribe_and_confirm(&app, &email).await;
+
+    let publisher = SubscriberEventInMemoryPublisher::new();
+    let relay = SubscriberEventRelay::new(
+        app.subscriber_event_outbox_repository.as_ref().clone(),
+        publisher.clone(),
+    );
+
+    // when
+    let relayed = relay.relay(100).await.unwrap();
+
+    // then
+    assert_eq!(relayed, 2);
+    let events = publisher.published_events();
+    assert!(matches!(
+        &events[0].event,
+        SubscriberEvent::SubscriberRegistered(event) if event.email == email
+    ));
+    assert!(matches!(
+        &events[1].event,
+        SubscriberEvent::SubscriberConfirmed(event)
+            if event.subscriber_id == events[0].event.subscriber_id()
+    ));
+}
+
+#[tokio::test]
+async fn relayed_events_are_not_relayed_again() {
+    // given
+    let app = App::new().await;
+    let email: String = SafeEmail().fake();
+    subscribe_and_confirm(&app, &email).await;
+
+    let publisher = SubscriberEventInMemoryPublisher::new();
+    let relay = SubscriberEventRelay::new(
+        app.subscriber_event_outbox_repository.as_ref().clone(),
+        publisher.clone(),
+    );
+    relay.relay(100).await.unwrap();
+
+    // when
+    let relayed = relay.relay(100).await.unwrap();
+
+    // then
+    assert_eq!(relayed, 0);
+    assert_eq!(publisher.published_events().len(), 2);
+    assert!(app
+        .subscriber_event_outbox_repository
+        .find_unpublished(100)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn unsubscribing_records_subscriber_unsubscribed_in_outbox() {
+    // given
+    let app = App::new().await;
+    let email: String = SafeEmail().fake();
+    subscribe_and_confirm(&app, &email).await;
+    let subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // when
+    let token = app.sign_unsubscribe_token(subscriber.id);
+    let response = app
+        .post_subscription_unsubscribe(&[("token", token.as_str())])
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    let events = app
+        .subscriber_event_outbox_repository
+        .find_unpublished(100)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(matches!(
+        &events[2].event,
+        SubscriberEvent::SubscriberUnsubscribed(event) if event.subscriber_id == subscriber.id
+    ));
+}
+
+#[tokio::test]
+async fn erasing_subscriber_records_subscriber_erased_in_outbox() {
+    // given
+    let app = App::new().await;
+    let email: String = SafeEmail().fake();
+    subscribe_and_confirm(&app, &email).await;
+    let subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // when
+    let body = serde_json::json!({ "SubscriberId": subscriber.id });
+    let response = app.post_admin_erase_subscriber(&body).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(app
+        .subscriber_repository
+        .find_by_id(subscriber.id)
+        .await
+        .unwrap()
+        .is_none());
+    let events = app
+        .subscriber_event_outbox_repository
+        .find_unpublished(100)
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(matches!(
+        &events[2].event,
+        SubscriberEvent::SubscriberErased(event) if event.subscriber_id == subscriber.id
+    ));
+}
@@ -0,0 +1,263 @@
+use domain::prelude::{
+    SubscriberContentFormat,
+    SubscriberRepository,
+};
+use fake::faker::internet::en::SafeEmail;
+use fake::faker::name::en::FirstName;
+use fake::Fake;
+use reqwest::StatusCode;
+use tests::api::app::App;
+use wiremock::matchers::{
+    method,
+    path,
+};
+use wiremock::{
+    Mock,
+    ResponseTemplate,
+};
+
+async fn subscribe(app: &App, email: &str, name: &str) {
+    let parameters = [("email", email), ("name", name)];
+    let response = app.post_subscription_subscribe(&parameters).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+async fn extract_profile_token(app: &App) -> String {
+    let requests = app.email_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests.last().unwrap().body).unwrap();
+    let content = body.get("Content").unwrap();
+
+    content
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/profile?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string()
+}
+
+#[tokio::test]
+async fn requesting_profile_link_for_unknown_email_returns_202_without_sending_email() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [("email", email.as_str())];
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&app.email_server)
+        .await;
+
+    // when
+    let response = app
+        .post_subscription_request_profile_link(&parameters)
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn subscriber_can_inquire_and_update_profile_with_magic_link() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let name: String = FirstName().fake();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&app.email_server)
+        .await;
+
+    subscribe(&app, &email, &name).await;
+
+    let parameters = [("email", email.as_str())];
+    let response = app
+        .post_subscription_request_profile_link(&parameters)
+        .await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let token = extract_profile_token(&app).await;
+    let parameters = [("token", token.as_str())];
+
+    // when
+    let response = app.get_subscription_inquire_profile(&parameters).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    let profile: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(profile["Email"], email.as_str());
+    assert_eq!(profile["Name"], name.as_str());
+    assert_eq!(profile["Status"], "Unconfirmed");
+    assert_eq!(profile["Preferences"]["ContentFormat"], "Html");
+
+    // when
+    let body = serde_json::json!({
+        "Name": "Arine You",
+        "Preferences": { "ContentFormat": "PlainText" },
+    });
+    let response = app
+        .post_subscription_update_profile(&parameters, &body)
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let saved_subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved_subscriber.name.as_ref(), "Arine You");
+    assert_eq!(
+        saved_subscriber.preferences.content_format,
+        SubscriberContentFormat::PlainText
+    );
+}
+
+#[tokio::test]
+async fn confirmation_token_cannot_be_used_to_inquire_profile() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let name: String = FirstName().fake();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    subscribe(&app, &email, &name).await;
+
+    let requests = app.email_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    let token = body["Content"]
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/confirm?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string();
+
+    // when
+    let parameters = [("token", token.as_str())];
+    let response = app.get_subscription_inquire_profile(&parameters).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn requesting_new_profile_link_revokes_the_older_one() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    subscribe(&app, &email, "Arine You").await;
+
+    let parameters = [("email", email.as_str())];
+    app.post_subscription_request_profile_link(&parameters)
+        .await;
+    let older_token = extract_profile_token(&app).await;
+
+    // when
+    app.post_subscription_request_profile_link(&parameters)
+        .await;
+    let newer_token = extract_profile_token(&app).await;
+
+    // then
+    let response = app
+        .get_subscription_inquire_profile(&[("token", older_token.as_str())])
+        .await;
+    assert_eq!(response.status(), StatusCode::GONE);
+
+    let response = app
+        .get_subscription_inquire_profile(&[("token", newer_token.as_str())])
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn profile_link_can_not_be_used_after_updating_profile() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    subscribe(&app, &email, "Arine You").await;
+    app.post_subscription_request_profile_link(&[("email", email.as_str())])
+        .await;
+    let token = extract_profile_token(&app).await;
+    let parameters = [("token", token.as_str())];
+    let body = serde_json::json!({ "Name": "Arine You" });
+
+    let response = app
+        .post_subscription_update_profile(&parameters, &body)
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // when
+    let response = app
+        .post_subscription_update_profile(&parameters, &body)
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn profile_link_used_by_concurrent_updates_updates_profile_once() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    subscribe(&app, &email, "Arine You").await;
+    app.post_subscription_request_profile_link(&[("email", email.as_str())])
+        .await;
+    let token = extract_profile_token(&app).await;
+    let parameters = [("token", token.as_str())];
+    let body = serde_json::json!({ "Name": "Arine You" });
+    let other_body = serde_json::json!({ "Name": "Arine Other" });
+
+    // when
+    let (response, other_response) = tokio::join!(
+        app.post_subscription_update_profile(&parameters, &body),
+        app.post_subscription_update_profile(&parameters, &other_body),
+    );
+
+    // then
+    let mut statuses = [response.status(), other_response.status()];
+    statuses.sort();
+    assert_eq!(statuses, [StatusCode::OK, StatusCode::CONFLICT]);
+}
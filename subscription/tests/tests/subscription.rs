@@ -182,3 +182,125 @@ async fn subscriber_is_confirmed_after_clicking_confirmation_link() {
         SubscriberStatus::Confirmed,
     ));
 }
+
+#[tokio::test]
+async fn confirming_with_already_used_token_returns_409() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [("email", email.as_str()), ("name", "Arine You")];
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    app.post_subscription_subscribe(&parameters).await;
+
+    let request = &app.email_server.received_requests().await.unwrap()[0];
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    let token = body["Content"]
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/confirm?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string();
+    let parameters = [("token", token.as_str())];
+
+    let response = app.post_subscription_confirm(&parameters).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // when
+    let response = app.post_subscription_confirm(&parameters).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn subscriber_is_unsubscribed_after_clicking_unsubscribe_link() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [("email", email.as_str()), ("name", "Arine You")];
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    app.post_subscription_subscribe(&parameters).await;
+    let subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // when
+    let token = app.sign_unsubscribe_token(subscriber.id);
+    let response = app
+        .post_subscription_unsubscribe(&[("token", token.as_str())])
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    let saved_subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        saved_subscriber.status,
+        SubscriberStatus::Unsubscribed,
+    ));
+}
+
+#[tokio::test]
+async fn unsubscribing_with_unsigned_subscriber_id_returns_404() {
+    // given
+    let app = App::new().await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [("email", email.as_str()), ("name", "Arine You")];
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    app.post_subscription_subscribe(&parameters).await;
+    let subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // when
+    let token = subscriber.id.to_string();
+    let response = app
+        .post_subscription_unsubscribe(&[("token", token.as_str())])
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let saved_subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        saved_subscriber.status,
+        SubscriberStatus::Unconfirmed,
+    ));
+}
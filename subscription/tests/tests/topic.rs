@@ -0,0 +1,180 @@
+use domain::prelude::{
+    SubscriberRepository,
+    TopicMembershipRepository,
+    TopicMembershipStatus,
+    TopicRepository,
+};
+use fake::faker::internet::en::SafeEmail;
+use fake::Fake;
+use reqwest::StatusCode;
+use tests::api::app::App;
+use wiremock::matchers::{
+    method,
+    path,
+};
+use wiremock::{
+    Mock,
+    ResponseTemplate,
+};
+
+async fn create_topic(app: &App, slug: &str, visibility: &str) {
+    let body = serde_json::json!({
+        "Slug": slug,
+        "Name": slug,
+        "Description": format!("News about {}", slug),
+        "Visibility": visibility,
+    });
+    let response = app.post_admin_create_topic(&body).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+async fn extract_confirmation_token(app: &App) -> String {
+    let requests = app.email_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+
+    body["Content"]
+        .as_str()
+        .unwrap()
+        .split("/subscriptions/confirm?token=")
+        .collect::<Vec<&str>>()[1]
+        .split('"')
+        .collect::<Vec<&str>>()[0]
+        .to_string()
+}
+
+#[tokio::test]
+async fn creating_topic_with_existing_slug_returns_409() {
+    // given
+    let app = App::new().await;
+    create_topic(&app, "rust", "Public").await;
+
+    // when
+    let body = serde_json::json!({
+        "Slug": "rust",
+        "Name": "Rust again",
+        "Visibility": "Public",
+    });
+    let response = app.post_admin_create_topic(&body).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn inquiring_topics_returns_only_public_topics() {
+    // given
+    let app = App::new().await;
+    create_topic(&app, "rust", "Public").await;
+    create_topic(&app, "internal", "Private").await;
+
+    // when
+    let response = app.get_subscription_inquire_topics().await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::OK);
+    let topics: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        topics,
+        serde_json::json!([
+            { "Slug": "rust", "Name": "rust", "Description": "News about rust" },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn subscription_with_private_topic_returns_400_without_registering() {
+    // given
+    let app = App::new().await;
+    create_topic(&app, "internal", "Private").await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [
+        ("email", email.as_str()),
+        ("name", "Arine You"),
+        ("topics", "internal"),
+    ];
+
+    // when
+    let response = app.post_subscription_subscribe(&parameters).await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let saved_subscriber = app
+        .subscriber_repository
+        .find_by_email(email.as_str())
+        .await
+        .unwrap();
+    assert!(saved_subscriber.is_none());
+}
+
+#[tokio::test]
+async fn confirming_subscription_confirms_pending_topic_memberships() {
+    // given
+    let app = App::new().await;
+    create_topic(&app, "rust", "Public").await;
+    create_topic(&app, "go", "Public").await;
+    create_topic(&app, "zig", "Public").await;
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&app.email_server)
+        .await;
+
+    let email: String = SafeEmail().fake();
+    let parameters = [
+        ("email", email.as_str()),
+        ("name", "Arine You"),
+        ("topics", "rust, go"),
+    ];
+    let response = app.post_subscription_subscribe(&parameters).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let rust = app
+        .topic_repository
+        .find_by_slug("rust")
+        .await
+        .unwrap()
+        .unwrap();
+    let pending_subscribers = app
+        .topic_membership_repository
+        .find_subscribers_by_topic_id(rust.id, TopicMembershipStatus::Pending)
+        .await
+        .unwrap();
+    assert_eq!(pending_subscribers.len(), 1);
+
+    // when
+    let token = extract_confirmation_token(&app).await;
+    let response = app
+        .post_subscription_confirm(&[("token", token.as_str())])
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // then
+    for (slug, expected) in [("rust", 1), ("go", 1), ("zig", 0)] {
+        let response = app
+            .get_admin_inquire_topic_subscribers(&[("slug", slug)])
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let subscribers: Vec<serde_json::Value> = response.json().await.unwrap();
+        assert_eq!(subscribers.len(), expected, "topic {}", slug);
+        if expected == 1 {
+            assert_eq!(subscribers[0]["Email"], email.as_str());
+        }
+    }
+}
+
+#[tokio::test]
+async fn inquiring_subscribers_of_not_existing_topic_returns_404() {
+    // given
+    let app = App::new().await;
+
+    // when
+    let response = app
+        .get_admin_inquire_topic_subscribers(&[("slug", "not-existing")])
+        .await;
+
+    // then
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}